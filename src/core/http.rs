@@ -1,7 +1,7 @@
 use std::{collections::BTreeMap, time::Duration};
 
 use reqwest::{
-    header::{HeaderMap, HeaderValue, RETRY_AFTER},
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE, LOCATION, RETRY_AFTER},
     redirect::Policy,
     Method, StatusCode, Url,
 };
@@ -17,7 +17,8 @@ use crate::error::{
 #[cfg(feature = "stream")]
 use crate::stream::{AiStream, AiStreamError, AiStreamErrorKind};
 
-const MAX_ERROR_BODY_BYTES: usize = 8 * 1024;
+pub(crate) const MAX_ERROR_BODY_BYTES: usize = 8 * 1024;
+const MAX_REDIRECTS: usize = 10;
 
 pub(crate) struct HttpTransportConfig {
     pub provider: AiProvider,
@@ -41,7 +42,7 @@ impl HttpTransport {
 
         let redirect_origin = base_url.clone();
         let mut builder = reqwest::Client::builder().redirect(Policy::custom(move |attempt| {
-            if attempt.previous().len() >= 10 {
+            if attempt.previous().len() >= MAX_REDIRECTS {
                 return attempt.stop();
             }
             if same_origin(attempt.url(), &redirect_origin) {
@@ -107,7 +108,7 @@ impl HttpTransport {
     }
 
     #[cfg(feature = "stream")]
-    pub(crate) async fn get_json_stream_segments<D>(
+    pub(crate) async fn get_stream_segments<D>(
         &self,
         operation: &'static str,
         path_segments: &[&str],
@@ -138,6 +139,43 @@ impl HttpTransport {
         self.send_and_decode(operation, request, decode_error).await
     }
 
+    pub(crate) async fn post_multipart<Res, D>(
+        &self,
+        operation: &'static str,
+        path: &str,
+        form: reqwest::multipart::Form,
+        decode_error: D,
+    ) -> AiResult<AiResponse<Res>>
+    where
+        Res: DeserializeOwned,
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let url = self.build_url(path, &[])?;
+        let request = self
+            .client
+            .post(url)
+            .headers(self.headers.clone())
+            .multipart(form);
+        self.send_and_decode(operation, request, decode_error).await
+    }
+
+    pub(crate) async fn post_multipart_segments<Res, D>(
+        &self,
+        operation: &'static str,
+        path_segments: &[&str],
+        form: reqwest::multipart::Form,
+        decode_error: D,
+    ) -> AiResult<AiResponse<Res>>
+    where
+        Res: DeserializeOwned,
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let request = self
+            .request_segments(Method::POST, path_segments, &[])?
+            .multipart(form);
+        self.send_and_decode(operation, request, decode_error).await
+    }
+
     pub(crate) async fn post_json_segments<Req, Res, D>(
         &self,
         operation: &'static str,
@@ -176,6 +214,25 @@ impl HttpTransport {
         self.send_and_decode(operation, request, decode_error).await
     }
 
+    pub(crate) async fn patch_json_segments_with_query<Req, Res, D>(
+        &self,
+        operation: &'static str,
+        path_segments: &[&str],
+        query: &[(String, String)],
+        request: &Req,
+        decode_error: D,
+    ) -> AiResult<AiResponse<Res>>
+    where
+        Req: Serialize + ?Sized,
+        Res: DeserializeOwned,
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let request = self
+            .request_segments(Method::PATCH, path_segments, query)?
+            .json(request);
+        self.send_and_decode(operation, request, decode_error).await
+    }
+
     pub(crate) async fn post_empty_segments<Res, D>(
         &self,
         operation: &'static str,
@@ -190,6 +247,22 @@ impl HttpTransport {
         self.send_and_decode(operation, request, decode_error).await
     }
 
+    /// Like `post_empty_segments`, for actions whose success body carries no
+    /// data (for example Google's `{}` from `:cancel`).
+    pub(crate) async fn post_empty_segments_no_content<D>(
+        &self,
+        operation: &'static str,
+        path_segments: &[&str],
+        decode_error: D,
+    ) -> AiResult<AiResponse<()>>
+    where
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let request = self.request_segments(Method::POST, path_segments, &[])?;
+        self.send_and_decode_empty(operation, request, decode_error)
+            .await
+    }
+
     pub(crate) async fn delete_json_segments<Res, D>(
         &self,
         operation: &'static str,
@@ -218,6 +291,152 @@ impl HttpTransport {
             .await
     }
 
+    /// Download raw bytes from an absolute URL returned by the provider, such
+    /// as a generated file URI. The URL must share the base URL's origin so
+    /// authentication headers are never sent to another host.
+    ///
+    /// Media URLs commonly redirect to signed storage on another host. Those
+    /// redirects are followed with a plain GET that carries no credentials.
+    pub(crate) async fn get_bytes_url<D>(
+        &self,
+        operation: &'static str,
+        url: &str,
+        decode_error: D,
+    ) -> AiResult<AiResponse<Vec<u8>>>
+    where
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let url = Url::parse(url)
+            .ok()
+            .filter(|url| same_origin(url, &self.base_url))
+            .ok_or_else(|| {
+                AiError::config(
+                    ConfigErrorKind::InvalidBaseUrl,
+                    "download URL must share the client's origin",
+                )
+            })?;
+        let mut response = self
+            .client
+            .get(url)
+            .headers(self.headers.clone())
+            .send()
+            .await
+            .map_err(|error| transport_error(self.provider, operation, error))?;
+        for _ in 0..MAX_REDIRECTS {
+            let Some(location) = redirect_location(&response) else {
+                break;
+            };
+            response = self
+                .client
+                .get(location)
+                .send()
+                .await
+                .map_err(|error| transport_error(self.provider, operation, error))?;
+        }
+        self.collect_bytes(operation, response, decode_error).await
+    }
+
+    /// Download a raw, non-JSON response body from an API path.
+    pub(crate) async fn get_bytes_segments<D>(
+        &self,
+        operation: &'static str,
+        path_segments: &[&str],
+        decode_error: D,
+    ) -> AiResult<AiResponse<Vec<u8>>>
+    where
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let request = self.request_segments(Method::GET, path_segments, &[])?;
+        self.send_and_collect_bytes(operation, request, decode_error)
+            .await
+    }
+
+    /// Download a raw body from an API path served under `prefix`, which is
+    /// placed before the base URL's own path. Gemini serves file media from
+    /// `/download/v1beta/...` rather than `/v1beta/...`.
+    pub(crate) async fn get_bytes_prefixed_segments<D>(
+        &self,
+        operation: &'static str,
+        prefix: &str,
+        path_segments: &[&str],
+        query: &[(String, String)],
+        decode_error: D,
+    ) -> AiResult<AiResponse<Vec<u8>>>
+    where
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let mut url = self.build_url_segments(path_segments, query)?;
+        let path = format!("/{prefix}{}", url.path());
+        url.set_path(&path);
+        let request = self.client.get(url).headers(self.headers.clone());
+        self.send_and_collect_bytes(operation, request, decode_error)
+            .await
+    }
+
+    /// POST a JSON body and collect a raw, non-JSON response body.
+    pub(crate) async fn post_json_bytes<Req, D>(
+        &self,
+        operation: &'static str,
+        path: &str,
+        request: &Req,
+        decode_error: D,
+    ) -> AiResult<AiResponse<Vec<u8>>>
+    where
+        Req: Serialize + ?Sized,
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let request = self.json_request(Method::POST, path, request)?;
+        self.send_and_collect_bytes(operation, request, decode_error)
+            .await
+    }
+
+    async fn send_and_collect_bytes<D>(
+        &self,
+        operation: &'static str,
+        request: reqwest::RequestBuilder,
+        decode_error: D,
+    ) -> AiResult<AiResponse<Vec<u8>>>
+    where
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let response = request
+            .send()
+            .await
+            .map_err(|error| transport_error(self.provider, operation, error))?;
+        self.collect_bytes(operation, response, decode_error).await
+    }
+
+    async fn collect_bytes<D>(
+        &self,
+        operation: &'static str,
+        response: reqwest::Response,
+        decode_error: D,
+    ) -> AiResult<AiResponse<Vec<u8>>>
+    where
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let metadata = response_metadata(response.status(), response.headers());
+        if metadata.status.is_success() {
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|error| transport_error(self.provider, operation, error))?;
+            return Ok(AiResponse::new(bytes.to_vec(), metadata));
+        }
+
+        let (bytes, truncated) = read_bounded_body(response, MAX_ERROR_BODY_BYTES)
+            .await
+            .map_err(|error| transport_error(self.provider, operation, error))?;
+        let body = BodySnippet::from_bytes(&bytes, truncated);
+        let error = decode_error(&bytes, body);
+        Err(AiError::Api {
+            provider: self.provider,
+            operation,
+            metadata: Box::new(metadata),
+            error: Box::new(error),
+        })
+    }
+
     #[cfg(feature = "stream")]
     pub(crate) async fn post_json_stream<Req, D>(
         &self,
@@ -235,6 +454,27 @@ impl HttpTransport {
             .await
     }
 
+    #[cfg(feature = "stream")]
+    pub(crate) async fn post_multipart_stream<D>(
+        &self,
+        operation: &'static str,
+        path: &str,
+        form: reqwest::multipart::Form,
+        decode_error: D,
+    ) -> AiResult<AiResponse<AiStream<Vec<u8>>>>
+    where
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let url = self.build_url(path, &[])?;
+        let request = self
+            .client
+            .post(url)
+            .headers(self.headers.clone())
+            .multipart(form);
+        self.send_stream_handshake(operation, request, decode_error)
+            .await
+    }
+
     #[cfg(feature = "stream")]
     pub(crate) async fn post_json_stream_segments<Req, D>(
         &self,
@@ -252,6 +492,26 @@ impl HttpTransport {
             .await
     }
 
+    #[cfg(feature = "stream")]
+    pub(crate) async fn post_json_stream_segments_with_query<Req, D>(
+        &self,
+        operation: &'static str,
+        path_segments: &[&str],
+        query: &[(String, String)],
+        request: &Req,
+        decode_error: D,
+    ) -> AiResult<AiResponse<AiStream<Vec<u8>>>>
+    where
+        Req: Serialize + ?Sized,
+        D: FnOnce(&[u8], BodySnippet) -> ProviderApiError,
+    {
+        let request = self
+            .request_segments(Method::POST, path_segments, query)?
+            .json(request);
+        self.send_stream_handshake(operation, request, decode_error)
+            .await
+    }
+
     fn json_request<Req: Serialize + ?Sized>(
         &self,
         method: Method,
@@ -410,6 +670,16 @@ impl HttpTransport {
         Ok(AiResponse::new(AiStream::new(stream), metadata))
     }
 
+    #[cfg(feature = "gemini-live")]
+    pub(crate) fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    #[cfg(feature = "gemini-live")]
+    pub(crate) fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
     pub(crate) fn build_url(&self, path: &str, query: &[(String, String)]) -> AiResult<Url> {
         validate_relative_path(path)?;
         self.build_url_segments(&path.split('/').collect::<Vec<_>>(), query)
@@ -493,6 +763,19 @@ fn validate_relative_path(path: &str) -> AiResult<()> {
     Ok(())
 }
 
+/// The target of a redirect the client's policy declined to follow.
+fn redirect_location(response: &reqwest::Response) -> Option<Url> {
+    if !response.status().is_redirection() {
+        return None;
+    }
+    let location = response.headers().get(LOCATION)?.to_str().ok()?;
+    response
+        .url()
+        .join(location)
+        .ok()
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+}
+
 fn same_origin(left: &Url, right: &Url) -> bool {
     left.scheme() == right.scheme()
         && left.host_str() == right.host_str()
@@ -530,11 +813,12 @@ fn classify_transport_error(error: &reqwest::Error) -> TransportErrorKind {
     }
 }
 
-fn response_metadata(status: StatusCode, headers: &HeaderMap) -> ResponseMetadata {
+pub(crate) fn response_metadata(status: StatusCode, headers: &HeaderMap) -> ResponseMetadata {
     let request_id = header_string(headers, "x-request-id")
         .or_else(|| header_string(headers, "x-goog-request-id"))
         .or_else(|| header_string(headers, "x-guploader-uploadid"));
     let retry_after = headers.get(RETRY_AFTER).and_then(header_value_string);
+    let content_type = headers.get(CONTENT_TYPE).and_then(header_value_string);
 
     let known = [
         "x-ratelimit-limit-requests",
@@ -557,6 +841,7 @@ fn response_metadata(status: StatusCode, headers: &HeaderMap) -> ResponseMetadat
         status,
         request_id,
         retry_after,
+        content_type,
         rate_limit: RateLimitMetadata {
             limit_requests: header_string(headers, known[0]),
             limit_tokens: header_string(headers, known[1]),
@@ -700,6 +985,7 @@ mod tests {
         let mut headers = HeaderMap::new();
         headers.insert("x-request-id", HeaderValue::from_static("req_123"));
         headers.insert(RETRY_AFTER, HeaderValue::from_static("2"));
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("audio/mpeg"));
         headers.insert(
             "x-ratelimit-remaining-requests",
             HeaderValue::from_static("17"),
@@ -708,6 +994,7 @@ mod tests {
         let metadata = response_metadata(StatusCode::TOO_MANY_REQUESTS, &headers);
         assert_eq!(metadata.request_id.as_deref(), Some("req_123"));
         assert_eq!(metadata.retry_after.as_deref(), Some("2"));
+        assert_eq!(metadata.content_type.as_deref(), Some("audio/mpeg"));
         assert_eq!(
             metadata.rate_limit.remaining_requests.as_deref(),
             Some("17")
@@ -951,7 +1238,7 @@ mod tests {
         ];
 
         let response = transport
-            .get_json_stream_segments(
+            .get_stream_segments(
                 "test.retrieve_stream",
                 &["responses", "resp/a"],
                 &query,
@@ -990,7 +1277,7 @@ mod tests {
         let transport = transport(&base_url);
 
         let result = transport
-            .get_json_stream_segments(
+            .get_stream_segments(
                 "test.retrieve_stream",
                 &["responses", "missing"],
                 &[],
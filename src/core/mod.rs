@@ -1,4 +1,5 @@
 pub(crate) mod http;
+pub(crate) mod poll;
 
 #[cfg(feature = "stream")]
 pub(crate) mod json_array;
@@ -0,0 +1,107 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use crate::error::{AiError, AiProvider, AiResponse, AiResult};
+
+/// Polling schedule for long-running operations, such as Gemini's
+/// `operations().wait` or an OpenAI vector store file batch.
+///
+/// The delay starts at `initial_delay` and is multiplied by `multiplier`
+/// after each poll, up to `max_delay`. A multiplier that is negative, NaN, or
+/// overflows the delay jumps straight to `max_delay`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PollBackoff {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+    /// Give up with [`AiError::Timeout`] once this much time has passed.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for PollBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(5),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+            timeout: None,
+        }
+    }
+}
+
+/// Call `fetch` until `is_done` accepts its result, sleeping between calls
+/// according to `backoff`.
+pub(crate) async fn poll_until<T, F, Fut>(
+    provider: AiProvider,
+    operation: &'static str,
+    backoff: PollBackoff,
+    mut fetch: F,
+    is_done: impl Fn(&T) -> bool,
+) -> AiResult<AiResponse<T>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = AiResult<AiResponse<T>>>,
+{
+    let deadline = backoff
+        .timeout
+        .and_then(|timeout| Instant::now().checked_add(timeout));
+    let mut delay = backoff.initial_delay;
+    loop {
+        let response = fetch().await?;
+        if is_done(response.data()) {
+            return Ok(response);
+        }
+        let sleep = match deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Err(AiError::Timeout {
+                        provider,
+                        operation,
+                    });
+                }
+                delay.min(remaining)
+            }
+            None => delay,
+        };
+        tokio::time::sleep(sleep).await;
+        delay = backoff.next_delay(delay);
+    }
+}
+
+impl PollBackoff {
+    pub(crate) fn next_delay(&self, delay: Duration) -> Duration {
+        Duration::try_from_secs_f64(delay.as_secs_f64() * self.multiplier)
+            .map_or(self.max_delay, |next| next.min(self.max_delay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_grow_to_the_cap_without_panicking_on_bad_multipliers() {
+        let backoff = PollBackoff::default();
+        assert_eq!(
+            backoff.next_delay(Duration::from_secs(5)),
+            Duration::from_secs(10)
+        );
+        assert_eq!(
+            backoff.next_delay(Duration::from_secs(40)),
+            Duration::from_secs(60)
+        );
+        for multiplier in [-1.0, f64::NAN, f64::INFINITY, f64::MAX] {
+            let backoff = PollBackoff {
+                multiplier,
+                ..backoff
+            };
+            assert_eq!(
+                backoff.next_delay(Duration::from_secs(5)),
+                Duration::from_secs(60)
+            );
+        }
+    }
+}
@@ -1,6 +1,33 @@
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
-async fn read_request(socket: &mut tokio::net::TcpStream) -> String {
+use crate::{gemini::GeminiClient, openai::OpenAIClient};
+
+pub(crate) fn openai_client(base_url: String) -> OpenAIClient {
+    OpenAIClient::builder()
+        .api_key("test-key".into())
+        .base_url(base_url)
+        .build()
+        .unwrap()
+}
+
+pub(crate) fn gemini_client(base_url: String) -> GeminiClient {
+    GeminiClient::builder()
+        .api_key("test-key".into())
+        .base_url(base_url)
+        .build()
+        .unwrap()
+}
+
+pub(crate) fn request_line(request: &str) -> &str {
+    request.lines().next().unwrap()
+}
+
+pub(crate) fn request_json(request: &str) -> serde_json::Value {
+    let (_, body) = request.split_once("\r\n\r\n").unwrap();
+    serde_json::from_str(body).unwrap()
+}
+
+pub(crate) async fn read_request(socket: &mut tokio::net::TcpStream) -> String {
     let mut request = Vec::new();
     loop {
         let mut chunk = [0; 4096];
@@ -52,6 +79,28 @@ pub(crate) async fn one_shot_server(
     )
 }
 
+/// Serves one response per connection, in order, and returns the requests.
+pub(crate) async fn sequence_server(
+    path_prefix: &str,
+    responses: Vec<String>,
+) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let handle = tokio::spawn(async move {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            requests.push(read_request(&mut socket).await);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+        requests
+    });
+    (
+        format!("http://{address}/{}", path_prefix.trim_matches('/')),
+        handle,
+    )
+}
+
 pub(crate) async fn delayed_server(
     path_prefix: &str,
     response: String,
@@ -94,9 +143,14 @@ pub(crate) async fn chunked_server(
 ) -> (String, tokio::task::JoinHandle<String>) {
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
     let address = listener.local_addr().unwrap();
-    let mut response_headers = String::from(
-        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n",
-    );
+    let mut response_headers =
+        String::from("HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n");
+    if !headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+    {
+        response_headers.push_str("Content-Type: text/event-stream\r\n");
+    }
     for (name, value) in headers {
         response_headers.push_str(name);
         response_headers.push_str(": ");
@@ -97,6 +97,8 @@ pub struct ResponseMetadata {
     pub request_id: Option<String>,
     /// Raw `Retry-After`; providers may use seconds or an HTTP date.
     pub retry_after: Option<String>,
+    /// Raw `Content-Type` of the response body.
+    pub content_type: Option<String>,
     pub rate_limit: RateLimitMetadata,
 }
 
@@ -252,6 +254,14 @@ pub enum AiError {
         metadata: Box<ResponseMetadata>,
         error: Box<ProviderApiError>,
     },
+    /// The request was rejected locally, before anything was sent. `source`
+    /// is the typed reason, for example an `ImageInputError`; downcast it to
+    /// inspect the details.
+    #[error("invalid request for `{operation}`: {source}")]
+    InvalidRequest {
+        operation: &'static str,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
 }
 
 impl AiError {
@@ -261,4 +271,14 @@ impl AiError {
             message: message.into(),
         }
     }
+
+    pub(crate) fn invalid_request(
+        operation: &'static str,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self::InvalidRequest {
+            operation,
+            source: source.into(),
+        }
+    }
 }
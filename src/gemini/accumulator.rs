@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+
+use super::{
+    BlockReason, Candidate, Content, GenerateContentResponse, Part, PromptFeedback, SafetyRating,
+    UsageMetadata,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GeminiStreamAccumulatorError {
+    #[error("prompt was blocked: {reason}")]
+    PromptBlocked {
+        reason: BlockReason,
+        safety_ratings: Vec<SafetyRating>,
+    },
+    #[error("received a chunk after the prompt was blocked")]
+    ChunkAfterBlock,
+}
+
+/// Merges partial `streamGenerateContent` chunks into one
+/// `GenerateContentResponse`.
+///
+/// Candidates are merged by index. Adjacent text parts are joined; function
+/// calls, inline blobs, and other parts are kept intact and in order. The
+/// latest finish reason, safety ratings, citation and grounding metadata,
+/// prompt feedback, and usage are carried forward.
+#[derive(Debug, Default)]
+pub struct GeminiStreamAccumulator {
+    candidates: BTreeMap<u64, Candidate>,
+    prompt_feedback: Option<PromptFeedback>,
+    usage_metadata: Option<UsageMetadata>,
+    blocked: bool,
+}
+
+impl GeminiStreamAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(
+        &mut self,
+        chunk: GenerateContentResponse,
+    ) -> Result<(), GeminiStreamAccumulatorError> {
+        if self.blocked {
+            return Err(GeminiStreamAccumulatorError::ChunkAfterBlock);
+        }
+        self.usage_metadata = Some(chunk.usage_metadata);
+        if let Some(feedback) = chunk.prompt_feedback {
+            if let Some(reason) = feedback.block_reason {
+                self.blocked = true;
+                let safety_ratings = feedback.safety_ratings.clone().unwrap_or_default();
+                self.prompt_feedback = Some(feedback);
+                return Err(GeminiStreamAccumulatorError::PromptBlocked {
+                    reason,
+                    safety_ratings,
+                });
+            }
+            self.prompt_feedback = Some(feedback);
+        }
+        for candidate in chunk.candidates {
+            match self.candidates.get_mut(&candidate.index) {
+                Some(existing) => merge_candidate(existing, candidate),
+                None => {
+                    self.candidates.insert(candidate.index, candidate);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The merged response so far, or `None` before the first chunk.
+    pub fn response(&self) -> Option<GenerateContentResponse> {
+        Some(GenerateContentResponse {
+            candidates: self.candidates.values().cloned().collect(),
+            prompt_feedback: self.prompt_feedback.clone(),
+            usage_metadata: self.usage_metadata.clone()?,
+        })
+    }
+
+    /// Consume the accumulator and return the merged response, or `None` if
+    /// no chunk was pushed.
+    pub fn into_response(self) -> Option<GenerateContentResponse> {
+        Some(GenerateContentResponse {
+            candidates: self.candidates.into_values().collect(),
+            prompt_feedback: self.prompt_feedback,
+            usage_metadata: self.usage_metadata?,
+        })
+    }
+
+    /// Text accumulated so far for the candidate with `index`.
+    pub fn text(&self, index: u64) -> String {
+        self.candidates
+            .get(&index)
+            .and_then(|candidate| candidate.content.as_ref())
+            .map(|content| content.parts.iter().filter_map(Part::as_text).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn is_blocked(&self) -> bool {
+        self.blocked
+    }
+}
+
+fn merge_candidate(existing: &mut Candidate, update: Candidate) {
+    if let Some(update) = update.content {
+        match &mut existing.content {
+            Some(content) => merge_content(content, update),
+            None => existing.content = Some(update),
+        }
+    }
+    if update.finish_reason.is_some() {
+        existing.finish_reason = update.finish_reason;
+    }
+    if !update.safety_ratings.is_empty() {
+        existing.safety_ratings = update.safety_ratings;
+    }
+    if update.citation_metadata.is_some() {
+        existing.citation_metadata = update.citation_metadata;
+    }
+    if update.grounding_metadata.is_some() {
+        existing.grounding_metadata = update.grounding_metadata;
+    }
+    if update.url_context_metadata.is_some() {
+        existing.url_context_metadata = update.url_context_metadata;
+    }
+    if update.token_count.is_some() {
+        existing.token_count = update.token_count;
+    }
+}
+
+fn merge_content(content: &mut Content, update: Content) {
+    if content.role.is_none() {
+        content.role = update.role;
+    }
+    for part in update.parts {
+        match (content.parts.last_mut(), part) {
+            (Some(Part::Text(text)), Part::Text(delta))
+            | (Some(Part::Thought(text)), Part::Thought(delta)) => text.push_str(&delta),
+            (_, part) => content.parts.push(part),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::gemini::FinishReason;
+
+    fn chunk(value: serde_json::Value) -> GenerateContentResponse {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn merges_text_keeps_calls_and_blobs_and_carries_latest_metadata() {
+        let mut accumulator = GeminiStreamAccumulator::new();
+        assert!(accumulator.response().is_none());
+        accumulator
+            .push(chunk(json!({
+                "candidates":[
+                    {"content":{"role":"model","parts":[{"text":"Hel"}]},"index":0},
+                    {"content":{"role":"model","parts":[{"text":"Other"}]},"index":1}
+                ],
+                "usageMetadata":{"promptTokenCount":3,"totalTokenCount":3}
+            })))
+            .unwrap();
+        accumulator
+            .push(chunk(json!({
+                "candidates":[{"content":{"parts":[
+                    {"text":"lo"},
+                    {"inlineData":{"mimeType":"image/png","data":"iVBORw=="}},
+                    {"text":" world"},
+                    {"functionCall":{"name":"lookup","args":{"q":"x"}}}
+                ]},"index":0}],
+                "usageMetadata":{"promptTokenCount":3,"totalTokenCount":5}
+            })))
+            .unwrap();
+        accumulator
+            .push(chunk(json!({
+                "candidates":[{
+                    "content":{"parts":[{"text":"!"}]},
+                    "finishReason":"STOP",
+                    "safetyRatings":[{"category":"HARM_CATEGORY_HARASSMENT","probability":"LOW"}],
+                    "citationMetadata":{"citationSources":[{"uri":"https://a.example"}]},
+                    "index":0
+                }],
+                "usageMetadata":{"promptTokenCount":3,"candidatesTokenCount":4,"totalTokenCount":7}
+            })))
+            .unwrap();
+
+        assert_eq!(accumulator.text(0), "Hello world!");
+        let response = accumulator.into_response().unwrap();
+        assert_eq!(response.candidates.len(), 2);
+        let first = &response.candidates[0];
+        let parts = &first.content.as_ref().unwrap().parts;
+        assert_eq!(parts.len(), 5);
+        assert_eq!(parts[0], Part::text("Hello"));
+        assert_eq!(parts[1], Part::blob_base64("image/png", "iVBORw=="));
+        assert_eq!(parts[2], Part::text(" world"));
+        assert_eq!(parts[3].as_function_call().unwrap().name, "lookup");
+        assert_eq!(parts[4], Part::text("!"));
+        assert_eq!(first.finish_reason, Some(FinishReason::Stop));
+        assert_eq!(first.safety_ratings.len(), 1);
+        assert!(first.citation_metadata.is_some());
+        assert_eq!(response.usage_metadata.total_token_count, 7);
+        assert_eq!(response.usage_metadata.candidates_token_count, Some(4));
+    }
+
+    #[test]
+    fn prompt_block_is_an_error_and_stops_accumulation() {
+        let mut accumulator = GeminiStreamAccumulator::new();
+        let error = accumulator
+            .push(chunk(json!({
+                "candidates":[],
+                "promptFeedback":{
+                    "blockReason":"SAFETY",
+                    "safetyRatings":[{
+                        "category":"HARM_CATEGORY_DANGEROUS_CONTENT",
+                        "probability":"HIGH",
+                        "blocked":true
+                    }]
+                },
+                "usageMetadata":{"promptTokenCount":3,"totalTokenCount":3}
+            })))
+            .unwrap_err();
+        match error {
+            GeminiStreamAccumulatorError::PromptBlocked {
+                reason,
+                safety_ratings,
+            } => {
+                assert_eq!(reason, BlockReason::Safety);
+                assert!(safety_ratings[0].blocked());
+            }
+            other => panic!("expected prompt block, got {other:?}"),
+        }
+        assert!(accumulator.is_blocked());
+        assert_eq!(
+            accumulator
+                .push(chunk(json!({
+                    "candidates":[],
+                    "usageMetadata":{"promptTokenCount":3,"totalTokenCount":3}
+                })))
+                .unwrap_err(),
+            GeminiStreamAccumulatorError::ChunkAfterBlock
+        );
+    }
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::utils::IntoQuery;
 
-use super::{Content, CountTokensGenerateContentRequest, ModelInfo};
+use super::{Content, CountTokensGenerateContentRequest, Modality, ModalityTokenCount, ModelInfo};
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -63,10 +63,41 @@ impl CountTokensRequest {
 #[serde(rename_all = "camelCase")]
 pub struct CountTokensResponse {
     total_tokens: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cached_content_token_count: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    prompt_tokens_details: Vec<ModalityTokenCount>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    cache_tokens_details: Vec<ModalityTokenCount>,
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
 }
 
 impl CountTokensResponse {
     pub fn total_tokens(&self) -> u64 {
         self.total_tokens
     }
+
+    /// Tokens of the request's `cached_content`, if any.
+    pub fn cached_content_token_count(&self) -> Option<u64> {
+        self.cached_content_token_count
+    }
+
+    pub fn prompt_tokens_details(&self) -> &[ModalityTokenCount] {
+        &self.prompt_tokens_details
+    }
+
+    pub fn cache_tokens_details(&self) -> &[ModalityTokenCount] {
+        &self.cache_tokens_details
+    }
+
+    /// Prompt tokens of `modality`, or 0 if the breakdown omits it.
+    pub fn prompt_tokens(&self, modality: Modality) -> u64 {
+        ModalityTokenCount::sum(&self.prompt_tokens_details, &modality)
+    }
+
+    /// Fields this crate does not model yet.
+    pub fn other(&self) -> &serde_json::Map<String, serde_json::Value> {
+        &self.other
+    }
 }
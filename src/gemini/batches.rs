@@ -0,0 +1,835 @@
+use std::{fmt, io};
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr, PickFirst};
+
+use crate::{
+    error::{AiError, AiProvider, AiResponse, AiResult, JsonDecodeError},
+    utils::IntoQuery,
+};
+
+use super::{
+    client::decode_gemini_error, BatchName, FileName, GeminiClient, GeminiModelId,
+    GenerateContentRequest, GenerateContentResponse, RpcStatus,
+};
+
+/// One `generateContent` request in a batch, tagged with a caller-chosen key
+/// that is echoed back with its result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchRequest {
+    pub key: String,
+    pub request: GenerateContentRequest,
+}
+
+impl BatchRequest {
+    pub fn new(key: impl Into<String>, request: GenerateContentRequest) -> Self {
+        Self {
+            key: key.into(),
+            request,
+        }
+    }
+}
+
+/// Write requests in the JSONL layout expected for file-based batch input,
+/// one `{"key":...,"request":...}` object per line.
+pub fn write_batch_jsonl<'a, W: io::Write>(
+    mut writer: W,
+    requests: impl IntoIterator<Item = &'a BatchRequest>,
+) -> io::Result<()> {
+    for request in requests {
+        serde_json::to_writer(&mut writer, request)?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Where a batch reads its requests from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchInput {
+    /// Requests sent in the create call. Suitable for batches under the
+    /// provider's inline size limit (currently 20 MB).
+    Requests(Vec<BatchRequest>),
+    /// A previously uploaded JSONL file; see [`write_batch_jsonl`].
+    File(FileName),
+}
+
+impl Serialize for BatchInput {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct InlinedRequest<'a> {
+            request: &'a GenerateContentRequest,
+            metadata: RequestMetadata<'a>,
+        }
+        #[derive(Serialize)]
+        struct RequestMetadata<'a> {
+            key: &'a str,
+        }
+        #[derive(Serialize)]
+        struct InlinedRequests<'a> {
+            requests: Vec<InlinedRequest<'a>>,
+        }
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        enum Wire<'a> {
+            Requests(InlinedRequests<'a>),
+            FileName(&'a FileName),
+        }
+
+        match self {
+            Self::Requests(requests) => Wire::Requests(InlinedRequests {
+                requests: requests
+                    .iter()
+                    .map(|request| InlinedRequest {
+                        request: &request.request,
+                        metadata: RequestMetadata { key: &request.key },
+                    })
+                    .collect(),
+            }),
+            Self::File(file) => Wire::FileName(file),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Request body for `models.batchGenerateContent`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateBatchRequest {
+    model: GeminiModelId,
+    display_name: Option<String>,
+    input: BatchInput,
+}
+
+impl CreateBatchRequest {
+    /// Batch inline requests, each tagged with its key.
+    pub fn inline(
+        model: impl Into<GeminiModelId>,
+        requests: impl IntoIterator<Item = BatchRequest>,
+    ) -> Self {
+        Self::new(model, BatchInput::Requests(requests.into_iter().collect()))
+    }
+
+    /// Batch the requests in an uploaded JSONL file.
+    pub fn from_file(model: impl Into<GeminiModelId>, file: FileName) -> Self {
+        Self::new(model, BatchInput::File(file))
+    }
+
+    pub fn new(model: impl Into<GeminiModelId>, input: BatchInput) -> Self {
+        Self {
+            model: model.into(),
+            display_name: None,
+            input,
+        }
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+}
+
+impl Serialize for CreateBatchRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Wire<'a> {
+            batch: Batch<'a>,
+        }
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Batch<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            display_name: Option<&'a str>,
+            input_config: &'a BatchInput,
+        }
+
+        Wire {
+            batch: Batch {
+                display_name: self.display_name.as_deref(),
+                input_config: &self.input,
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BatchState {
+    #[serde(rename = "BATCH_STATE_PENDING")]
+    Pending,
+    #[serde(rename = "BATCH_STATE_RUNNING")]
+    Running,
+    #[serde(rename = "BATCH_STATE_SUCCEEDED")]
+    Succeeded,
+    #[serde(rename = "BATCH_STATE_FAILED")]
+    Failed,
+    #[serde(rename = "BATCH_STATE_CANCELLED")]
+    Cancelled,
+    #[serde(rename = "BATCH_STATE_EXPIRED")]
+    Expired,
+    /// Also used for states added after this crate was released.
+    #[serde(rename = "BATCH_STATE_UNSPECIFIED", other)]
+    Unspecified,
+}
+
+impl BatchState {
+    /// Whether the batch has stopped and will not change state again.
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            Self::Succeeded | Self::Failed | Self::Cancelled | Self::Expired
+        )
+    }
+}
+
+/// Request counts. The API encodes these 64-bit values as strings.
+#[serde_as]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchStats {
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    #[serde(default)]
+    pub request_count: u64,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    #[serde(default)]
+    pub successful_request_count: u64,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    #[serde(default)]
+    pub failed_request_count: u64,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    #[serde(default)]
+    pub pending_request_count: u64,
+}
+
+/// Where a finished batch put its results.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchOutput {
+    /// Results returned inline, in request order.
+    Inline(Vec<BatchResult>),
+    /// A JSONL results file; fetch it with [`BatchesResource::results`] or
+    /// download it with [`BatchesResource::download_results`] and parse it
+    /// with [`parse_batch_results`].
+    File(FileName),
+}
+
+/// The outcome of one request in a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchResult {
+    /// The key given in [`BatchRequest`], if the provider echoed it.
+    pub key: Option<String>,
+    pub result: Result<GenerateContentResponse, BatchResultError>,
+}
+
+/// Why a single batched request produced no response.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum BatchResultError {
+    /// The request failed, as it would have outside a batch.
+    Status(RpcStatus),
+    /// The request succeeded but its response is not one this crate can
+    /// decode.
+    Decode(JsonDecodeError),
+    /// The provider returned neither a response nor an error for the request.
+    Missing,
+}
+
+#[derive(Deserialize)]
+struct BatchResultWire {
+    #[serde(default)]
+    key: Option<String>,
+    #[serde(default)]
+    metadata: Option<ResultMetadataWire>,
+    #[serde(default)]
+    response: Option<serde_json::Value>,
+    #[serde(default, alias = "status")]
+    error: Option<RpcStatus>,
+}
+
+#[derive(Deserialize)]
+struct ResultMetadataWire {
+    #[serde(default)]
+    key: Option<String>,
+}
+
+impl From<BatchResultWire> for BatchResult {
+    fn from(wire: BatchResultWire) -> Self {
+        let key = wire
+            .key
+            .or_else(|| wire.metadata.and_then(|metadata| metadata.key));
+        let result = match (wire.response, wire.error) {
+            (Some(response), _) => serde_json::from_value(response)
+                .map_err(|error| BatchResultError::Decode(error.into())),
+            (None, Some(error)) => Err(BatchResultError::Status(error)),
+            (None, None) => Err(BatchResultError::Missing),
+        };
+        Self { key, result }
+    }
+}
+
+/// Iterate over the lines of a downloaded batch results file.
+///
+/// Only a line that is not a batch result object is an error; a response that
+/// does not decode becomes [`BatchResultError::Decode`] for that key.
+pub fn parse_batch_results(
+    jsonl: &str,
+) -> impl Iterator<Item = Result<BatchResult, serde_json::Error>> + '_ {
+    jsonl
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str::<BatchResultWire>(line).map(BatchResult::from))
+}
+
+/// A batch job, decoded from the long-running operation the API returns.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "BatchOperationWire")]
+pub struct Batch {
+    pub name: BatchName,
+    pub display_name: Option<String>,
+    /// Model resource name, for example `models/gemini-2.5-flash`.
+    pub model: Option<String>,
+    pub state: BatchState,
+    pub create_time: Option<String>,
+    pub update_time: Option<String>,
+    pub end_time: Option<String>,
+    pub batch_stats: Option<BatchStats>,
+    /// Present once the batch has succeeded.
+    pub output: Option<BatchOutput>,
+    /// Set when the whole batch failed, as opposed to individual requests.
+    pub error: Option<RpcStatus>,
+}
+
+impl Batch {
+    /// Results of an inline batch, in request order. Returns `None` until the
+    /// batch has finished or when results were written to a file.
+    pub fn results(&self) -> Option<impl Iterator<Item = &BatchResult>> {
+        match &self.output {
+            Some(BatchOutput::Inline(results)) => Some(results.iter()),
+            _ => None,
+        }
+    }
+
+    /// The results file name, when results were written to a file.
+    pub fn results_file(&self) -> Option<&FileName> {
+        match &self.output {
+            Some(BatchOutput::File(file)) => Some(file),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BatchOperationWire {
+    name: BatchName,
+    #[serde(default)]
+    metadata: Option<BatchMetadataWire>,
+    #[serde(default)]
+    response: Option<BatchOutputWire>,
+    #[serde(default)]
+    error: Option<RpcStatus>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchMetadataWire {
+    #[serde(default)]
+    display_name: Option<String>,
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    state: Option<BatchState>,
+    #[serde(default)]
+    create_time: Option<String>,
+    #[serde(default)]
+    update_time: Option<String>,
+    #[serde(default)]
+    end_time: Option<String>,
+    #[serde(default)]
+    batch_stats: Option<BatchStats>,
+    #[serde(default)]
+    output: Option<BatchOutputWire>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchOutputWire {
+    #[serde(default)]
+    responses_file: Option<FileName>,
+    #[serde(default)]
+    inlined_responses: Option<InlinedResponsesWire>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InlinedResponsesWire {
+    #[serde(default)]
+    inlined_responses: Vec<BatchResultWire>,
+}
+
+impl From<BatchOutputWire> for Option<BatchOutput> {
+    fn from(wire: BatchOutputWire) -> Self {
+        match (wire.responses_file, wire.inlined_responses) {
+            (Some(file), _) => Some(BatchOutput::File(file)),
+            (None, Some(inlined)) => Some(BatchOutput::Inline(
+                inlined
+                    .inlined_responses
+                    .into_iter()
+                    .map(BatchResult::from)
+                    .collect(),
+            )),
+            (None, None) => None,
+        }
+    }
+}
+
+impl From<BatchOperationWire> for Batch {
+    fn from(wire: BatchOperationWire) -> Self {
+        let metadata = wire.metadata.unwrap_or(BatchMetadataWire {
+            display_name: None,
+            model: None,
+            state: None,
+            create_time: None,
+            update_time: None,
+            end_time: None,
+            batch_stats: None,
+            output: None,
+        });
+        let output = metadata
+            .output
+            .or(wire.response)
+            .and_then(Option::<BatchOutput>::from);
+        Self {
+            name: wire.name,
+            display_name: metadata.display_name,
+            model: metadata.model,
+            state: metadata.state.unwrap_or(BatchState::Unspecified),
+            create_time: metadata.create_time,
+            update_time: metadata.update_time,
+            end_time: metadata.end_time,
+            batch_stats: metadata.batch_stats,
+            output,
+            error: wire.error,
+        }
+    }
+}
+
+impl fmt::Display for BatchState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+            Self::Expired => "expired",
+            Self::Unspecified => "unspecified",
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchesListRequest {
+    pub page_token: Option<String>,
+    pub page_size: Option<i32>,
+}
+
+impl IntoQuery for BatchesListRequest {
+    fn into_query(self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+
+        if let Some(page_token) = self.page_token {
+            query.push(("pageToken".to_string(), page_token));
+        }
+
+        if let Some(page_size) = self.page_size {
+            query.push(("pageSize".to_string(), page_size.to_string()));
+        }
+
+        query
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchesListResponse {
+    #[serde(default, rename = "operations")]
+    pub batches: Vec<Batch>,
+    pub next_page_token: Option<String>,
+}
+
+/// Gemini's Batch API for asynchronous, discounted `generateContent` jobs.
+#[derive(Clone, Copy)]
+pub struct BatchesResource<'a> {
+    client: &'a GeminiClient,
+}
+
+impl<'a> BatchesResource<'a> {
+    pub(crate) fn new(client: &'a GeminiClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create(&self, request: &CreateBatchRequest) -> AiResult<AiResponse<Batch>> {
+        let [collection, action] = request.model.action_segments("batchGenerateContent");
+        self.client
+            .transport()
+            .post_json_segments(
+                "batches.create",
+                &[&collection, &action],
+                request,
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    pub async fn get(&self, name: &BatchName) -> AiResult<AiResponse<Batch>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "batches.get",
+                &[BatchName::COLLECTION, name.id()],
+                &[],
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    pub async fn list(
+        &self,
+        params: BatchesListRequest,
+    ) -> AiResult<AiResponse<BatchesListResponse>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "batches.list",
+                &[BatchName::COLLECTION],
+                &params.into_query(),
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    /// Ask the provider to stop a pending or running batch. Poll with
+    /// [`Self::get`] to observe the `Cancelled` state.
+    pub async fn cancel(&self, name: &BatchName) -> AiResult<AiResponse<()>> {
+        self.client
+            .transport()
+            .post_empty_segments_no_content(
+                "batches.cancel",
+                &[BatchName::COLLECTION, &format!("{}:cancel", name.id())],
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    /// Download the raw JSONL contents of a batch results file.
+    pub async fn download_results(&self, file: &FileName) -> AiResult<AiResponse<Vec<u8>>> {
+        self.client
+            .transport()
+            .get_bytes_prefixed_segments(
+                "batches.download_results",
+                "download",
+                &[FileName::COLLECTION, &format!("{}:download", file.id())],
+                &[("alt".into(), "media".into())],
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    /// Download and parse a batch results file, in the order the provider
+    /// wrote it. Inline results are available from [`Batch::results`].
+    ///
+    /// Fails with [`AiError::Decode`] only when a line is not a batch result
+    /// object; see [`parse_batch_results`].
+    pub async fn results(&self, file: &FileName) -> AiResult<AiResponse<Vec<BatchResult>>> {
+        let (jsonl, metadata) = self.download_results(file).await?.into_parts();
+        let jsonl = String::from_utf8_lossy(&jsonl);
+        match parse_batch_results(&jsonl).collect() {
+            Ok(results) => Ok(AiResponse::new(results, metadata)),
+            Err(error) => Err(AiError::Decode {
+                provider: AiProvider::Gemini,
+                operation: "batches.download_results",
+                metadata: Box::new(metadata),
+                error: error.into(),
+            }),
+        }
+    }
+
+    pub async fn delete(&self, name: &BatchName) -> AiResult<AiResponse<()>> {
+        self.client
+            .transport()
+            .delete_empty_segments(
+                "batches.delete",
+                &[BatchName::COLLECTION, name.id()],
+                decode_gemini_error,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        core::test_support::{
+            gemini_client, json_response, one_shot_server, request_json, request_line,
+        },
+        gemini::{Content, GeminiModel, Part},
+    };
+
+    fn prompt(text: &str) -> GenerateContentRequest {
+        GenerateContentRequest {
+            contents: vec![Content {
+                parts: vec![Part::text(text)],
+                role: None,
+            }],
+            ..Default::default()
+        }
+    }
+
+    const PENDING: &str = r#"{
+        "name":"batches/job1",
+        "metadata":{
+            "@type":"type.googleapis.com/google.ai.generativelanguage.v1main.GenerateContentBatch",
+            "model":"models/gemini-2.5-flash",
+            "displayName":"nightly",
+            "state":"BATCH_STATE_PENDING",
+            "batchStats":{"requestCount":"2","pendingRequestCount":"2"}
+        }
+    }"#;
+
+    #[tokio::test]
+    async fn batch_operations_use_exact_verbs_paths_and_bodies() {
+        let name = BatchName::new("job1").unwrap();
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], PENDING)).await;
+        let request = CreateBatchRequest::inline(
+            GeminiModel::Gemini2_5Flash,
+            [
+                BatchRequest::new("a", prompt("one")),
+                BatchRequest::new("b", prompt("two")),
+            ],
+        )
+        .display_name("nightly");
+        let batch = gemini_client(base_url)
+            .batches()
+            .create(&request)
+            .await
+            .unwrap();
+        assert_eq!(batch.data().name, name);
+        assert_eq!(batch.data().state, BatchState::Pending);
+        assert_eq!(batch.data().batch_stats.unwrap().request_count, 2);
+        let captured = captured.await.unwrap();
+        assert_eq!(
+            request_line(&captured),
+            "POST /v1beta/models/gemini-2.5-flash:batchGenerateContent HTTP/1.1"
+        );
+        assert_eq!(
+            request_json(&captured),
+            json!({"batch":{"displayName":"nightly","inputConfig":{"requests":{"requests":[
+                {"request":{"contents":[{"parts":[{"text":"one"}]}]},"metadata":{"key":"a"}},
+                {"request":{"contents":[{"parts":[{"text":"two"}]}]},"metadata":{"key":"b"}}
+            ]}}}})
+        );
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], PENDING)).await;
+        gemini_client(base_url)
+            .batches()
+            .create(&CreateBatchRequest::from_file(
+                GeminiModel::Gemini2_5Flash,
+                FileName::new("input").unwrap(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            request_json(&captured.await.unwrap()),
+            json!({"batch":{"inputConfig":{"fileName":"files/input"}}})
+        );
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], PENDING)).await;
+        gemini_client(base_url).batches().get(&name).await.unwrap();
+        assert_eq!(
+            request_line(&captured.await.unwrap()),
+            "GET /v1beta/batches/job1 HTTP/1.1"
+        );
+
+        let list = format!(r#"{{"operations":[{PENDING}],"nextPageToken":"next"}}"#);
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], &list)).await;
+        let response = gemini_client(base_url)
+            .batches()
+            .list(BatchesListRequest {
+                page_token: None,
+                page_size: Some(10),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.data().batches.len(), 1);
+        assert_eq!(
+            request_line(&captured.await.unwrap()),
+            "GET /v1beta/batches?pageSize=10 HTTP/1.1"
+        );
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], "{}")).await;
+        gemini_client(base_url)
+            .batches()
+            .cancel(&name)
+            .await
+            .unwrap();
+        assert_eq!(
+            request_line(&captured.await.unwrap()),
+            "POST /v1beta/batches/job1:cancel HTTP/1.1"
+        );
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], "{}")).await;
+        gemini_client(base_url)
+            .batches()
+            .delete(&name)
+            .await
+            .unwrap();
+        assert_eq!(
+            request_line(&captured.await.unwrap()),
+            "DELETE /v1beta/batches/job1 HTTP/1.1"
+        );
+    }
+
+    #[test]
+    fn finished_batches_yield_results_keyed_by_request() {
+        let batch: Batch = serde_json::from_value(json!({
+            "name":"batches/job1",
+            "metadata":{"state":"BATCH_STATE_SUCCEEDED","endTime":"2026-01-01T00:00:00Z"},
+            "done":true,
+            "response":{
+                "@type":"type.googleapis.com/google.ai.generativelanguage.v1main.GenerateContentBatchOutput",
+                "inlinedResponses":{"inlinedResponses":[
+                    {"metadata":{"key":"a"},"response":{
+                        "candidates":[{"content":{"parts":[{"text":"1"}],"role":"model"},"index":0}],
+                        "usageMetadata":{"promptTokenCount":1,"totalTokenCount":2}
+                    }},
+                    {"metadata":{"key":"b"},"error":{"code":3,"message":"bad request"}}
+                ]}
+            }
+        }))
+        .unwrap();
+        assert!(batch.state.is_terminal());
+        let results: Vec<_> = batch.results().unwrap().collect();
+        assert_eq!(results[0].key.as_deref(), Some("a"));
+        assert_eq!(results[0].result.as_ref().unwrap().candidates[0].index, 0);
+        assert_eq!(results[1].key.as_deref(), Some("b"));
+        assert!(matches!(
+            results[1].result,
+            Err(BatchResultError::Status(RpcStatus { code: 3, .. }))
+        ));
+
+        let future: Batch = serde_json::from_value(
+            json!({"name":"batches/x","metadata":{"state":"BATCH_STATE_NEW"}}),
+        )
+        .unwrap();
+        assert_eq!(future.state, BatchState::Unspecified);
+    }
+
+    #[test]
+    fn jsonl_input_and_file_results_round_trip_keys() {
+        let mut jsonl = Vec::new();
+        write_batch_jsonl(&mut jsonl, &[BatchRequest::new("a", prompt("one"))]).unwrap();
+        assert_eq!(
+            String::from_utf8(jsonl).unwrap(),
+            "{\"key\":\"a\",\"request\":{\"contents\":[{\"parts\":[{\"text\":\"one\"}]}]}}\n"
+        );
+
+        let output = concat!(
+            r#"{"key":"a","response":{"candidates":[],"usageMetadata":{"promptTokenCount":1,"totalTokenCount":1}}}"#,
+            "\n",
+            r#"{"key":"b","error":{"code":8,"message":"quota"}}"#,
+            "\n"
+        );
+        let results: Vec<_> = parse_batch_results(output)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].result.is_ok());
+        assert_eq!(results[1].key.as_deref(), Some("b"));
+        assert!(matches!(
+            &results[1].result,
+            Err(BatchResultError::Status(status)) if status.message == "quota"
+        ));
+    }
+
+    #[test]
+    fn undecodable_and_empty_results_stay_with_their_key() {
+        let output = concat!(
+            r#"{"key":"a","response":{"candidates":"not a list"}}"#,
+            "\n",
+            r#"{"key":"b"}"#,
+            "\n"
+        );
+        let results: Vec<_> = parse_batch_results(output)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(results[0].key.as_deref(), Some("a"));
+        assert!(matches!(
+            results[0].result,
+            Err(BatchResultError::Decode(_))
+        ));
+        assert_eq!(results[1].key.as_deref(), Some("b"));
+        assert_eq!(results[1].result, Err(BatchResultError::Missing));
+    }
+
+    #[tokio::test]
+    async fn file_results_are_downloaded_from_the_media_path_and_parsed() {
+        let file = FileName::new("out1").unwrap();
+        let output = concat!(
+            r#"{"key":"a","response":{"candidates":[],"usageMetadata":{"promptTokenCount":1,"totalTokenCount":1}}}"#,
+            "\n",
+            r#"{"key":"b","error":{"code":8,"message":"quota"}}"#,
+            "\n"
+        );
+        let (base_url, captured) = one_shot_server(
+            "v1beta",
+            json_response("200 OK", &[("x-goog-request-id", "req-1")], output),
+        )
+        .await;
+        let response = gemini_client(base_url)
+            .batches()
+            .results(&file)
+            .await
+            .unwrap();
+        assert_eq!(
+            request_line(&captured.await.unwrap()),
+            "GET /download/v1beta/files/out1:download?alt=media HTTP/1.1"
+        );
+        assert_eq!(response.metadata().request_id.as_deref(), Some("req-1"));
+        let results = response.into_inner();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].key.as_deref(), Some("a"));
+        assert!(matches!(
+            results[1].result,
+            Err(BatchResultError::Status(RpcStatus { code: 8, .. }))
+        ));
+
+        let (base_url, _captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], "{not json\n")).await;
+        let error = gemini_client(base_url)
+            .batches()
+            .results(&file)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            AiError::Decode {
+                operation: "batches.download_results",
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,436 @@
+use std::time::Duration;
+
+use serde::{ser::SerializeMap as _, Deserialize, Serialize, Serializer};
+
+use crate::{
+    error::{AiResponse, AiResult},
+    utils::IntoQuery,
+};
+
+use super::{
+    client::decode_gemini_error, CachedContentName, Content, GeminiClient, GeminiModelId, Tool,
+    ToolConfig,
+};
+
+/// When a context cache expires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheExpiration {
+    /// Time-to-live measured from when the cache is created or updated.
+    Ttl(Duration),
+    /// Absolute RFC 3339 timestamp, for example `2026-01-01T00:00:00Z`.
+    ExpireTime(String),
+}
+
+impl CacheExpiration {
+    fn field(&self) -> &'static str {
+        match self {
+            Self::Ttl(_) => "ttl",
+            Self::ExpireTime(_) => "expireTime",
+        }
+    }
+}
+
+impl Serialize for CacheExpiration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        match self {
+            Self::Ttl(ttl) => map.serialize_entry(self.field(), &duration_to_wire(*ttl))?,
+            Self::ExpireTime(expire_time) => map.serialize_entry(self.field(), expire_time)?,
+        }
+        map.end()
+    }
+}
+
+/// Google's JSON `Duration` form: decimal seconds with an `s` suffix.
+pub(crate) fn duration_to_wire(duration: Duration) -> String {
+    let nanos = duration.subsec_nanos();
+    if nanos == 0 {
+        return format!("{}s", duration.as_secs());
+    }
+    let fraction = format!("{nanos:09}");
+    format!("{}.{}s", duration.as_secs(), fraction.trim_end_matches('0'))
+}
+
+/// Request body for `cachedContents.create`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCachedContentRequest {
+    model: GeminiModelId,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    contents: Vec<Content>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_instruction: Option<Content>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_config: Option<ToolConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    expiration: Option<CacheExpiration>,
+}
+
+impl CreateCachedContentRequest {
+    /// Cache `contents` for use with `model`. The cache can only be used by
+    /// requests to the same model.
+    pub fn new(model: impl Into<GeminiModelId>, contents: Vec<Content>) -> Self {
+        Self {
+            model: model.into(),
+            contents,
+            system_instruction: None,
+            tools: None,
+            tool_config: None,
+            display_name: None,
+            expiration: None,
+        }
+    }
+
+    pub fn system_instruction(mut self, system_instruction: Content) -> Self {
+        self.system_instruction = Some(system_instruction);
+        self
+    }
+
+    pub fn tools(mut self, tools: Vec<Tool>) -> Self {
+        self.tools = Some(tools);
+        self
+    }
+
+    pub fn tool_config(mut self, tool_config: ToolConfig) -> Self {
+        self.tool_config = Some(tool_config);
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Defaults to the provider's TTL (currently one hour) when omitted.
+    pub fn expiration(mut self, expiration: CacheExpiration) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    pub fn ttl(self, ttl: Duration) -> Self {
+        self.expiration(CacheExpiration::Ttl(ttl))
+    }
+
+    pub fn expire_time(self, expire_time: impl Into<String>) -> Self {
+        self.expiration(CacheExpiration::ExpireTime(expire_time.into()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedContent {
+    pub name: CachedContentName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Model resource name, for example `models/gemini-2.5-flash`.
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_metadata: Option<CachedContentUsageMetadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedContentUsageMetadata {
+    #[serde(default)]
+    pub total_token_count: u64,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedContentsListRequest {
+    pub page_token: Option<String>,
+    pub page_size: Option<i32>,
+}
+
+impl IntoQuery for CachedContentsListRequest {
+    fn into_query(self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+
+        if let Some(page_token) = self.page_token {
+            query.push(("pageToken".to_string(), page_token));
+        }
+
+        if let Some(page_size) = self.page_size {
+            query.push(("pageSize".to_string(), page_size.to_string()));
+        }
+
+        query
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedContentsListResponse {
+    #[serde(default)]
+    pub cached_contents: Vec<CachedContent>,
+    pub next_page_token: Option<String>,
+}
+
+/// Gemini's context caching API.
+#[derive(Clone, Copy)]
+pub struct CachedContentsResource<'a> {
+    client: &'a GeminiClient,
+}
+
+impl<'a> CachedContentsResource<'a> {
+    pub(crate) fn new(client: &'a GeminiClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create(
+        &self,
+        request: &CreateCachedContentRequest,
+    ) -> AiResult<AiResponse<CachedContent>> {
+        self.client
+            .transport()
+            .post_json_segments(
+                "cached_contents.create",
+                &[CachedContentName::COLLECTION],
+                request,
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    pub async fn get(&self, name: &CachedContentName) -> AiResult<AiResponse<CachedContent>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "cached_contents.get",
+                &[CachedContentName::COLLECTION, name.id()],
+                &[],
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    pub async fn list(
+        &self,
+        params: CachedContentsListRequest,
+    ) -> AiResult<AiResponse<CachedContentsListResponse>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "cached_contents.list",
+                &[CachedContentName::COLLECTION],
+                &params.into_query(),
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    /// Change when a cache expires. Only the expiration can be updated.
+    pub async fn update(
+        &self,
+        name: &CachedContentName,
+        expiration: CacheExpiration,
+    ) -> AiResult<AiResponse<CachedContent>> {
+        self.client
+            .transport()
+            .patch_json_segments_with_query(
+                "cached_contents.update",
+                &[CachedContentName::COLLECTION, name.id()],
+                &[("updateMask".to_string(), expiration.field().to_string())],
+                &expiration,
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    pub async fn delete(&self, name: &CachedContentName) -> AiResult<AiResponse<()>> {
+        self.client
+            .transport()
+            .delete_empty_segments(
+                "cached_contents.delete",
+                &[CachedContentName::COLLECTION, name.id()],
+                decode_gemini_error,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_support::{
+            gemini_client, json_response, one_shot_server, request_json, request_line,
+        },
+        gemini::{GeminiModel, GenerateContentRequest, GenerateContentResponse, Part, Role},
+    };
+
+    const CACHE_BODY: &str = r#"{
+        "name":"cachedContents/abc123",
+        "model":"models/gemini-2.5-flash",
+        "createTime":"2026-01-01T00:00:00Z",
+        "updateTime":"2026-01-01T00:00:00Z",
+        "expireTime":"2026-01-01T01:00:00Z",
+        "usageMetadata":{"totalTokenCount":4096}
+    }"#;
+
+    #[test]
+    fn durations_use_google_json_duration_form() {
+        assert_eq!(duration_to_wire(Duration::from_secs(300)), "300s");
+        assert_eq!(duration_to_wire(Duration::from_millis(1500)), "1.5s");
+        assert_eq!(duration_to_wire(Duration::from_nanos(1)), "0.000000001s");
+    }
+
+    #[tokio::test]
+    async fn cache_operations_use_exact_verbs_paths_and_bodies() {
+        let name = CachedContentName::new("abc123").unwrap();
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], CACHE_BODY)).await;
+        let request = CreateCachedContentRequest::new(
+            GeminiModel::Gemini2_5Flash,
+            vec![Content {
+                parts: vec![Part::text("large document")],
+                role: Some(Role::User),
+            }],
+        )
+        .system_instruction(Content {
+            parts: vec![Part::text("Answer from the document.")],
+            role: None,
+        })
+        .display_name("docs")
+        .ttl(Duration::from_secs(600));
+        let response = gemini_client(base_url)
+            .cached_contents()
+            .create(&request)
+            .await
+            .unwrap();
+        assert_eq!(response.data().name, name);
+        assert_eq!(
+            response
+                .data()
+                .usage_metadata
+                .as_ref()
+                .unwrap()
+                .total_token_count,
+            4096
+        );
+        let captured = captured.await.unwrap();
+        assert_eq!(
+            request_line(&captured),
+            "POST /v1beta/cachedContents HTTP/1.1"
+        );
+        assert_eq!(
+            request_json(&captured),
+            serde_json::json!({
+                "model":"models/gemini-2.5-flash",
+                "contents":[{"parts":[{"text":"large document"}],"role":"user"}],
+                "systemInstruction":{"parts":[{"text":"Answer from the document."}]},
+                "displayName":"docs",
+                "ttl":"600s"
+            })
+        );
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], CACHE_BODY)).await;
+        gemini_client(base_url)
+            .cached_contents()
+            .get(&name)
+            .await
+            .unwrap();
+        assert_eq!(
+            request_line(&captured.await.unwrap()),
+            "GET /v1beta/cachedContents/abc123 HTTP/1.1"
+        );
+
+        let list = format!(r#"{{"cachedContents":[{CACHE_BODY}],"nextPageToken":"next"}}"#);
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], &list)).await;
+        let response = gemini_client(base_url)
+            .cached_contents()
+            .list(CachedContentsListRequest {
+                page_token: Some("page".into()),
+                page_size: Some(5),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.data().cached_contents.len(), 1);
+        assert_eq!(
+            request_line(&captured.await.unwrap()),
+            "GET /v1beta/cachedContents?pageToken=page&pageSize=5 HTTP/1.1"
+        );
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], CACHE_BODY)).await;
+        gemini_client(base_url)
+            .cached_contents()
+            .update(
+                &name,
+                CacheExpiration::ExpireTime("2026-01-02T00:00:00Z".into()),
+            )
+            .await
+            .unwrap();
+        let captured = captured.await.unwrap();
+        assert_eq!(
+            request_line(&captured),
+            "PATCH /v1beta/cachedContents/abc123?updateMask=expireTime HTTP/1.1"
+        );
+        assert_eq!(
+            request_json(&captured),
+            serde_json::json!({"expireTime":"2026-01-02T00:00:00Z"})
+        );
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], "{}")).await;
+        gemini_client(base_url)
+            .cached_contents()
+            .delete(&name)
+            .await
+            .unwrap();
+        assert_eq!(
+            request_line(&captured.await.unwrap()),
+            "DELETE /v1beta/cachedContents/abc123 HTTP/1.1"
+        );
+    }
+
+    #[test]
+    fn generate_content_references_cache_and_reports_cached_tokens() {
+        let request = GenerateContentRequest {
+            contents: vec![Content {
+                parts: vec![Part::text("question")],
+                role: Some(Role::User),
+            }],
+            cached_content: Some(CachedContentName::new("abc123").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "contents":[{"parts":[{"text":"question"}],"role":"user"}],
+                "cachedContent":"cachedContents/abc123"
+            })
+        );
+
+        let response: GenerateContentResponse = serde_json::from_value(serde_json::json!({
+            "candidates":[],
+            "usageMetadata":{
+                "promptTokenCount":4100,
+                "cachedContentTokenCount":4096,
+                "candidatesTokenCount":3,
+                "totalTokenCount":4103
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            response.usage_metadata.cached_content_token_count,
+            Some(4096)
+        );
+    }
+}
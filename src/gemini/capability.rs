@@ -0,0 +1,88 @@
+use super::{ThinkingConfig, ThinkingLevel};
+
+/// A model accepted by Gemini's `generateContent` method.
+///
+/// This trait is intentionally unsealed so applications can describe tuned
+/// models and new releases without waiting for a crate release.
+pub trait GeminiGenerateContentModel: Send + Sync + 'static {
+    const ID: &'static str;
+}
+
+/// Models that can return images (`responseModalities` containing `IMAGE`).
+pub trait SupportsImageOutput: GeminiGenerateContentModel {}
+
+/// Models that accept a `thinkingConfig`. The associated type is the setting
+/// vocabulary for the model family.
+pub trait SupportsThinking: GeminiGenerateContentModel {
+    type Thinking: IntoThinkingConfig;
+}
+
+/// Models that accept function declarations and hosted tools.
+pub trait SupportsFunctionCalling: GeminiGenerateContentModel {}
+
+/// Models that accept the hosted Google Search grounding tool.
+pub trait SupportsGoogleSearch: GeminiGenerateContentModel {}
+
+/// Models that accept `responseMimeType = "application/json"` with a schema.
+pub trait SupportsStructuredOutput: GeminiGenerateContentModel {}
+
+/// Models that can return audio (`responseModalities` containing `AUDIO`).
+pub trait SupportsAudioOutput: GeminiGenerateContentModel {}
+
+/// Models that can be used with `cachedContents`.
+pub trait SupportsCaching: GeminiGenerateContentModel {}
+
+/// Converts a model-family-specific thinking setting into the wire config.
+pub trait IntoThinkingConfig {
+    fn into_thinking_config(self) -> ThinkingConfig;
+}
+
+/// Thinking setting for Gemini 2.5 Flash and Flash-Lite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThinkingBudget {
+    /// Let the model decide how much to think.
+    Dynamic,
+    Off,
+    Tokens(u32),
+}
+
+impl IntoThinkingConfig for ThinkingBudget {
+    fn into_thinking_config(self) -> ThinkingConfig {
+        let budget = match self {
+            Self::Dynamic => -1,
+            Self::Off => 0,
+            Self::Tokens(tokens) => i32::try_from(tokens).unwrap_or(i32::MAX),
+        };
+        ThinkingConfig {
+            thinking_budget: Some(budget),
+            ..Default::default()
+        }
+    }
+}
+
+/// Gemini 3 Flash-class models accept every thinking level.
+impl IntoThinkingConfig for ThinkingLevel {
+    fn into_thinking_config(self) -> ThinkingConfig {
+        ThinkingConfig {
+            thinking_level: Some(self),
+            ..Default::default()
+        }
+    }
+}
+
+/// Thinking levels accepted by Gemini 3 Pro-class models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProThinkingLevel {
+    Low,
+    High,
+}
+
+impl IntoThinkingConfig for ProThinkingLevel {
+    fn into_thinking_config(self) -> ThinkingConfig {
+        let level = match self {
+            Self::Low => ThinkingLevel::Low,
+            Self::High => ThinkingLevel::High,
+        };
+        level.into_thinking_config()
+    }
+}
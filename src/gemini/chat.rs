@@ -0,0 +1,433 @@
+#[cfg(feature = "stream")]
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "stream")]
+use futures::Stream;
+
+use crate::error::{AiResponse, AiResult};
+#[cfg(feature = "stream")]
+use crate::stream::{AiStream, AiStreamError};
+
+#[cfg(feature = "stream")]
+use super::GeminiStreamAccumulator;
+use super::{
+    Content, CountTokensGenerateContentRequest, CountTokensRequest, GeminiClient, GeminiModelId,
+    GenerateContentRequest, GenerateContentResponse, Part, Role,
+};
+
+/// A multi-turn conversation with a Gemini model.
+///
+/// Each `send` appends the user turn and the first candidate's reply to the
+/// history, so the next request carries the whole conversation. Replies are
+/// recorded part for part, which keeps function calls and thought signatures
+/// intact for thinking models. A failed request, or a response without
+/// candidate content, leaves the history unchanged.
+///
+/// The history is a plain `Vec<Content>` and can be serialized with serde to
+/// persist a conversation, then restored with [`Self::with_history`].
+#[derive(Clone)]
+pub struct GeminiChat<'a> {
+    client: &'a GeminiClient,
+    model: GeminiModelId,
+    settings: GenerateContentRequest,
+    history: Vec<Content>,
+}
+
+impl<'a> GeminiChat<'a> {
+    pub(crate) fn new(client: &'a GeminiClient, model: GeminiModelId) -> Self {
+        Self {
+            client,
+            model,
+            settings: GenerateContentRequest::default(),
+            history: Vec::new(),
+        }
+    }
+
+    /// System instruction, tools, and generation config sent with every turn.
+    /// The request's `contents` are appended to the history.
+    pub fn settings(mut self, mut request: GenerateContentRequest) -> Self {
+        self.history.append(&mut request.contents);
+        self.settings = request;
+        self
+    }
+
+    /// Replace the history, for example with one restored from storage.
+    pub fn with_history(mut self, history: Vec<Content>) -> Self {
+        self.history = history;
+        self
+    }
+
+    pub fn model(&self) -> &GeminiModelId {
+        &self.model
+    }
+
+    pub fn history(&self) -> &[Content] {
+        &self.history
+    }
+
+    pub fn into_history(self) -> Vec<Content> {
+        self.history
+    }
+
+    /// Append content without sending it, such as a model turn produced
+    /// elsewhere.
+    pub fn push(&mut self, content: Content) {
+        self.history.push(content);
+    }
+
+    /// Send a user turn and record the reply.
+    ///
+    /// Function results are sent the same way, as
+    /// [`Part::FunctionResponse`] parts.
+    pub async fn send(
+        &mut self,
+        parts: Vec<Part>,
+    ) -> AiResult<AiResponse<GenerateContentResponse>> {
+        let user = user_content(parts);
+        let response = self
+            .client
+            .generate_content(self.model.clone(), self.request_with(&user))
+            .await?;
+        record(&mut self.history, user, response.data());
+        Ok(response)
+    }
+
+    pub async fn send_text(
+        &mut self,
+        text: impl Into<String>,
+    ) -> AiResult<AiResponse<GenerateContentResponse>> {
+        self.send(vec![Part::text(text)]).await
+    }
+
+    #[cfg(feature = "stream")]
+    /// Send a user turn and stream the reply. The merged reply is recorded
+    /// once the stream ends without an error.
+    ///
+    /// Streaming support requires the `stream` crate feature.
+    pub async fn send_streamed(
+        &mut self,
+        parts: Vec<Part>,
+    ) -> AiResult<AiResponse<GeminiChatStream<'_>>> {
+        let user = user_content(parts);
+        let response = self
+            .client
+            .generate_content_streamed(self.model.clone(), self.request_with(&user))
+            .await?;
+        let (inner, metadata) = response.into_parts();
+        Ok(AiResponse::new(
+            GeminiChatStream {
+                inner,
+                accumulator: GeminiStreamAccumulator::new(),
+                history: &mut self.history,
+                user: Some(user),
+            },
+            metadata,
+        ))
+    }
+
+    /// Keep only the last `max_turns` turns.
+    ///
+    /// A turn starts with a user message and includes any function calls
+    /// and responses that follow it, so call/response pairs are never split.
+    pub fn trim_to_turns(&mut self, max_turns: usize) {
+        let starts = self.turn_starts();
+        let cut = match starts.len().checked_sub(max_turns) {
+            Some(0) | None => return,
+            Some(dropped) => starts.get(dropped).copied(),
+        };
+        self.history.drain(..cut.unwrap_or(self.history.len()));
+    }
+
+    /// Drop the oldest turns until the next request, including settings,
+    /// fits in `max_tokens` according to `countTokens`. Returns the final
+    /// token count.
+    ///
+    /// Makes one `countTokens` call, plus one per dropped turn. The history
+    /// may end up empty when the settings alone exceed the budget.
+    pub async fn trim_to_token_budget(&mut self, max_tokens: u64) -> AiResult<u64> {
+        loop {
+            let request =
+                CountTokensRequest::from_generate_content(CountTokensGenerateContentRequest {
+                    model: self.model.clone(),
+                    request: GenerateContentRequest {
+                        contents: self.history.clone(),
+                        ..self.settings.clone()
+                    },
+                });
+            let total = self
+                .client
+                .count_tokens(self.model.clone(), request)
+                .await?
+                .data()
+                .total_tokens();
+            if total <= max_tokens || self.history.is_empty() {
+                return Ok(total);
+            }
+            let cut = self
+                .turn_starts()
+                .into_iter()
+                .find(|&start| start > 0)
+                .unwrap_or(self.history.len());
+            self.history.drain(..cut);
+        }
+    }
+
+    fn request_with(&self, user: &Content) -> GenerateContentRequest {
+        let mut contents = Vec::with_capacity(self.history.len() + 1);
+        contents.extend_from_slice(&self.history);
+        contents.push(user.clone());
+        GenerateContentRequest {
+            contents,
+            ..self.settings.clone()
+        }
+    }
+
+    fn turn_starts(&self) -> Vec<usize> {
+        self.history
+            .iter()
+            .enumerate()
+            .filter(|(_, content)| is_turn_start(content))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+#[cfg(feature = "stream")]
+/// Reply stream returned by [`GeminiChat::send_streamed`].
+///
+/// Yields chunks unchanged and records the merged reply in the chat history
+/// when the stream ends.
+pub struct GeminiChatStream<'c> {
+    inner: AiStream<GenerateContentResponse>,
+    accumulator: GeminiStreamAccumulator,
+    history: &'c mut Vec<Content>,
+    /// Taken once the reply is recorded or the stream fails.
+    user: Option<Content>,
+}
+
+#[cfg(feature = "stream")]
+impl Stream for GeminiChatStream<'_> {
+    type Item = Result<GenerateContentResponse, AiStreamError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let item = std::task::ready!(Pin::new(&mut this.inner).poll_next(cx));
+        match &item {
+            Some(Ok(chunk)) => {
+                // A blocked prompt is reported to the caller through the
+                // chunk itself; the chat simply records nothing.
+                let _ = this.accumulator.push(chunk.clone());
+            }
+            Some(Err(_)) => this.user = None,
+            None => {
+                if let (Some(user), Some(response)) =
+                    (this.user.take(), this.accumulator.response())
+                {
+                    record(this.history, user, &response);
+                }
+            }
+        }
+        Poll::Ready(item)
+    }
+}
+
+fn user_content(parts: Vec<Part>) -> Content {
+    Content {
+        parts,
+        role: Some(Role::User),
+    }
+}
+
+fn is_turn_start(content: &Content) -> bool {
+    content.role == Some(Role::User)
+        && !content
+            .parts
+            .iter()
+            .any(|part| matches!(part.unsigned(), Part::FunctionResponse(_)))
+}
+
+fn record(history: &mut Vec<Content>, user: Content, response: &GenerateContentResponse) {
+    let Some(mut reply) = response
+        .candidates
+        .first()
+        .and_then(|candidate| candidate.content.clone())
+        .filter(|content| !content.parts.is_empty())
+    else {
+        return;
+    };
+    reply.role = Some(Role::Model);
+    history.push(user);
+    history.push(reply);
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::core::test_support::{gemini_client, json_response, request_json, sequence_server};
+
+    fn model() -> GeminiModelId {
+        GeminiModelId::new("models/gemini-test").unwrap()
+    }
+
+    fn text_turn(role: Role, text: &str) -> Content {
+        Content {
+            parts: vec![Part::text(text)],
+            role: Some(role),
+        }
+    }
+
+    #[tokio::test]
+    async fn send_records_reply_with_thought_signatures() {
+        let reply = json!({
+            "candidates":[{"content":{"role":"model","parts":[
+                {"text":"thinking...","thought":true},
+                {"functionCall":{"name":"lookup","args":{"q":"x"}},"thoughtSignature":"c2ln"}
+            ]},"index":0}],
+            "usageMetadata":{"promptTokenCount":1,"totalTokenCount":2}
+        });
+        let done = json!({
+            "candidates":[{"content":{"role":"model","parts":[{"text":"Done"}]},"index":0}],
+            "usageMetadata":{"promptTokenCount":1,"totalTokenCount":2}
+        });
+        let (base_url, requests) = sequence_server(
+            "v1beta",
+            vec![
+                json_response("200 OK", &[], &reply.to_string()),
+                json_response("200 OK", &[], &done.to_string()),
+            ],
+        )
+        .await;
+        let client = gemini_client(base_url);
+        let mut chat = client.chat(model());
+        chat.send_text("Look it up").await.unwrap();
+        chat.send(vec![Part::FunctionResponse(
+            serde_json::from_value(json!({"name":"lookup","response":{"ok":true}})).unwrap(),
+        )])
+        .await
+        .unwrap();
+
+        let requests = requests.await.unwrap();
+        assert!(requests[1].starts_with("POST /v1beta/models/gemini-test:generateContent "));
+        let contents = &request_json(&requests[1])["contents"];
+        assert_eq!(contents.as_array().unwrap().len(), 3);
+        assert_eq!(contents[1]["role"], "model");
+        assert_eq!(
+            contents[1]["parts"],
+            reply["candidates"][0]["content"]["parts"]
+        );
+        assert_eq!(chat.history().len(), 4);
+        assert_eq!(chat.history()[1].parts[1].thought_signature(), Some("c2ln"));
+
+        let restored: Vec<Content> =
+            serde_json::from_str(&serde_json::to_string(chat.history()).unwrap()).unwrap();
+        assert_eq!(restored, chat.history());
+    }
+
+    #[test]
+    fn trim_to_turns_keeps_function_exchanges_together() {
+        let client = gemini_client("http://127.0.0.1:9/v1beta".into());
+        let call = Content {
+            parts: vec![Part::FunctionCall(
+                serde_json::from_value(json!({"name":"f"})).unwrap(),
+            )],
+            role: Some(Role::Model),
+        };
+        let response = Content {
+            parts: vec![Part::FunctionResponse(
+                serde_json::from_value(json!({"name":"f","response":{}})).unwrap(),
+            )],
+            role: Some(Role::User),
+        };
+        let mut chat = client.chat(model()).with_history(vec![
+            text_turn(Role::User, "one"),
+            text_turn(Role::Model, "1"),
+            text_turn(Role::User, "two"),
+            call,
+            response,
+            text_turn(Role::Model, "2"),
+            text_turn(Role::User, "three"),
+            text_turn(Role::Model, "3"),
+        ]);
+        chat.trim_to_turns(2);
+        assert_eq!(chat.history().len(), 6);
+        assert_eq!(chat.history()[0].parts[0].as_text(), Some("two"));
+        chat.trim_to_turns(0);
+        assert!(chat.history().is_empty());
+    }
+
+    #[tokio::test]
+    async fn trim_to_token_budget_drops_oldest_turns() {
+        let (base_url, requests) = sequence_server(
+            "v1beta",
+            vec![
+                json_response("200 OK", &[], r#"{"totalTokens":120}"#),
+                json_response("200 OK", &[], r#"{"totalTokens":80}"#),
+            ],
+        )
+        .await;
+        let client = gemini_client(base_url);
+        let mut chat = client
+            .chat(model())
+            .settings(GenerateContentRequest {
+                system_instruction: Some(text_turn(Role::User, "Be brief")),
+                ..Default::default()
+            })
+            .with_history(vec![
+                text_turn(Role::User, "one"),
+                text_turn(Role::Model, "1"),
+                text_turn(Role::User, "two"),
+                text_turn(Role::Model, "2"),
+            ]);
+        assert_eq!(chat.trim_to_token_budget(100).await.unwrap(), 80);
+        assert_eq!(chat.history().len(), 2);
+
+        let requests = requests.await.unwrap();
+        let first = request_json(&requests[0]);
+        assert_eq!(
+            first["generateContentRequest"]["model"],
+            "models/gemini-test"
+        );
+        assert_eq!(
+            first["generateContentRequest"]["systemInstruction"]["parts"][0]["text"],
+            "Be brief"
+        );
+        assert_eq!(
+            request_json(&requests[1])["generateContentRequest"]["contents"][0]["parts"][0]["text"],
+            "two"
+        );
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn send_streamed_records_merged_reply() {
+        use futures::StreamExt;
+
+        use crate::core::test_support::chunked_server;
+
+        let body = r#"[{"candidates":[{"content":{"role":"model","parts":[{"text":"Hel"}]},"index":0}],"usageMetadata":{"promptTokenCount":1,"totalTokenCount":2}},
+{"candidates":[{"content":{"role":"model","parts":[{"text":"lo"}]},"finishReason":"STOP","index":0}],"usageMetadata":{"promptTokenCount":1,"totalTokenCount":3}}]"#;
+        let (base_url, _) = chunked_server("v1beta", &[], vec![body.as_bytes().to_vec()]).await;
+        let client = gemini_client(base_url);
+        let mut chat = client.chat(model());
+        let mut stream = chat
+            .send_streamed(vec![Part::text("Hi")])
+            .await
+            .unwrap()
+            .into_inner();
+        let mut chunks = 0;
+        while let Some(chunk) = stream.next().await {
+            chunk.unwrap();
+            chunks += 1;
+        }
+        drop(stream);
+        assert_eq!(chunks, 2);
+        assert_eq!(chat.history().len(), 2);
+        assert_eq!(chat.history()[1].parts, [Part::text("Hello")]);
+        assert_eq!(chat.history()[1].role, Some(Role::Model));
+    }
+}
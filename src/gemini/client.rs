@@ -10,11 +10,17 @@ use crate::{
     utils::IntoQuery,
 };
 #[cfg(feature = "stream")]
-use crate::{core::json_array, stream::AiStream};
+use crate::{
+    core::{json_array, sse},
+    stream::{AiStream, SseJsonEvent},
+};
 
 use super::{
-    CountTokensRequest, CountTokensResponse, GeminiModel, GenerateContentRequest,
-    GenerateContentResponse, ModelInfo, ModelsListRequest, ModelsListResponse,
+    embeddings::BatchEmbedContentsWire, BatchEmbedContentsResponse, BatchesResource,
+    CachedContentsResource, CountTokensRequest, CountTokensResponse, EmbedContentRequest,
+    EmbedContentResponse, GeminiChat, GeminiModelId, GenerateContentRequest,
+    GenerateContentResponse, ModelInfo, ModelsListRequest, ModelsListResponse, ModelsResource,
+    OperationsResource, VideosResource,
 };
 
 const BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
@@ -96,7 +102,7 @@ impl GeminiClientBuilder {
     }
 }
 
-fn decode_gemini_error(bytes: &[u8], body: BodySnippet) -> ProviderApiError {
+pub(crate) fn decode_gemini_error(bytes: &[u8], body: BodySnippet) -> ProviderApiError {
     #[derive(serde::Deserialize)]
     struct Envelope {
         error: Option<Detail>,
@@ -138,6 +144,46 @@ impl GeminiClient {
         GeminiClientBuilder::default()
     }
 
+    /// Compile-time checked generation with typed model markers.
+    pub fn models(&self) -> ModelsResource<'_> {
+        ModelsResource::new(self)
+    }
+
+    /// Start a multi-turn chat that keeps its own history.
+    pub fn chat(&self, model: impl Into<GeminiModelId>) -> GeminiChat<'_> {
+        GeminiChat::new(self, model.into())
+    }
+
+    /// Access Gemini's Batch API.
+    pub fn batches(&self) -> BatchesResource<'_> {
+        BatchesResource::new(self)
+    }
+
+    /// Open bidirectional Live API sessions.
+    #[cfg(feature = "gemini-live")]
+    pub fn live(&self) -> super::LiveResource<'_> {
+        super::LiveResource::new(self)
+    }
+
+    /// Poll long-running operations such as video generation.
+    pub fn operations(&self) -> OperationsResource<'_> {
+        OperationsResource::new(self)
+    }
+
+    /// Generate videos with Veo.
+    pub fn videos(&self) -> VideosResource<'_> {
+        VideosResource::new(self)
+    }
+
+    /// Access Gemini's context caching API.
+    pub fn cached_contents(&self) -> CachedContentsResource<'_> {
+        CachedContentsResource::new(self)
+    }
+
+    pub(crate) fn transport(&self) -> &HttpTransport {
+        &self.transport
+    }
+
     /// List Gemini models with default pagination.
     pub async fn list_models(&self) -> AiResult<AiResponse<ModelsListResponse>> {
         self.list_models_with_params(ModelsListRequest::default())
@@ -159,13 +205,16 @@ impl GeminiClient {
             .await
     }
 
-    /// Retrieve metadata for a known Gemini model.
-    pub async fn get_model(&self, model: GeminiModel) -> AiResult<AiResponse<ModelInfo>> {
-        let model = model.to_string();
+    /// Retrieve metadata for a base or tuned Gemini model.
+    pub async fn get_model(
+        &self,
+        model: impl Into<GeminiModelId>,
+    ) -> AiResult<AiResponse<ModelInfo>> {
+        let model = model.into();
         self.transport
             .get_json_segments(
                 "models.retrieve",
-                &["models", &model],
+                &[model.collection(), model.id()],
                 &[],
                 decode_gemini_error,
             )
@@ -175,14 +224,14 @@ impl GeminiClient {
     /// Count input tokens for a Gemini content request.
     pub async fn count_tokens(
         &self,
-        model: GeminiModel,
+        model: impl Into<GeminiModelId>,
         request: CountTokensRequest,
     ) -> AiResult<AiResponse<CountTokensResponse>> {
-        let model_action = format!("{model}:countTokens");
+        let [collection, action] = model.into().action_segments("countTokens");
         self.transport
             .post_json_segments(
                 "models.count_tokens",
-                &["models", &model_action],
+                &[&collection, &action],
                 &request,
                 decode_gemini_error,
             )
@@ -192,35 +241,71 @@ impl GeminiClient {
     /// Generate content through Gemini's native `generateContent` API.
     pub async fn generate_content(
         &self,
-        model: GeminiModel,
+        model: impl Into<GeminiModelId>,
         request: GenerateContentRequest,
     ) -> AiResult<AiResponse<GenerateContentResponse>> {
-        let model_action = format!("{model}:generateContent");
+        let [collection, action] = model.into().action_segments("generateContent");
         self.transport
             .post_json_segments(
                 "models.generate_content",
-                &["models", &model_action],
+                &[&collection, &action],
+                &request,
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    /// Embed a single content item.
+    pub async fn embed_content(
+        &self,
+        model: impl Into<GeminiModelId>,
+        request: EmbedContentRequest,
+    ) -> AiResult<AiResponse<EmbedContentResponse>> {
+        let [collection, action] = model.into().action_segments("embedContent");
+        self.transport
+            .post_json_segments(
+                "models.embed_content",
+                &[&collection, &action],
                 &request,
                 decode_gemini_error,
             )
             .await
     }
 
+    /// Embed several content items in one request. Embeddings are returned in
+    /// request order.
+    pub async fn batch_embed_contents(
+        &self,
+        model: impl Into<GeminiModelId>,
+        requests: &[EmbedContentRequest],
+    ) -> AiResult<AiResponse<BatchEmbedContentsResponse>> {
+        let model = model.into();
+        let [collection, action] = model.action_segments("batchEmbedContents");
+        self.transport
+            .post_json_segments(
+                "models.batch_embed_contents",
+                &[&collection, &action],
+                &BatchEmbedContentsWire::new(&model, requests),
+                decode_gemini_error,
+            )
+            .await
+    }
+
     #[cfg(feature = "stream")]
     /// Stream Gemini `generateContent` results.
     ///
     /// Streaming support requires the `stream` crate feature.
     pub async fn generate_content_streamed(
         &self,
-        model: GeminiModel,
+        model: impl Into<GeminiModelId>,
         request: GenerateContentRequest,
     ) -> AiResult<AiResponse<AiStream<GenerateContentResponse>>> {
-        let model_action = format!("{model}:streamGenerateContent");
+        let [collection, action] = model.into().action_segments("streamGenerateContent");
         let response = self
             .transport
             .post_json_stream_segments(
                 "models.stream_generate_content",
-                &["models", &model_action],
+                &[&collection, &action],
                 &request,
                 decode_gemini_error,
             )
@@ -231,14 +316,48 @@ impl GeminiClient {
             metadata,
         ))
     }
+
+    #[cfg(feature = "stream")]
+    /// Stream Gemini `generateContent` results as server-sent events.
+    ///
+    /// This uses `streamGenerateContent?alt=sse`. Each item is an
+    /// `SseJsonEvent` carrying the event metadata and the complete raw JSON
+    /// chunk alongside the typed response, matching the OpenAI stream
+    /// envelope. Streaming support requires the `stream` crate feature.
+    pub async fn generate_content_sse(
+        &self,
+        model: impl Into<GeminiModelId>,
+        request: GenerateContentRequest,
+    ) -> AiResult<AiResponse<AiStream<SseJsonEvent<GenerateContentResponse>>>> {
+        let [collection, action] = model.into().action_segments("streamGenerateContent");
+        let response = self
+            .transport
+            .post_json_stream_segments_with_query(
+                "models.stream_generate_content_sse",
+                &[&collection, &action],
+                &[("alt".to_owned(), "sse".to_owned())],
+                &request,
+                decode_gemini_error,
+            )
+            .await?;
+        let (bytes, metadata) = response.into_parts();
+        Ok(AiResponse::new(
+            sse::json_events(
+                bytes,
+                AiProvider::Gemini,
+                "models.stream_generate_content_sse",
+            ),
+            metadata,
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[cfg(feature = "stream")]
-    use crate::core::test_support::chunked_server;
     use crate::core::test_support::{cross_origin_redirect_server, json_response, one_shot_server};
+    #[cfg(feature = "stream")]
+    use crate::{core::test_support::chunked_server, gemini::GeminiModel};
 
     #[test]
     fn builder_debug_redacts_credentials_and_headers() {
@@ -306,6 +425,48 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn tuned_and_unlisted_model_ids_build_request_paths() {
+        let body =
+            r#"{"candidates":[],"usageMetadata":{"promptTokenCount":1,"totalTokenCount":1}}"#;
+        let (base_url, request) =
+            one_shot_server("v1beta", json_response("200 OK", &[], body)).await;
+        GeminiClient::builder()
+            .api_key("key".into())
+            .base_url(base_url)
+            .build()
+            .unwrap()
+            .generate_content(
+                GeminiModelId::new("tunedModels/my-tuned-model").unwrap(),
+                GenerateContentRequest::default(),
+            )
+            .await
+            .unwrap();
+        assert!(request
+            .await
+            .unwrap()
+            .starts_with("POST /v1beta/tunedModels/my-tuned-model:generateContent HTTP/1.1\r\n"));
+
+        let body = r#"{"totalTokens":3}"#;
+        let (base_url, request) =
+            one_shot_server("v1beta", json_response("200 OK", &[], body)).await;
+        GeminiClient::builder()
+            .api_key("key".into())
+            .base_url(base_url)
+            .build()
+            .unwrap()
+            .count_tokens(
+                GeminiModelId::new("models/gemini-4-flash-preview").unwrap(),
+                CountTokensRequest::from_contents(vec![]),
+            )
+            .await
+            .unwrap();
+        assert!(request
+            .await
+            .unwrap()
+            .starts_with("POST /v1beta/models/gemini-4-flash-preview:countTokens HTTP/1.1\r\n"));
+    }
+
     #[tokio::test]
     async fn structured_json_api_error_survives_public_client_boundary() {
         let body = r#"{"error":{"code":429,"message":"private Gemini detail","status":"RESOURCE_EXHAUSTED"}}"#;
@@ -368,6 +529,59 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn sse_stream_requests_alt_sse_and_preserves_raw_chunks() {
+        use futures::StreamExt;
+
+        use crate::gemini::GeminiStreamAccumulator;
+
+        let first = r#"{"candidates":[{"content":{"role":"model","parts":[{"text":"Hel"}]},"index":0}],"usageMetadata":{"promptTokenCount":2,"totalTokenCount":3},"modelVersion":"gemini-3.1-flash-lite"}"#;
+        let second = r#"{"candidates":[{"content":{"parts":[{"text":"lo"}]},"finishReason":"STOP","index":0}],"usageMetadata":{"promptTokenCount":2,"candidatesTokenCount":2,"totalTokenCount":4}}"#;
+        let body = format!("data: {first}\r\n\r\ndata: {second}\r\n\r\n");
+        let (head, tail) = body.split_at(40);
+        let (base_url, request) = chunked_server(
+            "v1beta",
+            &[("x-goog-request-id", "req_gemini_sse")],
+            vec![head.as_bytes().to_vec(), tail.as_bytes().to_vec()],
+        )
+        .await;
+        let response = GeminiClient::builder()
+            .api_key("key".into())
+            .base_url(base_url)
+            .build()
+            .unwrap()
+            .generate_content_sse(
+                GeminiModel::Gemini3_1FlashLite,
+                GenerateContentRequest {
+                    contents: vec![],
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            response.metadata().request_id.as_deref(),
+            Some("req_gemini_sse")
+        );
+        let events: Vec<_> = response.into_inner().collect().await;
+        assert_eq!(events.len(), 2);
+        let first_event = events[0].as_ref().unwrap();
+        assert_eq!(first_event.raw()["modelVersion"], "gemini-3.1-flash-lite");
+        assert!(first_event.metadata().event().is_none());
+
+        let mut accumulator = GeminiStreamAccumulator::new();
+        for event in events {
+            accumulator.push(event.unwrap().into_data()).unwrap();
+        }
+        assert_eq!(accumulator.text(0), "Hello");
+
+        let request = request.await.unwrap();
+        assert!(request.starts_with(
+            "POST /v1beta/models/gemini-3.1-flash-lite:streamGenerateContent?alt=sse HTTP/1.1\r\n"
+        ));
+    }
+
     #[cfg(feature = "stream")]
     #[tokio::test]
     async fn stream_handshake_returns_metadata_wire_path_and_provider_errors() {
@@ -391,8 +605,7 @@ mod tests {
                 GeminiModel::Gemini3_1FlashLite,
                 GenerateContentRequest {
                     contents: vec![],
-                    safety_settings: None,
-                    generation_config: None,
+                    ..Default::default()
                 },
             )
             .await
@@ -429,8 +642,7 @@ mod tests {
                 GeminiModel::Gemini3_1FlashLite,
                 GenerateContentRequest {
                     contents: vec![],
-                    safety_settings: None,
-                    generation_config: None,
+                    ..Default::default()
                 },
             )
             .await;
@@ -473,8 +685,7 @@ mod tests {
                 GeminiModel::Gemini3_1FlashLite,
                 GenerateContentRequest {
                     contents: vec![],
-                    safety_settings: None,
-                    generation_config: None,
+                    ..Default::default()
                 },
             )
             .await;
@@ -0,0 +1,272 @@
+use std::{collections::HashSet, fmt, marker::PhantomData};
+
+use super::{
+    CachedContentName, Content, FunctionDeclaration, GeminiGenerateContentModel, GeminiModelConfig,
+    GenerateContentRequest, GoogleSearch, Part, Role, SafetySetting, SupportsCaching,
+    SupportsFunctionCalling, SupportsGoogleSearch, SupportsStructuredOutput, Tool, ToolConfig,
+};
+
+type RequestState<Output, Tools, Cache> = fn() -> (Output, Tools, Cache);
+
+/// Model-independent content for a typed `generateContent` call.
+///
+/// Generation settings that depend on the model live in
+/// [`GeminiModelConfig`]. The type parameters record capabilities used by this
+/// request so the selected model is checked at `ModelsResource::generate_content`.
+pub struct GeminiGenerateContentRequest<
+    Output = GeminiPlainTextOutput,
+    Tools = GeminiNoTools,
+    Cache = GeminiNoCachedContent,
+> {
+    request: GenerateContentRequest,
+    state: PhantomData<RequestState<Output, Tools, Cache>>,
+}
+
+impl GeminiGenerateContentRequest {
+    pub fn builder() -> Self {
+        Self {
+            request: GenerateContentRequest::default(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<O, T, C> Clone for GeminiGenerateContentRequest<O, T, C> {
+    fn clone(&self) -> Self {
+        Self {
+            request: self.request.clone(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<O, T, C> fmt::Debug for GeminiGenerateContentRequest<O, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeminiGenerateContentRequest")
+            .field("request", &"[redacted]")
+            .finish()
+    }
+}
+
+impl<O, T, C> GeminiGenerateContentRequest<O, T, C> {
+    fn with_state<O2, T2, C2>(self) -> GeminiGenerateContentRequest<O2, T2, C2> {
+        GeminiGenerateContentRequest {
+            request: self.request,
+            state: PhantomData,
+        }
+    }
+
+    /// Append one content turn.
+    pub fn content(mut self, content: Content) -> Self {
+        self.request.contents.push(content);
+        self
+    }
+
+    /// Append several content turns.
+    pub fn contents(mut self, contents: impl IntoIterator<Item = Content>) -> Self {
+        self.request.contents.extend(contents);
+        self
+    }
+
+    /// Append a user turn containing one text part.
+    pub fn user_text(self, text: impl Into<String>) -> Self {
+        self.content(Content {
+            parts: vec![Part::text(text)],
+            role: Some(Role::User),
+        })
+    }
+
+    pub fn system_instruction(mut self, text: impl Into<String>) -> Self {
+        self.request.system_instruction = Some(Content {
+            parts: vec![Part::text(text)],
+            role: None,
+        });
+        self
+    }
+
+    pub fn safety_setting(mut self, setting: SafetySetting) -> Self {
+        self.request
+            .safety_settings
+            .get_or_insert_with(HashSet::new)
+            .replace(setting);
+        self
+    }
+
+    /// Declare a function the model may call.
+    pub fn function(
+        mut self,
+        declaration: FunctionDeclaration,
+    ) -> GeminiGenerateContentRequest<O, GeminiUsesTools, C> {
+        let tools = self.request.tools.get_or_insert_with(Vec::new);
+        match tools
+            .iter_mut()
+            .find_map(|tool| tool.function_declarations.as_mut())
+        {
+            Some(declarations) => declarations.push(declaration),
+            None => tools.push(Tool::functions(vec![declaration])),
+        }
+        self.with_state()
+    }
+
+    /// Add a function-declaration tool or a hosted tool such as code
+    /// execution.
+    ///
+    /// Use [`Self::google_search`] for Search grounding alone, so the request
+    /// also compiles for image models that support grounding but not tools.
+    pub fn tool(mut self, tool: Tool) -> GeminiGenerateContentRequest<O, GeminiUsesTools, C> {
+        self.request.tools.get_or_insert_with(Vec::new).push(tool);
+        self.with_state()
+    }
+
+    /// Ground responses with Google Search results.
+    pub fn google_search(
+        mut self,
+        google_search: GoogleSearch,
+    ) -> GeminiGenerateContentRequest<O, T::WithGoogleSearch, C>
+    where
+        T: GeminiToolsState,
+    {
+        self.request.tools.get_or_insert_with(Vec::new).push(Tool {
+            google_search: Some(google_search),
+            ..Default::default()
+        });
+        self.with_state()
+    }
+
+    pub fn tool_config(
+        mut self,
+        tool_config: ToolConfig,
+    ) -> GeminiGenerateContentRequest<O, GeminiUsesTools, C> {
+        self.request.tool_config = Some(tool_config);
+        self.with_state()
+    }
+
+    /// Constrain the response to JSON matching a standard JSON Schema.
+    pub fn response_json_schema(
+        mut self,
+        schema: serde_json::Value,
+    ) -> GeminiGenerateContentRequest<GeminiStructuredOutput, T, C> {
+        let config = self
+            .request
+            .generation_config
+            .get_or_insert_with(Default::default);
+        config.response_mime_type = Some("application/json".into());
+        config.response_json_schema = Some(schema);
+        self.with_state()
+    }
+
+    /// Constrain the response to JSON matching an OpenAPI-subset schema.
+    pub fn response_schema(
+        mut self,
+        schema: serde_json::Value,
+    ) -> GeminiGenerateContentRequest<GeminiStructuredOutput, T, C> {
+        let config = self
+            .request
+            .generation_config
+            .get_or_insert_with(Default::default);
+        config.response_mime_type = Some("application/json".into());
+        config.response_schema = Some(schema);
+        self.with_state()
+    }
+
+    /// Prefix the request with a previously created context cache.
+    pub fn cached_content(
+        mut self,
+        name: CachedContentName,
+    ) -> GeminiGenerateContentRequest<O, T, GeminiUsesCachedContent> {
+        self.request.cached_content = Some(name);
+        self.with_state()
+    }
+
+    pub fn build(self) -> Self {
+        self
+    }
+}
+
+mod compatibility {
+    use super::*;
+
+    pub trait OutputCompatible<M: GeminiGenerateContentModel> {}
+    impl<M: GeminiGenerateContentModel> OutputCompatible<M> for GeminiPlainTextOutput {}
+    impl<M: SupportsStructuredOutput> OutputCompatible<M> for GeminiStructuredOutput {}
+
+    pub trait ToolsCompatible<M: GeminiGenerateContentModel> {}
+    impl<M: GeminiGenerateContentModel> ToolsCompatible<M> for GeminiNoTools {}
+    impl<M: SupportsGoogleSearch> ToolsCompatible<M> for GeminiUsesGoogleSearch {}
+    impl<M: SupportsFunctionCalling> ToolsCompatible<M> for GeminiUsesTools {}
+
+    pub trait CacheCompatible<M: GeminiGenerateContentModel> {}
+    impl<M: GeminiGenerateContentModel> CacheCompatible<M> for GeminiNoCachedContent {}
+    impl<M: SupportsCaching> CacheCompatible<M> for GeminiUsesCachedContent {}
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Tracks which tools a request uses, so Search grounding alone needs only
+/// [`SupportsGoogleSearch`].
+#[doc(hidden)]
+pub trait GeminiToolsState: sealed::Sealed {
+    type WithGoogleSearch;
+}
+
+impl sealed::Sealed for GeminiNoTools {}
+impl sealed::Sealed for GeminiUsesGoogleSearch {}
+impl sealed::Sealed for GeminiUsesTools {}
+
+impl GeminiToolsState for GeminiNoTools {
+    type WithGoogleSearch = GeminiUsesGoogleSearch;
+}
+
+impl GeminiToolsState for GeminiUsesGoogleSearch {
+    type WithGoogleSearch = GeminiUsesGoogleSearch;
+}
+
+impl GeminiToolsState for GeminiUsesTools {
+    type WithGoogleSearch = GeminiUsesTools;
+}
+
+/// Implemented only when every capability used by a request is supported by M.
+#[doc(hidden)]
+pub trait CompatibleGenerateContentRequest<M: GeminiGenerateContentModel>: sealed::Sealed {
+    fn prepare(self, config: GeminiModelConfig<M>) -> GenerateContentRequest;
+}
+
+impl<O, T, C> sealed::Sealed for GeminiGenerateContentRequest<O, T, C> {}
+
+impl<M, O, T, C> CompatibleGenerateContentRequest<M> for GeminiGenerateContentRequest<O, T, C>
+where
+    M: GeminiGenerateContentModel,
+    O: compatibility::OutputCompatible<M>,
+    T: compatibility::ToolsCompatible<M>,
+    C: compatibility::CacheCompatible<M>,
+{
+    fn prepare(self, config: GeminiModelConfig<M>) -> GenerateContentRequest {
+        let mut request = self.request;
+        let mut generation_config = config.into_generation_config();
+        if let Some(output) = request.generation_config.take() {
+            generation_config.response_mime_type = output.response_mime_type;
+            generation_config.response_schema = output.response_schema;
+            generation_config.response_json_schema = output.response_json_schema;
+        }
+        request.generation_config =
+            (generation_config != Default::default()).then_some(generation_config);
+        request
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeminiPlainTextOutput;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeminiStructuredOutput;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeminiNoTools;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeminiUsesGoogleSearch;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeminiUsesTools;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeminiNoCachedContent;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeminiUsesCachedContent;
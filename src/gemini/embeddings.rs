@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Content, GeminiModelId, Part};
+
+/// How an embedding will be used, which lets the model optimize it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TaskType {
+    #[serde(rename = "TASK_TYPE_UNSPECIFIED")]
+    Unspecified,
+    RetrievalQuery,
+    RetrievalDocument,
+    SemanticSimilarity,
+    Classification,
+    Clustering,
+    QuestionAnswering,
+    FactVerification,
+    CodeRetrievalQuery,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbedContentRequest {
+    /// Content to embed. Only text parts are counted.
+    pub content: Content,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_type: Option<TaskType>,
+    /// Document title. Only applicable with `TaskType::RetrievalDocument`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Truncate the output embedding to this many dimensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_dimensionality: Option<u32>,
+}
+
+impl EmbedContentRequest {
+    /// Embed a single text value.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            content: Content {
+                parts: vec![Part::text(text)],
+                role: None,
+            },
+            ..Default::default()
+        }
+    }
+}
+
+/// Each `batchEmbedContents` entry repeats the model resource name.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BatchEmbedContentsWire<'a> {
+    requests: Vec<BatchEmbedContentWire<'a>>,
+}
+
+#[derive(Serialize)]
+struct BatchEmbedContentWire<'a> {
+    model: String,
+    #[serde(flatten)]
+    request: &'a EmbedContentRequest,
+}
+
+impl<'a> BatchEmbedContentsWire<'a> {
+    pub(crate) fn new(model: &GeminiModelId, requests: &'a [EmbedContentRequest]) -> Self {
+        let model = model.to_string();
+        Self {
+            requests: requests
+                .iter()
+                .map(|request| BatchEmbedContentWire {
+                    model: model.clone(),
+                    request,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentEmbedding {
+    #[serde(default)]
+    pub values: Vec<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbedContentResponse {
+    pub embedding: ContentEmbedding,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchEmbedContentsResponse {
+    /// Embeddings in the same order as the batch requests.
+    #[serde(default)]
+    pub embeddings: Vec<ContentEmbedding>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::test_support::{gemini_client, json_response, one_shot_server, request_json},
+        gemini::GeminiEmbeddingModel,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn embed_content_sends_task_options_and_decodes_f32_values() {
+        let body = r#"{"embedding":{"values":[0.25,-0.5,1.0]}}"#;
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], body)).await;
+        let response = gemini_client(base_url)
+            .embed_content(
+                GeminiEmbeddingModel::GeminiEmbedding001,
+                EmbedContentRequest {
+                    task_type: Some(TaskType::RetrievalDocument),
+                    title: Some("Guide".into()),
+                    output_dimensionality: Some(768),
+                    ..EmbedContentRequest::text("hello")
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.data().embedding.values, vec![0.25, -0.5, 1.0]);
+        let captured = captured.await.unwrap();
+        assert!(captured
+            .starts_with("POST /v1beta/models/gemini-embedding-001:embedContent HTTP/1.1\r\n"));
+        assert_eq!(
+            request_json(&captured),
+            serde_json::json!({
+                "content":{"parts":[{"text":"hello"}]},
+                "taskType":"RETRIEVAL_DOCUMENT",
+                "title":"Guide",
+                "outputDimensionality":768
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn batch_embed_contents_repeats_model_per_request() {
+        let body = r#"{"embeddings":[{"values":[1.0]},{"values":[2.0]}]}"#;
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], body)).await;
+        let response = gemini_client(base_url)
+            .batch_embed_contents(
+                GeminiEmbeddingModel::GeminiEmbedding001,
+                &[
+                    EmbedContentRequest::text("a"),
+                    EmbedContentRequest {
+                        task_type: Some(TaskType::SemanticSimilarity),
+                        ..EmbedContentRequest::text("b")
+                    },
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.data().embeddings.len(), 2);
+        assert_eq!(response.data().embeddings[1].values, vec![2.0]);
+        let captured = captured.await.unwrap();
+        assert!(captured.starts_with(
+            "POST /v1beta/models/gemini-embedding-001:batchEmbedContents HTTP/1.1\r\n"
+        ));
+        assert_eq!(
+            request_json(&captured),
+            serde_json::json!({"requests":[
+                {"model":"models/gemini-embedding-001","content":{"parts":[{"text":"a"}]}},
+                {
+                    "model":"models/gemini-embedding-001",
+                    "content":{"parts":[{"text":"b"}]},
+                    "taskType":"SEMANTIC_SIMILARITY"
+                }
+            ]})
+        );
+    }
+}
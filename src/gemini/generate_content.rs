@@ -1,12 +1,14 @@
 use std::{collections::HashSet, fmt::Display};
 
-use serde::{
-    de::Error as _, ser::SerializeMap as _, Deserialize, Deserializer, Serialize, Serializer,
-};
+use serde::{de::Error as _, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::utils::{base64_decode, base64_encode};
 
-use super::GeminiModel;
+use super::{
+    open_enum::open_enum, CachedContentName, CodeExecutionResult, ExecutableCode, FunctionCall,
+    FunctionResponse, GeminiModelId, GroundingMetadata, SpeechConfig, Tool, ToolConfig,
+    UrlContextMetadata,
+};
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -27,11 +29,55 @@ pub enum Role {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Part {
     Text(String),
+    /// A summary of the model's reasoning, returned when thoughts are
+    /// included. Not part of the answer text.
+    Thought(String),
     Blob {
         mime_type: String,
         /// Base64 encoded data.
         data: String,
     },
+    FunctionCall(FunctionCall),
+    FunctionResponse(FunctionResponse),
+    ExecutableCode(ExecutableCode),
+    CodeExecutionResult(CodeExecutionResult),
+    /// A part carrying an opaque thought signature. Thinking models require
+    /// the signature to be sent back unchanged with the part in later turns.
+    Signed(SignedPart),
+}
+
+/// A part and its thought signature. The wrapped part is never itself
+/// signed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedPart {
+    part: Box<Part>,
+    thought_signature: String,
+}
+
+impl SignedPart {
+    /// Sign `part`, replacing any signature it already carries.
+    pub fn new(part: Part, thought_signature: impl Into<String>) -> Self {
+        let part = match part {
+            Part::Signed(signed) => signed.part,
+            part => Box::new(part),
+        };
+        Self {
+            part,
+            thought_signature: thought_signature.into(),
+        }
+    }
+
+    pub fn part(&self) -> &Part {
+        &self.part
+    }
+
+    pub fn thought_signature(&self) -> &str {
+        &self.thought_signature
+    }
+
+    pub fn into_parts(self) -> (Part, String) {
+        (*self.part, self.thought_signature)
+    }
 }
 
 impl Serialize for Part {
@@ -39,33 +85,72 @@ impl Serialize for Part {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_map(Some(1))?;
+        let mut map = serializer.serialize_map(None)?;
+        self.serialize_entries(&mut map)?;
+        map.end()
+    }
+}
+
+impl Part {
+    fn serialize_entries<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
         match self {
-            Self::Text(text) => map.serialize_entry("text", text)?,
+            Self::Text(text) => map.serialize_entry("text", text),
+            Self::Thought(text) => {
+                map.serialize_entry("text", text)?;
+                map.serialize_entry("thought", &true)
+            }
             Self::Blob { mime_type, data } => map.serialize_entry(
                 "inlineData",
                 &serde_json::json!({"mimeType": mime_type, "data": data}),
-            )?,
+            ),
+            Self::FunctionCall(call) => map.serialize_entry("functionCall", call),
+            Self::FunctionResponse(response) => map.serialize_entry("functionResponse", response),
+            Self::ExecutableCode(code) => map.serialize_entry("executableCode", code),
+            Self::CodeExecutionResult(result) => map.serialize_entry("codeExecutionResult", result),
+            Self::Signed(signed) => {
+                signed.part.serialize_entries(map)?;
+                map.serialize_entry("thoughtSignature", &signed.thought_signature)
+            }
         }
-        map.end()
     }
 }
 
+const PART_DATA_FIELDS: [&str; 6] = [
+    "text",
+    "inlineData",
+    "functionCall",
+    "functionResponse",
+    "executableCode",
+    "codeExecutionResult",
+];
+
 impl<'de> Deserialize<'de> for Part {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
         let value = serde_json::Value::deserialize(deserializer)?;
-        let serde_json::Value::Object(object) = value else {
+        let serde_json::Value::Object(mut object) = value else {
             return Err(D::Error::custom("Gemini content part must be an object"));
         };
 
-        let text = object.get("text").and_then(serde_json::Value::as_str);
-        let inline_data = object.get("inlineData");
-        match (text, inline_data) {
-            (Some(text), None) => Ok(Self::Text(text.to_owned())),
-            (None, Some(inline_data)) => {
+        let mut fields = PART_DATA_FIELDS
+            .into_iter()
+            .filter(|field| object.contains_key(*field));
+        let (Some(field), None) = (fields.next(), fields.next()) else {
+            return Err(D::Error::custom(
+                "Gemini content part must contain exactly one data field",
+            ));
+        };
+        let data = object.remove(field).unwrap_or_default();
+        let thought = object.get("thought").and_then(serde_json::Value::as_bool) == Some(true);
+        let part = match field {
+            "text" => match data {
+                serde_json::Value::String(text) if thought => Ok(Self::Thought(text)),
+                serde_json::Value::String(text) => Ok(Self::Text(text)),
+                _ => Err(D::Error::custom("Gemini text part must be a string")),
+            },
+            "inlineData" => {
                 #[derive(Deserialize)]
                 #[serde(rename_all = "camelCase")]
                 struct InlineData {
@@ -74,18 +159,33 @@ impl<'de> Deserialize<'de> for Part {
                 }
 
                 let inline_data: InlineData =
-                    serde_json::from_value(inline_data.clone()).map_err(D::Error::custom)?;
+                    serde_json::from_value(data).map_err(D::Error::custom)?;
                 Ok(Self::Blob {
                     mime_type: inline_data.mime_type,
                     data: inline_data.data,
                 })
             }
-            (Some(_), Some(_)) => Err(D::Error::custom(
-                "Gemini content part cannot contain both text and inlineData",
-            )),
-            (None, None) => Err(D::Error::custom(
-                "Gemini content part must contain text or inlineData",
+            "functionCall" => serde_json::from_value(data)
+                .map(Self::FunctionCall)
+                .map_err(D::Error::custom),
+            "functionResponse" => serde_json::from_value(data)
+                .map(Self::FunctionResponse)
+                .map_err(D::Error::custom),
+            "executableCode" => serde_json::from_value(data)
+                .map(Self::ExecutableCode)
+                .map_err(D::Error::custom),
+            _ => serde_json::from_value(data)
+                .map(Self::CodeExecutionResult)
+                .map_err(D::Error::custom),
+        }?;
+        match object.remove("thoughtSignature") {
+            Some(serde_json::Value::String(thought_signature)) => {
+                Ok(Self::Signed(SignedPart::new(part, thought_signature)))
+            }
+            Some(_) => Err(D::Error::custom(
+                "Gemini thought signature must be a string",
             )),
+            None => Ok(part),
         }
     }
 }
@@ -112,17 +212,41 @@ impl Part {
         }
     }
 
-    /// Get the text data if this part is a text part.
+    /// The part without its thought signature, if it has one.
+    pub fn unsigned(&self) -> &Part {
+        match self {
+            Part::Signed(signed) => &signed.part,
+            part => part,
+        }
+    }
+
+    /// The thought signature attached to this part.
+    pub fn thought_signature(&self) -> Option<&str> {
+        match self {
+            Part::Signed(signed) => Some(&signed.thought_signature),
+            _ => None,
+        }
+    }
+
+    /// Get the text data if this part is a text part. Thoughts are excluded.
     pub fn as_text(&self) -> Option<&str> {
-        match &self {
+        match self.unsigned() {
             Part::Text(text) => Some(text),
             _ => None,
         }
     }
 
+    /// Get the thought summary if this part is a thought.
+    pub fn as_thought(&self) -> Option<&str> {
+        match self.unsigned() {
+            Part::Thought(text) => Some(text),
+            _ => None,
+        }
+    }
+
     /// Get the blob data if this part is a blob part.
     pub fn as_blob(&self) -> Option<(String, Vec<u8>)> {
-        match &self {
+        match self.unsigned() {
             Part::Blob { mime_type, data } => base64_decode(data)
                 .ok()
                 .map(|data| (mime_type.clone(), data)),
@@ -132,11 +256,19 @@ impl Part {
 
     /// Get the base64 encoded blob data if this part is a blob part.
     pub fn as_blob_base64(&self) -> Option<(&str, &str)> {
-        match &self {
+        match self.unsigned() {
             Part::Blob { mime_type, data } => Some((mime_type, data)),
             _ => None,
         }
     }
+
+    /// Get the function call if this part is a function call part.
+    pub fn as_function_call(&self) -> Option<&FunctionCall> {
+        match self.unsigned() {
+            Part::FunctionCall(call) => Some(call),
+            _ => None,
+        }
+    }
 }
 
 /// Request type used in the `countTokens` endpoint.
@@ -146,19 +278,30 @@ impl Part {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CountTokensGenerateContentRequest {
-    pub model: GeminiModel,
+    pub model: GeminiModelId,
     #[serde(flatten)]
     pub request: GenerateContentRequest,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GenerateContentRequest {
     pub contents: Vec<Content>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_instruction: Option<Content>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_config: Option<ToolConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub safety_settings: Option<HashSet<SafetySetting>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub generation_config: Option<GenerationConfig>,
+    /// Previously created context cache to prefix to `contents`. A request
+    /// that uses a cache must not repeat the cached system instruction, tools,
+    /// or tool config.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cached_content: Option<CachedContentName>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -264,6 +407,11 @@ pub struct GenerationConfig {
     /// text will be constrained to match the provided schema.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub response_schema: Option<serde_json::Value>,
+    /// JSON Schema for the generated candidate text. An alternative to
+    /// `response_schema` that accepts standard JSON Schema; requires
+    /// `response_mime_type` to be "application/json".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_json_schema: Option<serde_json::Value>,
     /// Response modalities for multimodal output.
     /// Use ["TEXT", "IMAGE"] for image generation with Gemini 2.0 Flash.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -272,6 +420,40 @@ pub struct GenerationConfig {
     /// REST API field: `imageConfig` inside `generationConfig`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_config: Option<GeminiImageConfig>,
+    /// Thinking configuration for models that support thinking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking_config: Option<ThinkingConfig>,
+    /// Voice settings for text-to-speech models. Requires the `AUDIO`
+    /// response modality.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speech_config: Option<SpeechConfig>,
+}
+
+/// Controls the model's internal reasoning.
+///
+/// Gemini 2.5 models use `thinking_budget`; Gemini 3 models use
+/// `thinking_level`. The typed model configuration picks the right one.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThinkingConfig {
+    /// Return thought summaries as parts marked `thought`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_thoughts: Option<bool>,
+    /// Number of thinking tokens; `0` disables thinking and `-1` lets the
+    /// model decide.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking_budget: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking_level: Option<ThinkingLevel>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThinkingLevel {
+    Minimal,
+    Low,
+    Medium,
+    High,
 }
 
 /// Image configuration for Gemini image generation.
@@ -332,6 +514,7 @@ pub enum GeminiImageSize {
 pub enum ResponseModality {
     Text,
     Image,
+    Audio,
 }
 
 impl ResponseModality {
@@ -339,19 +522,22 @@ impl ResponseModality {
         match self {
             ResponseModality::Text => "TEXT",
             ResponseModality::Image => "IMAGE",
+            ResponseModality::Audio => "AUDIO",
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GenerateContentResponse {
+    /// Empty when the prompt was blocked.
+    #[serde(default)]
     pub candidates: Vec<Candidate>,
     pub prompt_feedback: Option<PromptFeedback>,
     pub usage_metadata: UsageMetadata,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Candidate {
     pub content: Option<Content>,
@@ -359,22 +545,41 @@ pub struct Candidate {
     #[serde(default)]
     pub safety_ratings: Vec<SafetyRating>,
     pub citation_metadata: Option<CitationMetadata>,
+    /// Sources used by the Google Search grounding tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grounding_metadata: Option<GroundingMetadata>,
+    /// URLs retrieved by the URL context tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_context_metadata: Option<UrlContextMetadata>,
     pub token_count: Option<u64>,
     #[serde(default)]
     pub index: u64,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum FinishReason {
-    #[serde(rename = "FINISH_REASON_UNSPECIFIED")]
-    Unspecified,
-    Stop,
-    MaxTokens,
-    Safety,
-    Recitation,
-    Language,
-    Other,
+open_enum! {
+    pub enum FinishReason {
+        Unspecified => "FINISH_REASON_UNSPECIFIED",
+        Stop => "STOP",
+        MaxTokens => "MAX_TOKENS",
+        Safety => "SAFETY",
+        Recitation => "RECITATION",
+        Language => "LANGUAGE",
+        Other => "OTHER",
+        Blocklist => "BLOCKLIST",
+        ProhibitedContent => "PROHIBITED_CONTENT",
+        Spii => "SPII",
+        MalformedFunctionCall => "MALFORMED_FUNCTION_CALL",
+        ImageSafety => "IMAGE_SAFETY",
+        ImageProhibitedContent => "IMAGE_PROHIBITED_CONTENT",
+        ImageRecitation => "IMAGE_RECITATION",
+        ImageOther => "IMAGE_OTHER",
+        NoImage => "NO_IMAGE",
+        UnexpectedToolCall => "UNEXPECTED_TOOL_CALL",
+        TooManyToolCalls => "TOO_MANY_TOOL_CALLS",
+        @unknown
+        /// A finish reason added after this crate was released.
+        Unknown,
+    }
 }
 
 impl Display for FinishReason {
@@ -392,6 +597,38 @@ impl Display for FinishReason {
                 "The candidate content was flagged for using an unsupported language"
             }
             FinishReason::Other => "Unknown reason",
+            FinishReason::Blocklist => {
+                "Token generation stopped because the content contains forbidden terms"
+            }
+            FinishReason::ProhibitedContent => {
+                "Token generation stopped for potentially containing prohibited content"
+            }
+            FinishReason::Spii => {
+                "Token generation stopped because the content potentially contains Sensitive Personally Identifiable Information"
+            }
+            FinishReason::MalformedFunctionCall => {
+                "The function call generated by the model is invalid"
+            }
+            FinishReason::ImageSafety => {
+                "Token generation stopped because generated images contain safety violations"
+            }
+            FinishReason::ImageProhibitedContent => {
+                "Image generation stopped because generated images have other prohibited content"
+            }
+            FinishReason::ImageRecitation => "Image generation stopped due to recitation",
+            FinishReason::ImageOther => {
+                "Image generation stopped because of another miscellaneous issue"
+            }
+            FinishReason::NoImage => {
+                "The model was expected to generate an image, but none was generated"
+            }
+            FinishReason::UnexpectedToolCall => {
+                "The model generated a tool call but no tools were enabled in the request"
+            }
+            FinishReason::TooManyToolCalls => {
+                "The model called too many tools consecutively, so execution stopped"
+            }
+            FinishReason::Unknown(value) => return write!(f, "Unrecognized finish reason {value}"),
         };
         write!(f, "{desc}")
     }
@@ -471,6 +708,9 @@ pub enum BlockReason {
     Unspecified,
     Safety,
     Other,
+    Blocklist,
+    ProhibitedContent,
+    ImageSafety,
 }
 
 impl Display for BlockReason {
@@ -480,17 +720,95 @@ impl Display for BlockReason {
             BlockReason::Unspecified => "Block reason is unspecified",
             BlockReason::Safety => "Prompt was blocked due to safety reasons. You can inspect safetyRatings to understand which safety category blocked it",
             BlockReason::Other => "Prompt was blocked due to unknown reasons",
+            BlockReason::Blocklist => {
+                "Prompt was blocked due to the terms which are included from the terminology blocklist"
+            }
+            BlockReason::ProhibitedContent => {
+                "Prompt was blocked due to prohibited content"
+            }
+            BlockReason::ImageSafety => "Candidates blocked due to unsafe image generation content",
         };
         write!(f, "{desc}")
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UsageMetadata {
     pub prompt_token_count: u64,
+    /// Number of prompt tokens served from `cached_content`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cached_content_token_count: Option<u64>,
     pub candidates_token_count: Option<u64>,
+    /// Tokens in prompts produced by tool calls, such as search results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_use_prompt_token_count: Option<u64>,
+    /// Tokens spent on thinking, billed as output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thoughts_token_count: Option<u64>,
     pub total_token_count: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prompt_tokens_details: Vec<ModalityTokenCount>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cache_tokens_details: Vec<ModalityTokenCount>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub candidates_tokens_details: Vec<ModalityTokenCount>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_use_prompt_tokens_details: Vec<ModalityTokenCount>,
+    /// Fields this crate does not model yet.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UsageMetadata {
+    /// Prompt tokens of `modality`, or 0 if the breakdown omits it.
+    pub fn prompt_tokens(&self, modality: Modality) -> u64 {
+        ModalityTokenCount::sum(&self.prompt_tokens_details, &modality)
+    }
+
+    /// Cached prompt tokens of `modality`.
+    pub fn cached_tokens(&self, modality: Modality) -> u64 {
+        ModalityTokenCount::sum(&self.cache_tokens_details, &modality)
+    }
+
+    /// Output tokens of `modality`, excluding thinking tokens.
+    pub fn candidates_tokens(&self, modality: Modality) -> u64 {
+        ModalityTokenCount::sum(&self.candidates_tokens_details, &modality)
+    }
+}
+
+open_enum! {
+    /// Content modality used in token breakdowns.
+    pub enum Modality {
+        Unspecified => "MODALITY_UNSPECIFIED",
+        Text => "TEXT",
+        Image => "IMAGE",
+        Video => "VIDEO",
+        Audio => "AUDIO",
+        Document => "DOCUMENT",
+        @unknown
+        /// A modality added after this crate was released.
+        Other,
+    }
+}
+
+/// Token count for one modality.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModalityTokenCount {
+    pub modality: Modality,
+    #[serde(default)]
+    pub token_count: u64,
+}
+
+impl ModalityTokenCount {
+    pub(crate) fn sum(details: &[Self], modality: &Modality) -> u64 {
+        details
+            .iter()
+            .filter(|detail| detail.modality == *modality)
+            .map(|detail| detail.token_count)
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -498,6 +816,7 @@ mod tests {
     use serde_json::json;
 
     use super::*;
+    use crate::gemini::CountTokensResponse;
 
     #[test]
     fn test_content_round_trip() {
@@ -536,9 +855,42 @@ mod tests {
             "thoughtSignature": "opaque-provider-value"
         }))
         .unwrap();
+        // The signature is kept for the next turn, but the text reads the same.
+        assert_eq!(part.as_text(), Some("OK"));
+        let Part::Signed(signed) = &part else {
+            panic!("expected a signed part, got {part:?}");
+        };
+        assert_eq!(signed.part(), &Part::text("OK"));
+        assert_eq!(signed.thought_signature(), "opaque-provider-value");
+
+        let part: Part = serde_json::from_value(json!({
+            "text": "OK",
+            "partMetadata": {"opaque": true}
+        }))
+        .unwrap();
         assert_eq!(part, Part::text("OK"));
     }
 
+    #[test]
+    fn thought_signatures_and_thoughts_round_trip() {
+        let wire = json!([
+            {"text": "Considering options", "thought": true},
+            {"text": "OK", "thoughtSignature": "opaque-provider-value"},
+            {"functionCall": {"name": "f"}, "thoughtSignature": "c2ln"}
+        ]);
+        let parts: Vec<Part> = serde_json::from_value(wire.clone()).unwrap();
+        assert_eq!(parts[0], Part::Thought("Considering options".into()));
+        assert_eq!(parts[0].as_text(), None);
+        assert_eq!(parts[1].as_text(), Some("OK"));
+        assert_eq!(parts[1].thought_signature(), Some("opaque-provider-value"));
+        assert_eq!(parts[2].as_function_call().unwrap().name, "f");
+        assert_eq!(serde_json::to_value(&parts).unwrap(), wire);
+
+        let resigned = Part::Signed(SignedPart::new(parts[2].clone(), "bmV3"));
+        assert_eq!(resigned.thought_signature(), Some("bmV3"));
+        assert!(matches!(resigned.unsigned(), Part::FunctionCall(_)));
+    }
+
     #[test]
     fn test_content_serialize() {
         let content = Content {
@@ -563,6 +915,10 @@ mod tests {
                 parts: vec![Part::text("Hello, World!")],
                 role: Some(Role::User),
             }],
+            system_instruction: None,
+            tools: None,
+            tool_config: None,
+            cached_content: None,
             safety_settings: Some(HashSet::from([SafetySetting {
                 category: HarmCategory::Unspecified,
                 threshold: HarmBlockThreshold::Unspecified,
@@ -578,6 +934,9 @@ mod tests {
                 response_schema: None,
                 response_modalities: None,
                 image_config: None,
+                response_json_schema: None,
+                thinking_config: None,
+                speech_config: None,
             }),
         };
 
@@ -616,6 +975,10 @@ mod tests {
                     parts: vec![Part::text("Hello, World!")],
                     role: Some(Role::User),
                 }],
+                system_instruction: None,
+                tools: None,
+                tool_config: None,
+                cached_content: None,
                 safety_settings: Some(HashSet::from([SafetySetting {
                     category: HarmCategory::Unspecified,
                     threshold: HarmBlockThreshold::Unspecified,
@@ -631,6 +994,9 @@ mod tests {
                     response_schema: None,
                     response_modalities: None,
                     image_config: None,
+                    response_json_schema: None,
+                    thinking_config: None,
+                    speech_config: None,
                 }),
             }
         );
@@ -643,6 +1009,10 @@ mod tests {
                 parts: vec![Part::text("Hello, World!")],
                 role: Some(Role::User),
             }],
+            system_instruction: None,
+            tools: None,
+            tool_config: None,
+            cached_content: None,
             safety_settings: Some(HashSet::from([SafetySetting {
                 category: HarmCategory::Unspecified,
                 threshold: HarmBlockThreshold::Unspecified,
@@ -658,6 +1028,9 @@ mod tests {
                 response_schema: None,
                 response_modalities: None,
                 image_config: None,
+                response_json_schema: None,
+                thinking_config: None,
+                speech_config: None,
             }),
         };
 
@@ -685,6 +1058,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn usage_metadata_keeps_breakdowns_and_unknown_fields() {
+        let usage: UsageMetadata = serde_json::from_value(json!({
+            "promptTokenCount": 1300,
+            "cachedContentTokenCount": 1024,
+            "candidatesTokenCount": 40,
+            "toolUsePromptTokenCount": 12,
+            "thoughtsTokenCount": 88,
+            "totalTokenCount": 1440,
+            "promptTokensDetails": [
+                {"modality": "TEXT", "tokenCount": 42},
+                {"modality": "IMAGE", "tokenCount": 1258}
+            ],
+            "cacheTokensDetails": [{"modality": "IMAGE", "tokenCount": 1024}],
+            "candidatesTokensDetails": [{"modality": "AUDIO", "tokenCount": 40}],
+            "toolUsePromptTokensDetails": [{"modality": "HOLOGRAM", "tokenCount": 12}],
+            "trafficType": "ON_DEMAND"
+        }))
+        .unwrap();
+        assert_eq!(usage.thoughts_token_count, Some(88));
+        assert_eq!(usage.tool_use_prompt_token_count, Some(12));
+        assert_eq!(usage.prompt_tokens(Modality::Image), 1258);
+        assert_eq!(usage.cached_tokens(Modality::Image), 1024);
+        assert_eq!(usage.candidates_tokens(Modality::Audio), 40);
+        assert_eq!(usage.candidates_tokens(Modality::Text), 0);
+        assert_eq!(
+            usage.tool_use_prompt_tokens_details[0].modality,
+            Modality::Other("HOLOGRAM".into())
+        );
+        assert_eq!(usage.other["trafficType"], "ON_DEMAND");
+        assert_eq!(
+            serde_json::to_value(&usage).unwrap()["trafficType"],
+            "ON_DEMAND"
+        );
+        assert_eq!(
+            serde_json::to_value(&usage).unwrap()["toolUsePromptTokensDetails"][0]["modality"],
+            "HOLOGRAM"
+        );
+
+        let count: CountTokensResponse = serde_json::from_value(json!({
+            "totalTokens": 300,
+            "cachedContentTokenCount": 100,
+            "promptTokensDetails": [{"modality": "VIDEO", "tokenCount": 300}],
+            "cacheTokensDetails": [{"modality": "TEXT", "tokenCount": 100}],
+            "future": true
+        }))
+        .unwrap();
+        assert_eq!(count.total_tokens(), 300);
+        assert_eq!(count.cached_content_token_count(), Some(100));
+        assert_eq!(count.prompt_tokens(Modality::Video), 300);
+        assert_eq!(count.cache_tokens_details()[0].modality, Modality::Text);
+        assert_eq!(count.other()["future"], true);
+    }
+
     #[test]
     fn test_response_deserialize() {
         let response_json = json!({
@@ -760,14 +1187,18 @@ mod tests {
                         }
                     ],
                     citation_metadata: None,
+                    grounding_metadata: None,
+                    url_context_metadata: None,
                     token_count: None,
                     index: 0,
                 }],
                 prompt_feedback: None,
                 usage_metadata: UsageMetadata {
                     prompt_token_count: 21,
+                    cached_content_token_count: None,
                     candidates_token_count: Some(8),
                     total_token_count: 29,
+                    ..Default::default()
                 },
             }
         );
@@ -0,0 +1,313 @@
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use super::Candidate;
+
+/// Sources and citations returned by the Google Search grounding tool.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroundingMetadata {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub web_search_queries: Vec<String>,
+    /// Google Search suggestions that must be displayed with grounded results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_entry_point: Option<SearchEntryPoint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grounding_chunks: Vec<GroundingChunk>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grounding_supports: Vec<GroundingSupport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retrieval_metadata: Option<RetrievalMetadata>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchEntryPoint {
+    /// HTML and CSS snippet to embed in a web page or web view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendered_content: Option<String>,
+    /// Base64 encoded JSON of `(search term, search URL)` tuples.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sdk_blob: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroundingChunk {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web: Option<WebChunk>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebChunk {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// Links a segment of the response text to the chunks that support it.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroundingSupport {
+    #[serde(default)]
+    pub segment: Segment,
+    /// Indices into `GroundingMetadata::grounding_chunks`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grounding_chunk_indices: Vec<usize>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub confidence_scores: Vec<f32>,
+}
+
+/// A span of one content part. Indices are UTF-8 byte offsets.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Segment {
+    #[serde(default)]
+    pub part_index: usize,
+    #[serde(default)]
+    pub start_index: usize,
+    #[serde(default)]
+    pub end_index: usize,
+    #[serde(default)]
+    pub text: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetrievalMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_search_dynamic_retrieval_score: Option<f32>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlContextMetadata {
+    #[serde(default)]
+    pub url_metadata: Vec<UrlMetadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlMetadata {
+    pub retrieved_url: String,
+    pub url_retrieval_status: UrlRetrievalStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UrlRetrievalStatus {
+    #[serde(rename = "URL_RETRIEVAL_STATUS_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "URL_RETRIEVAL_STATUS_SUCCESS")]
+    Success,
+    #[serde(rename = "URL_RETRIEVAL_STATUS_ERROR")]
+    Error,
+    #[serde(rename = "URL_RETRIEVAL_STATUS_PAYWALL")]
+    Paywall,
+    #[serde(rename = "URL_RETRIEVAL_STATUS_UNSAFE")]
+    Unsafe,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A grounding support resolved against its chunks, ready for rendering.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroundedSpan<'a> {
+    pub part_index: usize,
+    /// UTF-8 byte range within the text of part `part_index`.
+    pub range: Range<usize>,
+    pub text: &'a str,
+    /// Supporting chunks in the order listed by the provider. Out-of-range
+    /// chunk indices are skipped.
+    pub sources: Vec<&'a GroundingChunk>,
+    pub confidence_scores: &'a [f32],
+}
+
+impl GroundingMetadata {
+    /// Resolve every grounding support into its text span and sources.
+    pub fn grounded_spans(&self) -> Vec<GroundedSpan<'_>> {
+        self.grounding_supports
+            .iter()
+            .map(|support| GroundedSpan {
+                part_index: support.segment.part_index,
+                range: support.segment.start_index..support.segment.end_index,
+                text: &support.segment.text,
+                sources: support
+                    .grounding_chunk_indices
+                    .iter()
+                    .filter_map(|index| self.grounding_chunks.get(*index))
+                    .collect(),
+                confidence_scores: &support.confidence_scores,
+            })
+            .collect()
+    }
+}
+
+impl Candidate {
+    /// Concatenated text with a rendered citation inserted after each grounded
+    /// span, for example `render = |span| format!("[{}]", span.sources.len())`.
+    ///
+    /// Returns `None` when the candidate has no text. Spans whose byte offsets
+    /// do not fall on character boundaries of their part are skipped rather
+    /// than corrupting the text.
+    pub fn text_with_citations<F>(&self, mut render: F) -> Option<String>
+    where
+        F: FnMut(&GroundedSpan<'_>) -> String,
+    {
+        let parts = &self.content.as_ref()?.parts;
+        let mut spans = self
+            .grounding_metadata
+            .as_ref()
+            .map(GroundingMetadata::grounded_spans)
+            .unwrap_or_default();
+        spans.sort_by_key(|span| (span.part_index, span.range.end));
+
+        let mut output = String::new();
+        let mut any_text = false;
+        for (part_index, part) in parts.iter().enumerate() {
+            let Some(text) = part.as_text() else {
+                continue;
+            };
+            any_text = true;
+            let mut written = 0;
+            for span in spans.iter().filter(|span| span.part_index == part_index) {
+                let end = span.range.end;
+                if end < written || !text.is_char_boundary(end) {
+                    continue;
+                }
+                output.push_str(&text[written..end]);
+                output.push_str(&render(span));
+                written = end;
+            }
+            output.push_str(&text[written..]);
+        }
+        any_text.then_some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::gemini::{CodeExecutionOutcome, CodeLanguage, GenerateContentResponse, Part, Tool};
+
+    #[test]
+    fn built_in_tools_serialize_as_empty_objects() {
+        assert_eq!(
+            serde_json::to_value(vec![
+                Tool::google_search(),
+                Tool::code_execution(),
+                Tool::url_context(),
+            ])
+            .unwrap(),
+            json!([{"googleSearch":{}},{"codeExecution":{}},{"urlContext":{}}])
+        );
+    }
+
+    #[test]
+    fn decodes_code_execution_parts_and_grounding_metadata() {
+        let response: GenerateContentResponse = serde_json::from_value(json!({
+            "candidates":[{
+                "content":{"role":"model","parts":[
+                    {"text":"Café prices rose. Rents fell."},
+                    {"executableCode":{"language":"PYTHON","code":"print(1)"}},
+                    {"codeExecutionResult":{"outcome":"OUTCOME_OK","output":"1\n"}}
+                ]},
+                "groundingMetadata":{
+                    "webSearchQueries":["cafe prices"],
+                    "searchEntryPoint":{"renderedContent":"<div></div>"},
+                    "groundingChunks":[
+                        {"web":{"uri":"https://a.example","title":"a.example"}},
+                        {"web":{"uri":"https://b.example","title":"b.example"}}
+                    ],
+                    "groundingSupports":[
+                        {
+                            "segment":{"endIndex":18,"text":"Café prices rose."},
+                            "groundingChunkIndices":[0,1],
+                            "confidenceScores":[0.9,0.7]
+                        },
+                        {
+                            "segment":{"startIndex":19,"endIndex":30,"text":"Rents fell."},
+                            "groundingChunkIndices":[1,9]
+                        }
+                    ]
+                },
+                "urlContextMetadata":{"urlMetadata":[{
+                    "retrievedUrl":"https://a.example",
+                    "urlRetrievalStatus":"URL_RETRIEVAL_STATUS_SUCCESS"
+                }]},
+                "index":0
+            }],
+            "usageMetadata":{"promptTokenCount":1,"totalTokenCount":2}
+        }))
+        .unwrap();
+        let candidate = &response.candidates[0];
+        let parts = &candidate.content.as_ref().unwrap().parts;
+        let Part::ExecutableCode(code) = &parts[1] else {
+            panic!("expected executable code");
+        };
+        assert_eq!(code.code, "print(1)");
+        let Part::CodeExecutionResult(result) = &parts[2] else {
+            panic!("expected code execution result");
+        };
+        assert_eq!(result.outcome, CodeExecutionOutcome::Ok);
+        assert_eq!(
+            candidate
+                .url_context_metadata
+                .as_ref()
+                .unwrap()
+                .url_metadata[0]
+                .url_retrieval_status,
+            UrlRetrievalStatus::Success
+        );
+
+        let spans = candidate
+            .grounding_metadata
+            .as_ref()
+            .unwrap()
+            .grounded_spans();
+        assert_eq!(spans[0].range, 0..18);
+        assert_eq!(spans[0].sources.len(), 2);
+        assert_eq!(spans[1].sources.len(), 1, "out-of-range index is skipped");
+
+        let rendered = candidate
+            .text_with_citations(|span| {
+                let links: Vec<_> = span
+                    .sources
+                    .iter()
+                    .filter_map(|source| source.web.as_ref()?.title.as_deref())
+                    .collect();
+                format!("[{}]", links.join(", "))
+            })
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "Café prices rose.[a.example, b.example] Rents fell.[b.example]"
+        );
+        assert_eq!(
+            serde_json::to_value(&parts[2]).unwrap(),
+            json!({"codeExecutionResult":{"outcome":"OUTCOME_OK","output":"1\n"}})
+        );
+    }
+
+    #[test]
+    fn unknown_code_languages_and_outcomes_round_trip() {
+        let wire = json!([
+            {"executableCode":{"language":"JAVASCRIPT","code":"1"}},
+            {"codeExecutionResult":{"outcome":"OUTCOME_CANCELLED"}}
+        ]);
+        let parts: Vec<Part> = serde_json::from_value(wire.clone()).unwrap();
+        let Part::ExecutableCode(code) = &parts[0] else {
+            panic!("expected executable code");
+        };
+        assert_eq!(code.language, CodeLanguage::Other("JAVASCRIPT".into()));
+        let Part::CodeExecutionResult(result) = &parts[1] else {
+            panic!("expected code execution result");
+        };
+        assert_eq!(result.outcome.as_str(), "OUTCOME_CANCELLED");
+        assert_eq!(serde_json::to_value(&parts).unwrap(), wire);
+    }
+}
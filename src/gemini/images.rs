@@ -0,0 +1,216 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use tokio::io::AsyncWrite;
+
+use crate::utils::{base64_decode, save_with_extension, write_and_flush};
+
+use super::{Candidate, GenerateContentResponse};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GeminiImageError {
+    #[error("image in candidate {candidate_index} is not valid base64")]
+    InvalidBase64 { candidate_index: u64 },
+}
+
+/// An image returned by an image-output model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeminiImage {
+    pub candidate_index: u64,
+    /// MIME type reported by the provider, for example `image/png`.
+    pub mime_type: String,
+    /// Decoded image bytes.
+    pub data: Vec<u8>,
+    /// Text the model wrote since the previous image in the same candidate,
+    /// such as a caption or the step an interleaved image illustrates.
+    pub text: Option<String>,
+}
+
+impl GeminiImage {
+    /// File extension matching the MIME type, without the leading dot.
+    /// Unknown types use `bin`.
+    pub fn extension(&self) -> &'static str {
+        let essence = self.mime_type.split(';').next().unwrap_or_default().trim();
+        match essence.to_ascii_lowercase().as_str() {
+            "image/png" => "png",
+            "image/jpeg" | "image/jpg" => "jpg",
+            "image/webp" => "webp",
+            "image/gif" => "gif",
+            "image/heic" => "heic",
+            "image/heif" => "heif",
+            _ => "bin",
+        }
+    }
+
+    /// Save the image as `path` with a [`Self::extension`] extension and
+    /// return the path written.
+    pub async fn save(&self, path: impl AsRef<Path>) -> io::Result<PathBuf> {
+        save_with_extension(path.as_ref(), self.extension(), &self.data).await
+    }
+
+    /// Write [`Self::data`] to `writer`, then flush.
+    pub async fn write_to<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        write_and_flush(writer, &self.data).await
+    }
+}
+
+impl Candidate {
+    /// Every `image/*` inline data part in this candidate, in order.
+    pub fn images(&self) -> Result<Vec<GeminiImage>, GeminiImageError> {
+        let mut images = Vec::new();
+        collect_images(self, &mut String::new(), &mut images)?;
+        Ok(images)
+    }
+}
+
+impl GenerateContentResponse {
+    /// Every generated image across all candidates.
+    pub fn images(&self) -> Result<Vec<GeminiImage>, GeminiImageError> {
+        let mut images = Vec::new();
+        for candidate in &self.candidates {
+            collect_images(candidate, &mut String::new(), &mut images)?;
+        }
+        Ok(images)
+    }
+}
+
+/// Emits images from `streamGenerateContent` chunks as soon as they arrive.
+///
+/// Inline data is never split across chunks, so each image is complete in
+/// the chunk that carries it. Text from earlier chunks is kept per candidate
+/// and attached to the next image.
+#[derive(Debug, Default)]
+pub struct GeminiImageCollector {
+    pending_text: BTreeMap<u64, String>,
+}
+
+impl GeminiImageCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Images completed by this chunk.
+    pub fn push(
+        &mut self,
+        chunk: &GenerateContentResponse,
+    ) -> Result<Vec<GeminiImage>, GeminiImageError> {
+        let mut images = Vec::new();
+        for candidate in &chunk.candidates {
+            let pending = self.pending_text.entry(candidate.index).or_default();
+            collect_images(candidate, pending, &mut images)?;
+        }
+        Ok(images)
+    }
+}
+
+fn collect_images(
+    candidate: &Candidate,
+    pending_text: &mut String,
+    images: &mut Vec<GeminiImage>,
+) -> Result<(), GeminiImageError> {
+    let parts = candidate.content.iter().flat_map(|content| &content.parts);
+    for part in parts {
+        if let Some(text) = part.as_text() {
+            pending_text.push_str(text);
+            continue;
+        }
+        let Some((mime_type, data)) = part
+            .as_blob_base64()
+            .filter(|(mime_type, _)| mime_type.starts_with("image/"))
+        else {
+            continue;
+        };
+        let data = base64_decode(data).map_err(|_| GeminiImageError::InvalidBase64 {
+            candidate_index: candidate.index,
+        })?;
+        let text = std::mem::take(pending_text);
+        images.push(GeminiImage {
+            candidate_index: candidate.index,
+            mime_type: mime_type.to_owned(),
+            data,
+            text: (!text.trim().is_empty()).then_some(text),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::utils::base64_encode;
+
+    fn response(parts: serde_json::Value) -> GenerateContentResponse {
+        serde_json::from_value(json!({
+            "candidates":[{"content":{"role":"model","parts":parts},"index":0}],
+            "usageMetadata":{"promptTokenCount":1,"totalTokenCount":1}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn images_carry_preceding_text_and_extension() {
+        let response = response(json!([
+            {"text":"Step 1"},
+            {"inlineData":{"mimeType":"image/png","data":base64_encode(&[1, 2])}},
+            {"inlineData":{"mimeType":"image/jpeg","data":base64_encode(&[3])}},
+            {"inlineData":{"mimeType":"audio/wav","data":base64_encode(&[4])}}
+        ]));
+        let images = response.images().unwrap();
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].data, [1, 2]);
+        assert_eq!(images[0].text.as_deref(), Some("Step 1"));
+        assert_eq!(images[0].extension(), "png");
+        assert_eq!(images[1].text, None);
+        assert_eq!(images[1].extension(), "jpg");
+    }
+
+    #[test]
+    fn collector_emits_images_as_chunks_arrive() {
+        let mut collector = GeminiImageCollector::new();
+        assert!(collector
+            .push(&response(json!([{"text":"A red "}])))
+            .unwrap()
+            .is_empty());
+        let images = collector
+            .push(&response(json!([
+                {"text":"fox"},
+                {"inlineData":{"mimeType":"image/webp","data":base64_encode(&[9])}}
+            ])))
+            .unwrap();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].text.as_deref(), Some("A red fox"));
+
+        let invalid = response(json!([{"inlineData":{"mimeType":"image/png","data":"%%"}}]));
+        assert_eq!(
+            collector.push(&invalid),
+            Err(GeminiImageError::InvalidBase64 { candidate_index: 0 })
+        );
+    }
+
+    #[tokio::test]
+    async fn save_replaces_extension_and_write_to_flushes_bytes() {
+        let image = GeminiImage {
+            candidate_index: 0,
+            mime_type: "image/png".into(),
+            data: vec![0x89, b'P', b'N', b'G'],
+            text: None,
+        };
+        let path = std::env::temp_dir().join(format!("ai-client-image-{}.tmp", std::process::id()));
+        let saved = image.save(&path).await.unwrap();
+        assert_eq!(saved, path.with_extension("png"));
+        assert_eq!(std::fs::read(&saved).unwrap(), image.data);
+        std::fs::remove_file(saved).unwrap();
+
+        let mut buffer = Vec::new();
+        image.write_to(&mut buffer).await.unwrap();
+        assert_eq!(buffer, image.data);
+    }
+}
@@ -0,0 +1,889 @@
+use futures::{
+    stream::{SplitSink, SplitStream},
+    SinkExt as _, StreamExt as _,
+};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{
+        client::IntoClientRequest as _, protocol::frame::coding::CloseCode, Error as WsError,
+        Message,
+    },
+    MaybeTlsStream, WebSocketStream,
+};
+
+use crate::{
+    core::http::{response_metadata, MAX_ERROR_BODY_BYTES},
+    error::{
+        AiError, AiProvider, AiResult, BodySnippet, ConfigErrorKind, ProviderApiError,
+        ResponseMetadata, TransportErrorKind,
+    },
+    utils::base64_encode,
+};
+
+use super::{
+    client::decode_gemini_error, Content, FunctionCall, FunctionResponse, GeminiClient,
+    GeminiModelId, GenerationConfig, Part, Role, Tool,
+};
+
+/// Enables transcription of the audio sent or produced in a live session.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudioTranscriptionConfig {}
+
+/// The first message of a live session, fixing the model and its settings
+/// for the session's lifetime.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveSetup {
+    model: GeminiModelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generation_config: Option<GenerationConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_instruction: Option<Content>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_audio_transcription: Option<AudioTranscriptionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_audio_transcription: Option<AudioTranscriptionConfig>,
+}
+
+impl LiveSetup {
+    pub fn new(model: impl Into<GeminiModelId>) -> Self {
+        Self {
+            model: model.into(),
+            generation_config: None,
+            system_instruction: None,
+            tools: None,
+            input_audio_transcription: None,
+            output_audio_transcription: None,
+        }
+    }
+
+    /// Response modalities, speech settings, and sampling for the session.
+    pub fn generation_config(mut self, generation_config: GenerationConfig) -> Self {
+        self.generation_config = Some(generation_config);
+        self
+    }
+
+    pub fn system_instruction(mut self, text: impl Into<String>) -> Self {
+        self.system_instruction = Some(Content {
+            parts: vec![Part::text(text)],
+            role: None,
+        });
+        self
+    }
+
+    pub fn tools(mut self, tools: Vec<Tool>) -> Self {
+        self.tools = Some(tools);
+        self
+    }
+
+    /// Receive transcripts of the audio input.
+    pub fn input_audio_transcription(mut self) -> Self {
+        self.input_audio_transcription = Some(AudioTranscriptionConfig {});
+        self
+    }
+
+    /// Receive transcripts of the model's audio output.
+    pub fn output_audio_transcription(mut self) -> Self {
+        self.output_audio_transcription = Some(AudioTranscriptionConfig {});
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+enum ClientMessage<'a> {
+    Setup(&'a LiveSetup),
+    ClientContent {
+        turns: &'a [Content],
+        turn_complete: bool,
+    },
+    RealtimeInput(RealtimeInput<'a>),
+    ToolResponse {
+        function_responses: &'a [FunctionResponse],
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+enum RealtimeInput<'a> {
+    Audio(MediaChunk<'a>),
+    Video(MediaChunk<'a>),
+    AudioStreamEnd(bool),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MediaChunk<'a> {
+    mime_type: &'a str,
+    data: String,
+}
+
+/// Incremental transcript of input or output audio.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Transcription {
+    #[serde(default)]
+    pub text: String,
+}
+
+/// Model output and turn state.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveServerContent {
+    /// Parts generated so far for the current turn.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_turn: Option<Content>,
+    /// The model has finished its turn and is waiting for input.
+    #[serde(default)]
+    pub turn_complete: bool,
+    /// The user interrupted the model; discard queued playback.
+    #[serde(default)]
+    pub interrupted: bool,
+    /// The model has finished generating, though playback may continue.
+    #[serde(default)]
+    pub generation_complete: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_transcription: Option<Transcription>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_transcription: Option<Transcription>,
+}
+
+/// Token usage for a live session, reported periodically by the server.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveUsageMetadata {
+    #[serde(default)]
+    pub prompt_token_count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cached_content_token_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_token_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_use_prompt_token_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thoughts_token_count: Option<u64>,
+    #[serde(default)]
+    pub total_token_count: u64,
+    /// Per-modality breakdowns and fields added after this crate was released.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiveServerEvent {
+    SetupComplete,
+    Content(LiveServerContent),
+    /// The model asks the client to run functions and reply with
+    /// [`GeminiLiveSession::send_tool_response`].
+    ToolCall(Vec<FunctionCall>),
+    /// Previously issued tool calls that should not be executed after all.
+    ToolCallCancellation(Vec<String>),
+    /// The server will close the connection soon.
+    GoAway {
+        time_left: Option<String>,
+    },
+    /// A message carrying only `usage_metadata`.
+    Usage,
+    /// A message type this crate does not model, kept as received.
+    Unknown(serde_json::Value),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveServerMessage {
+    pub event: LiveServerEvent,
+    pub usage_metadata: Option<LiveUsageMetadata>,
+}
+
+impl LiveServerMessage {
+    fn from_json(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Wire {
+            setup_complete: Option<serde_json::Value>,
+            server_content: Option<LiveServerContent>,
+            tool_call: Option<ToolCallWire>,
+            tool_call_cancellation: Option<ToolCallCancellationWire>,
+            go_away: Option<GoAwayWire>,
+            usage_metadata: Option<LiveUsageMetadata>,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ToolCallWire {
+            #[serde(default)]
+            function_calls: Vec<FunctionCall>,
+        }
+        #[derive(Deserialize)]
+        struct ToolCallCancellationWire {
+            #[serde(default)]
+            ids: Vec<String>,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct GoAwayWire {
+            time_left: Option<String>,
+        }
+
+        let raw: serde_json::Value = serde_json::from_slice(bytes)?;
+        let wire: Wire = serde_json::from_value(raw.clone())?;
+        let event = if wire.setup_complete.is_some() {
+            LiveServerEvent::SetupComplete
+        } else if let Some(content) = wire.server_content {
+            LiveServerEvent::Content(content)
+        } else if let Some(tool_call) = wire.tool_call {
+            LiveServerEvent::ToolCall(tool_call.function_calls)
+        } else if let Some(cancellation) = wire.tool_call_cancellation {
+            LiveServerEvent::ToolCallCancellation(cancellation.ids)
+        } else if let Some(go_away) = wire.go_away {
+            LiveServerEvent::GoAway {
+                time_left: go_away.time_left,
+            }
+        } else if wire.usage_metadata.is_some() && raw.as_object().is_some_and(|raw| raw.len() == 1)
+        {
+            LiveServerEvent::Usage
+        } else {
+            LiveServerEvent::Unknown(raw)
+        };
+        Ok(Self {
+            event,
+            usage_metadata: wire.usage_metadata,
+        })
+    }
+}
+
+/// Opens `BidiGenerateContent` sessions with the Gemini Live API.
+#[derive(Clone, Copy)]
+pub struct LiveResource<'a> {
+    client: &'a GeminiClient,
+}
+
+impl<'a> LiveResource<'a> {
+    pub(crate) fn new(client: &'a GeminiClient) -> Self {
+        Self { client }
+    }
+
+    /// Connect, send `setup`, and wait for the server to acknowledge it.
+    pub async fn connect(&self, setup: &LiveSetup) -> AiResult<GeminiLiveSession> {
+        const OPERATION: &str = "live.connect";
+        let transport = self.client.transport();
+        let url = live_url(transport.base_url())?;
+        let mut request = url
+            .as_str()
+            .into_client_request()
+            .map_err(|error| live_error(OPERATION, error, None))?;
+        request.headers_mut().extend(transport.headers().clone());
+        let (socket, response) = connect_async(request)
+            .await
+            .map_err(|error| live_error(OPERATION, error, None))?;
+        let metadata = response_metadata(response.status(), response.headers());
+        let (sink, stream) = socket.split();
+        let mut session = GeminiLiveSession {
+            sender: GeminiLiveSender {
+                sink,
+                metadata: metadata.clone(),
+            },
+            receiver: GeminiLiveReceiver { stream, metadata },
+        };
+        session
+            .sender
+            .send(OPERATION, &ClientMessage::Setup(setup))
+            .await?;
+        match session.receiver.receive_for(OPERATION).await? {
+            Some(LiveServerMessage {
+                event: LiveServerEvent::SetupComplete,
+                ..
+            }) => Ok(session),
+            Some(_) => Err(AiError::Transport {
+                provider: AiProvider::Gemini,
+                operation: OPERATION,
+                kind: TransportErrorKind::Unknown,
+            }),
+            None => Err(AiError::Transport {
+                provider: AiProvider::Gemini,
+                operation: OPERATION,
+                kind: TransportErrorKind::Body,
+            }),
+        }
+    }
+}
+
+/// The WebSocket endpoint lives beside the REST API version:
+/// `{origin}/ws/google.ai.generativelanguage.{version}.GenerativeService.BidiGenerateContent`.
+fn live_url(base_url: &Url) -> AiResult<Url> {
+    let invalid = || {
+        AiError::config(
+            ConfigErrorKind::InvalidBaseUrl,
+            "base URL must end in an API version such as `/v1beta` to derive the Live API URL",
+        )
+    };
+    let mut segments: Vec<_> = base_url
+        .path_segments()
+        .ok_or_else(invalid)?
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let version = segments.pop().ok_or_else(invalid)?;
+    let service =
+        format!("google.ai.generativelanguage.{version}.GenerativeService.BidiGenerateContent");
+    let mut url = base_url.clone();
+    url.set_scheme(if base_url.scheme() == "https" {
+        "wss"
+    } else {
+        "ws"
+    })
+    .map_err(|_| invalid())?;
+    segments.extend(["ws", &service]);
+    url.set_path(&segments.join("/"));
+    Ok(url)
+}
+
+fn live_error(
+    operation: &'static str,
+    error: WsError,
+    metadata: Option<&ResponseMetadata>,
+) -> AiError {
+    let kind = match error {
+        WsError::Http(response) => {
+            let metadata = response_metadata(response.status(), response.headers());
+            let body = response.into_body().unwrap_or_default();
+            let truncated = body.len() > MAX_ERROR_BODY_BYTES;
+            let body = &body[..body.len().min(MAX_ERROR_BODY_BYTES)];
+            return AiError::Api {
+                provider: AiProvider::Gemini,
+                operation,
+                metadata: Box::new(metadata),
+                error: Box::new(decode_gemini_error(
+                    body,
+                    BodySnippet::from_bytes(body, truncated),
+                )),
+            };
+        }
+        WsError::Url(_) | WsError::HttpFormat(_) => {
+            return AiError::config(ConfigErrorKind::InvalidBaseUrl, "Live API URL is invalid")
+        }
+        WsError::Io(_) | WsError::Tls(_) if metadata.is_none() => TransportErrorKind::Connect,
+        WsError::Io(_) | WsError::ConnectionClosed | WsError::AlreadyClosed => {
+            TransportErrorKind::Body
+        }
+        WsError::Protocol(_) | WsError::Utf8(_) => TransportErrorKind::Body,
+        _ => TransportErrorKind::Unknown,
+    };
+    AiError::Transport {
+        provider: AiProvider::Gemini,
+        operation,
+        kind,
+    }
+}
+
+type LiveSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// An open Gemini Live session.
+///
+/// Every method takes `&mut self`, so a single task alternates between
+/// sending and receiving. To send audio while a [`Self::receive`] is pending,
+/// [`Self::split`] the session and drive each half from its own task or
+/// `select!` branch.
+pub struct GeminiLiveSession {
+    sender: GeminiLiveSender,
+    receiver: GeminiLiveReceiver,
+}
+
+impl GeminiLiveSession {
+    /// Metadata from the WebSocket handshake response.
+    pub fn metadata(&self) -> &ResponseMetadata {
+        &self.sender.metadata
+    }
+
+    /// Separate the session into halves that send and receive independently.
+    pub fn split(self) -> (GeminiLiveSender, GeminiLiveReceiver) {
+        (self.sender, self.receiver)
+    }
+
+    /// See [`GeminiLiveSender::send_client_content`].
+    pub async fn send_client_content(
+        &mut self,
+        turns: &[Content],
+        turn_complete: bool,
+    ) -> AiResult<()> {
+        self.sender.send_client_content(turns, turn_complete).await
+    }
+
+    /// See [`GeminiLiveSender::send_text`].
+    pub async fn send_text(&mut self, text: impl Into<String>) -> AiResult<()> {
+        self.sender.send_text(text).await
+    }
+
+    /// See [`GeminiLiveSender::send_audio`].
+    pub async fn send_audio(&mut self, mime_type: &str, data: &[u8]) -> AiResult<()> {
+        self.sender.send_audio(mime_type, data).await
+    }
+
+    /// See [`GeminiLiveSender::send_video`].
+    pub async fn send_video(&mut self, mime_type: &str, data: &[u8]) -> AiResult<()> {
+        self.sender.send_video(mime_type, data).await
+    }
+
+    /// See [`GeminiLiveSender::send_audio_stream_end`].
+    pub async fn send_audio_stream_end(&mut self) -> AiResult<()> {
+        self.sender.send_audio_stream_end().await
+    }
+
+    /// See [`GeminiLiveSender::send_tool_response`].
+    pub async fn send_tool_response(&mut self, responses: &[FunctionResponse]) -> AiResult<()> {
+        self.sender.send_tool_response(responses).await
+    }
+
+    /// See [`GeminiLiveReceiver::receive`].
+    pub async fn receive(&mut self) -> AiResult<Option<LiveServerMessage>> {
+        self.receiver.receive().await
+    }
+
+    pub async fn close(self) -> AiResult<()> {
+        self.sender.close().await
+    }
+}
+
+/// The sending half of a [`GeminiLiveSession`].
+pub struct GeminiLiveSender {
+    sink: SplitSink<LiveSocket, Message>,
+    metadata: ResponseMetadata,
+}
+
+impl GeminiLiveSender {
+    /// Append turns to the conversation. With `turn_complete`, the model
+    /// starts responding.
+    pub async fn send_client_content(
+        &mut self,
+        turns: &[Content],
+        turn_complete: bool,
+    ) -> AiResult<()> {
+        self.send(
+            "live.send_client_content",
+            &ClientMessage::ClientContent {
+                turns,
+                turn_complete,
+            },
+        )
+        .await
+    }
+
+    /// Send a complete user turn containing one text part.
+    pub async fn send_text(&mut self, text: impl Into<String>) -> AiResult<()> {
+        let turn = Content {
+            parts: vec![Part::text(text)],
+            role: Some(Role::User),
+        };
+        self.send_client_content(&[turn], true).await
+    }
+
+    /// Stream a chunk of audio, for example 16 kHz 16-bit PCM with
+    /// `audio/pcm;rate=16000`.
+    pub async fn send_audio(&mut self, mime_type: &str, data: &[u8]) -> AiResult<()> {
+        self.send(
+            "live.send_realtime_input",
+            &ClientMessage::RealtimeInput(RealtimeInput::Audio(MediaChunk {
+                mime_type,
+                data: base64_encode(data),
+            })),
+        )
+        .await
+    }
+
+    /// Stream one video frame, for example a JPEG image.
+    pub async fn send_video(&mut self, mime_type: &str, data: &[u8]) -> AiResult<()> {
+        self.send(
+            "live.send_realtime_input",
+            &ClientMessage::RealtimeInput(RealtimeInput::Video(MediaChunk {
+                mime_type,
+                data: base64_encode(data),
+            })),
+        )
+        .await
+    }
+
+    /// Signal that the microphone was paused so buffered audio is flushed.
+    pub async fn send_audio_stream_end(&mut self) -> AiResult<()> {
+        self.send(
+            "live.send_realtime_input",
+            &ClientMessage::RealtimeInput(RealtimeInput::AudioStreamEnd(true)),
+        )
+        .await
+    }
+
+    /// Reply to a [`LiveServerEvent::ToolCall`]. Each response must carry the
+    /// `id` of the call it answers.
+    pub async fn send_tool_response(&mut self, responses: &[FunctionResponse]) -> AiResult<()> {
+        self.send(
+            "live.send_tool_response",
+            &ClientMessage::ToolResponse {
+                function_responses: responses,
+            },
+        )
+        .await
+    }
+
+    /// Send a close frame. The [`GeminiLiveReceiver`] then ends once the
+    /// server acknowledges it.
+    pub async fn close(mut self) -> AiResult<()> {
+        self.sink
+            .close()
+            .await
+            .map_err(|error| live_error("live.close", error, Some(&self.metadata)))
+    }
+
+    async fn send(&mut self, operation: &'static str, message: &ClientMessage<'_>) -> AiResult<()> {
+        let json = serde_json::to_string(message).expect("live client messages serialize");
+        self.sink
+            .send(Message::text(json))
+            .await
+            .map_err(|error| live_error(operation, error, Some(&self.metadata)))
+    }
+}
+
+/// The receiving half of a [`GeminiLiveSession`].
+pub struct GeminiLiveReceiver {
+    stream: SplitStream<LiveSocket>,
+    metadata: ResponseMetadata,
+}
+
+impl GeminiLiveReceiver {
+    /// The next server message, or `None` once the server closed the session
+    /// normally. Closing with an error code is reported as [`AiError::Api`].
+    pub async fn receive(&mut self) -> AiResult<Option<LiveServerMessage>> {
+        self.receive_for("live.receive").await
+    }
+
+    async fn receive_for(
+        &mut self,
+        operation: &'static str,
+    ) -> AiResult<Option<LiveServerMessage>> {
+        loop {
+            let message = match self.stream.next().await {
+                None => return Ok(None),
+                Some(message) => {
+                    message.map_err(|error| live_error(operation, error, Some(&self.metadata)))?
+                }
+            };
+            let bytes = match &message {
+                Message::Text(text) => text.as_bytes(),
+                Message::Binary(bytes) => bytes,
+                Message::Close(frame) => {
+                    return match frame {
+                        Some(frame) if frame.code != CloseCode::Normal => Err(AiError::Api {
+                            provider: AiProvider::Gemini,
+                            operation,
+                            metadata: Box::new(self.metadata.clone()),
+                            error: Box::new(ProviderApiError::new(
+                                frame.reason.as_str(),
+                                Some(u16::from(frame.code).to_string()),
+                                None,
+                                None,
+                                BodySnippet::from_bytes(frame.reason.as_bytes(), false),
+                            )),
+                        }),
+                        _ => Ok(None),
+                    };
+                }
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => continue,
+            };
+            return LiveServerMessage::from_json(bytes)
+                .map(Some)
+                .map_err(|error| AiError::Decode {
+                    provider: AiProvider::Gemini,
+                    operation,
+                    metadata: Box::new(self.metadata.clone()),
+                    error: error.into(),
+                });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tokio_tungstenite::{
+        accept_hdr_async,
+        tungstenite::{
+            handshake::server::{ErrorResponse, Request, Response},
+            protocol::CloseFrame,
+        },
+    };
+
+    use super::*;
+    use crate::core::test_support::gemini_client;
+    use crate::gemini::ResponseModality;
+
+    async fn next_json(socket: &mut WebSocketStream<tokio::net::TcpStream>) -> serde_json::Value {
+        match socket.next().await.unwrap().unwrap() {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("unexpected frame {other:?}"),
+        }
+    }
+
+    #[test]
+    fn live_url_sits_beside_the_rest_version() {
+        let url = live_url(&Url::parse("https://example.com/proxy/v1beta/").unwrap()).unwrap();
+        assert_eq!(
+            url.as_str(),
+            "wss://example.com/proxy/ws/google.ai.generativelanguage.v1beta.GenerativeService.BidiGenerateContent"
+        );
+    }
+
+    // tungstenite's handshake callback signature fixes the error type.
+    #[allow(clippy::result_large_err)]
+    #[tokio::test]
+    async fn session_exchanges_setup_content_tools_and_typed_events() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut handshake = None;
+            let mut socket = accept_hdr_async(stream, |request: &Request, response: Response| {
+                handshake = Some((
+                    request.uri().path().to_owned(),
+                    request.headers()["x-goog-api-key"]
+                        .to_str()
+                        .unwrap()
+                        .to_owned(),
+                ));
+                Ok(response)
+            })
+            .await
+            .unwrap();
+            let mut received = vec![next_json(&mut socket).await];
+            for reply in [json!({"setupComplete":{}})] {
+                socket.send(Message::text(reply.to_string())).await.unwrap();
+            }
+            received.push(next_json(&mut socket).await);
+            received.push(next_json(&mut socket).await);
+            for reply in [
+                json!({
+                    "serverContent":{
+                        "modelTurn":{"role":"model","parts":[{"text":"Hi"}]},
+                        "outputTranscription":{"text":"Hi"}
+                    },
+                    "usageMetadata":{"promptTokenCount":3,"responseTokenCount":1,"totalTokenCount":4,
+                        "responseTokensDetails":[{"modality":"AUDIO","tokenCount":1}]}
+                }),
+                json!({"toolCall":{"functionCalls":[{"id":"c1","name":"lights","args":{"on":true}}]}}),
+            ] {
+                socket
+                    .send(Message::binary(reply.to_string().into_bytes()))
+                    .await
+                    .unwrap();
+            }
+            received.push(next_json(&mut socket).await);
+            for reply in [
+                json!({"serverContent":{"interrupted":true,"inputTranscription":{"text":"stop"}}}),
+                json!({"serverContent":{"turnComplete":true}}),
+                json!({"sessionResumptionUpdate":{"newHandle":"h1","resumable":true}}),
+            ] {
+                socket.send(Message::text(reply.to_string())).await.unwrap();
+            }
+            socket
+                .close(Some(CloseFrame {
+                    code: CloseCode::Policy,
+                    reason: "quota exceeded".into(),
+                }))
+                .await
+                .unwrap();
+            (handshake.unwrap(), received)
+        });
+
+        let client = gemini_client(format!("http://{address}/v1beta"));
+        let setup = LiveSetup::new(GeminiModelId::new("gemini-live-2.5-flash-preview").unwrap())
+            .generation_config(GenerationConfig {
+                response_modalities: Some(vec![ResponseModality::Audio.as_str().to_owned()]),
+                ..Default::default()
+            })
+            .output_audio_transcription();
+        let mut session = client.live().connect(&setup).await.unwrap();
+        session.send_text("Turn on the lights").await.unwrap();
+        session
+            .send_audio("audio/pcm;rate=16000", &[0, 1])
+            .await
+            .unwrap();
+
+        let message = session.receive().await.unwrap().unwrap();
+        let LiveServerEvent::Content(content) = &message.event else {
+            panic!("expected content, got {message:?}");
+        };
+        assert_eq!(
+            content.model_turn.as_ref().unwrap().parts,
+            [Part::text("Hi")]
+        );
+        assert_eq!(content.output_transcription.as_ref().unwrap().text, "Hi");
+        let usage = message.usage_metadata.unwrap();
+        assert_eq!(usage.response_token_count, Some(1));
+        assert!(usage.other.contains_key("responseTokensDetails"));
+
+        let message = session.receive().await.unwrap().unwrap();
+        let LiveServerEvent::ToolCall(calls) = message.event else {
+            panic!("expected tool call");
+        };
+        assert_eq!(calls[0].id.as_deref(), Some("c1"));
+        session
+            .send_tool_response(&[FunctionResponse {
+                id: calls[0].id.clone(),
+                name: calls[0].name.clone(),
+                response: json!({"ok":true}).as_object().unwrap().clone(),
+            }])
+            .await
+            .unwrap();
+
+        let LiveServerEvent::Content(content) = session.receive().await.unwrap().unwrap().event
+        else {
+            panic!("expected interruption");
+        };
+        assert!(content.interrupted);
+        assert_eq!(content.input_transcription.unwrap().text, "stop");
+        let LiveServerEvent::Content(content) = session.receive().await.unwrap().unwrap().event
+        else {
+            panic!("expected turn completion");
+        };
+        assert!(content.turn_complete);
+        assert_eq!(
+            session.receive().await.unwrap().unwrap().event,
+            LiveServerEvent::Unknown(
+                json!({"sessionResumptionUpdate":{"newHandle":"h1","resumable":true}})
+            )
+        );
+        let error = session.receive().await.unwrap_err();
+        let AiError::Api {
+            provider: AiProvider::Gemini,
+            operation: "live.receive",
+            error,
+            ..
+        } = error
+        else {
+            panic!("expected API error, got {error:?}");
+        };
+        assert_eq!(error.message(), "quota exceeded");
+        assert_eq!(error.code(), Some("1008"));
+
+        let ((path, api_key), received) = server.await.unwrap();
+        assert_eq!(
+            path,
+            "/ws/google.ai.generativelanguage.v1beta.GenerativeService.BidiGenerateContent"
+        );
+        assert_eq!(api_key, "test-key");
+        assert_eq!(
+            received,
+            [
+                json!({"setup":{
+                    "model":"models/gemini-live-2.5-flash-preview",
+                    "generationConfig":{"responseModalities":["AUDIO"]},
+                    "outputAudioTranscription":{}
+                }}),
+                json!({"clientContent":{
+                    "turns":[{"parts":[{"text":"Turn on the lights"}],"role":"user"}],
+                    "turnComplete":true
+                }}),
+                json!({"realtimeInput":{"audio":{"mimeType":"audio/pcm;rate=16000","data":"AAE="}}}),
+                json!({"toolResponse":{"functionResponses":[
+                    {"id":"c1","name":"lights","response":{"ok":true}}
+                ]}}),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn split_session_sends_audio_while_a_receive_is_pending() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            next_json(&mut socket).await;
+            socket
+                .send(Message::text(json!({"setupComplete":{}}).to_string()))
+                .await
+                .unwrap();
+            // Reply only once the audio arrives, so the client's receive is
+            // still pending while it sends.
+            let audio = next_json(&mut socket).await;
+            socket
+                .send(Message::text(
+                    json!({"serverContent":{"inputTranscription":{"text":"hello"}}}).to_string(),
+                ))
+                .await
+                .unwrap();
+            socket.close(None).await.unwrap();
+            audio
+        });
+
+        let (mut sender, mut receiver) = gemini_client(format!("http://{address}/v1beta"))
+            .live()
+            .connect(&LiveSetup::new(
+                GeminiModelId::new("gemini-live-2.5-flash-preview").unwrap(),
+            ))
+            .await
+            .unwrap()
+            .split();
+        let message = {
+            let receive = receiver.receive();
+            tokio::pin!(receive);
+            tokio::select! {
+                biased;
+                message = &mut receive => panic!("received {message:?} before sending"),
+                sent = sender.send_audio("audio/pcm;rate=16000", &[0, 1]) => {
+                    sent.unwrap();
+                    receive.await.unwrap().unwrap()
+                }
+            }
+        };
+        let LiveServerEvent::Content(content) = message.event else {
+            panic!("expected content, got {message:?}");
+        };
+        assert_eq!(content.input_transcription.unwrap().text, "hello");
+        assert!(receiver.receive().await.unwrap().is_none());
+
+        assert_eq!(
+            server.await.unwrap(),
+            json!({"realtimeInput":{"audio":{"mimeType":"audio/pcm;rate=16000","data":"AAE="}}})
+        );
+    }
+
+    // tungstenite's handshake callback signature fixes the error type.
+    #[allow(clippy::result_large_err)]
+    #[tokio::test]
+    async fn rejected_handshake_is_a_gemini_api_error() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let _ = accept_hdr_async(stream, |_: &Request, _: Response| {
+                let mut response = ErrorResponse::new(Some(
+                    r#"{"error":{"code":403,"message":"API key not valid","status":"PERMISSION_DENIED"}}"#.into(),
+                ));
+                *response.status_mut() = reqwest::StatusCode::FORBIDDEN;
+                Err(response)
+            })
+            .await;
+        });
+
+        let error = gemini_client(format!("http://{address}/v1beta"))
+            .live()
+            .connect(&LiveSetup::new(
+                GeminiModelId::new("gemini-live-2.5-flash-preview").unwrap(),
+            ))
+            .await
+            .err()
+            .unwrap();
+        let AiError::Api {
+            provider: AiProvider::Gemini,
+            operation: "live.connect",
+            metadata,
+            error,
+        } = error
+        else {
+            panic!("expected API error, got {error:?}");
+        };
+        assert_eq!(metadata.status, reqwest::StatusCode::FORBIDDEN);
+        assert_eq!(error.message(), "API key not valid");
+        assert_eq!(error.kind(), Some("PERMISSION_DENIED"));
+    }
+}
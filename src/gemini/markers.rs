@@ -0,0 +1,71 @@
+use super::{
+    GeminiGenerateContentModel, ProThinkingLevel, SupportsAudioOutput, SupportsCaching,
+    SupportsFunctionCalling, SupportsGoogleSearch, SupportsImageOutput, SupportsStructuredOutput,
+    SupportsThinking, ThinkingBudget, ThinkingLevel,
+};
+
+macro_rules! models {
+    ($($(#[$meta:meta])* $name:ident => $id:literal),+ $(,)?) => {$(
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+        impl GeminiGenerateContentModel for $name { const ID: &'static str = $id; }
+        impl $name {
+            /// Start a compile-time checked configuration for this model.
+            pub fn config() -> super::GeminiModelConfig<Self> {
+                super::GeminiModelConfig::new()
+            }
+        }
+        impl From<$name> for super::GeminiModelId {
+            fn from(_: $name) -> Self {
+                Self::new($id).expect("checked-in Gemini model IDs are valid")
+            }
+        }
+    )+};
+}
+
+models! {
+    Gemini3_5Flash => "gemini-3.5-flash",
+    Gemini3_1FlashLite => "gemini-3.1-flash-lite",
+    Gemini3_1ProPreview => "gemini-3.1-pro-preview",
+    Gemini3FlashPreview => "gemini-3-flash-preview",
+    Gemini2_5Flash => "gemini-2.5-flash",
+    Gemini2_5FlashLite => "gemini-2.5-flash-lite",
+    /// Gemini 3.1 Flash Image (Nano Banana 2).
+    Gemini3_1FlashImage => "gemini-3.1-flash-image",
+    /// Gemini 3.1 Flash Lite Image (Nano Banana 2 Lite).
+    Gemini3_1FlashLiteImage => "gemini-3.1-flash-lite-image",
+    /// Gemini 3 Pro Image (Nano Banana Pro).
+    Gemini3ProImage => "gemini-3-pro-image",
+    /// Gemini 2.5 Flash Image (Nano Banana).
+    Gemini2_5FlashImage => "gemini-2.5-flash-image",
+    /// Gemini 2.5 Flash text-to-speech.
+    Gemini2_5FlashPreviewTts => "gemini-2.5-flash-preview-tts",
+    /// Gemini 2.5 Pro text-to-speech.
+    Gemini2_5ProPreviewTts => "gemini-2.5-pro-preview-tts",
+}
+
+// Capability evidence taken from the per-model pages of the Gemini API
+// documentation. Image and speech models are single-purpose: they do not
+// accept tools, JSON schemas, thinking settings, or cached content, except
+// that Gemini 3 image models can ground images with Google Search.
+
+macro_rules! impl_trait {
+    ($trait:ident: $($model:ty),+ $(,)?) => { $(impl $trait for $model {})+ };
+}
+
+macro_rules! thinking {
+    ($setting:ty: $($model:ty),+ $(,)?) => { $(
+        impl SupportsThinking for $model { type Thinking = $setting; }
+    )+ };
+}
+
+impl_trait!(SupportsImageOutput: Gemini3_1FlashImage, Gemini3_1FlashLiteImage, Gemini3ProImage, Gemini2_5FlashImage);
+impl_trait!(SupportsAudioOutput: Gemini2_5FlashPreviewTts, Gemini2_5ProPreviewTts);
+impl_trait!(SupportsFunctionCalling: Gemini3_5Flash, Gemini3_1FlashLite, Gemini3_1ProPreview, Gemini3FlashPreview, Gemini2_5Flash, Gemini2_5FlashLite);
+impl_trait!(SupportsGoogleSearch: Gemini3_5Flash, Gemini3_1FlashLite, Gemini3_1ProPreview, Gemini3FlashPreview, Gemini2_5Flash, Gemini2_5FlashLite, Gemini3_1FlashImage, Gemini3ProImage);
+impl_trait!(SupportsStructuredOutput: Gemini3_5Flash, Gemini3_1FlashLite, Gemini3_1ProPreview, Gemini3FlashPreview, Gemini2_5Flash, Gemini2_5FlashLite);
+impl_trait!(SupportsCaching: Gemini3_5Flash, Gemini3_1FlashLite, Gemini3_1ProPreview, Gemini3FlashPreview, Gemini2_5Flash, Gemini2_5FlashLite);
+thinking!(ThinkingLevel: Gemini3_5Flash, Gemini3_1FlashLite, Gemini3FlashPreview);
+thinking!(ProThinkingLevel: Gemini3_1ProPreview);
+thinking!(ThinkingBudget: Gemini2_5Flash, Gemini2_5FlashLite);
@@ -1,9 +1,50 @@
+mod accumulator;
 mod api_types;
+mod batches;
+mod cached_contents;
+mod capability;
+mod chat;
 mod client;
+mod content_request;
+mod embeddings;
 mod generate_content;
+mod grounding;
+mod images;
+#[cfg(feature = "gemini-live")]
+mod live;
+mod markers;
 mod model;
+mod model_config;
+mod models;
+mod names;
+mod open_enum;
+mod operations;
+mod safety;
+mod speech;
+mod tools;
+mod videos;
 
+pub use accumulator::*;
 pub use api_types::*;
+pub use batches::*;
+pub use cached_contents::*;
+pub use capability::*;
+pub use chat::*;
 pub use client::*;
+pub use content_request::*;
+pub use embeddings::*;
 pub use generate_content::*;
+pub use grounding::*;
+pub use images::*;
+#[cfg(feature = "gemini-live")]
+pub use live::*;
+pub use markers::*;
 pub use model::*;
+pub use model_config::*;
+pub use models::*;
+pub use names::*;
+pub use operations::*;
+pub use safety::*;
+pub use speech::*;
+pub use tools::*;
+pub use videos::*;
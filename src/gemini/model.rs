@@ -141,6 +141,53 @@ impl GeminiModel {
     }
 }
 
+/// Gemini models that support `embedContent` and `batchEmbedContents`.
+#[non_exhaustive]
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    serde_with::DeserializeFromStr,
+    serde_with::SerializeDisplay,
+)]
+pub enum GeminiEmbeddingModel {
+    #[default]
+    GeminiEmbedding001,
+}
+
+impl Display for GeminiEmbeddingModel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            GeminiEmbeddingModel::GeminiEmbedding001 => "gemini-embedding-001",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for GeminiEmbeddingModel {
+    type Err = AiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("models/").unwrap_or(s) {
+            "gemini-embedding-001" => Ok(GeminiEmbeddingModel::GeminiEmbedding001),
+            _ => Err(AiError::Config {
+                kind: ConfigErrorKind::InvalidModel,
+                message: "unknown Gemini embedding model".to_string(),
+            }),
+        }
+    }
+}
+
+impl GeminiEmbeddingModel {
+    pub const ALL: &'static [Self] = &[Self::GeminiEmbedding001];
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelInfo {
@@ -208,7 +255,7 @@ impl Display for GenerationMethod {
 
 #[cfg(test)]
 mod tests {
-    use super::GeminiModel;
+    use super::{GeminiEmbeddingModel, GeminiModel};
     use std::str::FromStr;
 
     #[test]
@@ -222,4 +269,17 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn every_embedding_model_round_trips_its_wire_id() {
+        for model in GeminiEmbeddingModel::ALL {
+            let name = model.to_string();
+            assert_eq!(GeminiEmbeddingModel::from_str(&name).unwrap(), *model);
+            assert_eq!(
+                GeminiEmbeddingModel::from_str(&format!("models/{name}")).unwrap(),
+                *model
+            );
+        }
+        assert!(GeminiEmbeddingModel::from_str("gemini-2.5-flash").is_err());
+    }
 }
@@ -0,0 +1,157 @@
+use std::{fmt, marker::PhantomData};
+
+use super::{
+    GeminiGenerateContentModel, GeminiImageConfig, GenerationConfig, IntoThinkingConfig,
+    ResponseModality, SpeechConfig, SupportsAudioOutput, SupportsImageOutput, SupportsThinking,
+};
+
+/// Reusable, compile-time checked generation settings for one Gemini model.
+///
+/// Request content is deliberately separate, as with the OpenAI Responses
+/// model configuration: a request can be built once and sent to any model
+/// whose capabilities cover it.
+pub struct GeminiModelConfig<M: GeminiGenerateContentModel> {
+    generation_config: GenerationConfig,
+    model: PhantomData<fn() -> M>,
+}
+
+impl<M: GeminiGenerateContentModel> Clone for GeminiModelConfig<M> {
+    fn clone(&self) -> Self {
+        Self {
+            generation_config: self.generation_config.clone(),
+            model: PhantomData,
+        }
+    }
+}
+
+impl<M: GeminiGenerateContentModel> fmt::Debug for GeminiModelConfig<M> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("GeminiModelConfig")
+            .field("model", &M::ID)
+            .field("generation_config", &self.generation_config)
+            .finish()
+    }
+}
+
+impl<M: GeminiGenerateContentModel> Default for GeminiModelConfig<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: GeminiGenerateContentModel> GeminiModelConfig<M> {
+    /// Start configuring a custom or tuned model marker.
+    pub fn new() -> Self {
+        Self {
+            generation_config: GenerationConfig::default(),
+            model: PhantomData,
+        }
+    }
+
+    pub fn temperature(mut self, temperature: f64) -> Self {
+        self.generation_config.temperature = Some(temperature);
+        self
+    }
+
+    pub fn top_p(mut self, top_p: f64) -> Self {
+        self.generation_config.top_p = Some(top_p);
+        self
+    }
+
+    pub fn top_k(mut self, top_k: u64) -> Self {
+        self.generation_config.top_k = Some(top_k);
+        self
+    }
+
+    pub fn max_output_tokens(mut self, max_output_tokens: u64) -> Self {
+        self.generation_config.max_output_tokens = Some(max_output_tokens);
+        self
+    }
+
+    pub fn candidate_count(mut self, candidate_count: u64) -> Self {
+        self.generation_config.candidate_count = Some(candidate_count);
+        self
+    }
+
+    pub fn stop_sequences(mut self, stop_sequences: Vec<String>) -> Self {
+        self.generation_config.stop_sequences = Some(stop_sequences);
+        self
+    }
+
+    pub(crate) fn into_generation_config(self) -> GenerationConfig {
+        self.generation_config
+    }
+
+    fn response_modalities(&mut self, modalities: &[ResponseModality]) {
+        self.generation_config.response_modalities = Some(
+            modalities
+                .iter()
+                .map(|modality| modality.as_str().to_owned())
+                .collect(),
+        );
+    }
+}
+
+impl<M: SupportsThinking> GeminiModelConfig<M> {
+    /// Select how much the model thinks, using the model family's setting.
+    pub fn thinking(mut self, thinking: M::Thinking) -> Self {
+        let include_thoughts = self
+            .generation_config
+            .thinking_config
+            .as_ref()
+            .and_then(|config| config.include_thoughts);
+        let mut config = thinking.into_thinking_config();
+        config.include_thoughts = include_thoughts;
+        self.generation_config.thinking_config = Some(config);
+        self
+    }
+
+    /// Return thought summaries alongside the answer.
+    pub fn include_thoughts(mut self) -> Self {
+        self.generation_config
+            .thinking_config
+            .get_or_insert_with(Default::default)
+            .include_thoughts = Some(true);
+        self
+    }
+}
+
+impl<M: SupportsImageOutput> GeminiModelConfig<M> {
+    /// Return images, optionally interleaved with text.
+    pub fn image_output(mut self) -> Self {
+        self.response_modalities(&[ResponseModality::Text, ResponseModality::Image]);
+        self
+    }
+
+    /// Return only images, with no accompanying text.
+    pub fn image_only_output(mut self) -> Self {
+        self.response_modalities(&[ResponseModality::Image]);
+        self
+    }
+
+    /// Set the aspect ratio and resolution of generated images. This also
+    /// enables image output if no image modality was selected yet.
+    pub fn image_config(mut self, image_config: GeminiImageConfig) -> Self {
+        if self.generation_config.response_modalities.is_none() {
+            self.response_modalities(&[ResponseModality::Text, ResponseModality::Image]);
+        }
+        self.generation_config.image_config = Some(image_config);
+        self
+    }
+}
+
+impl<M: SupportsAudioOutput> GeminiModelConfig<M> {
+    /// Return generated speech.
+    pub fn audio_output(mut self) -> Self {
+        self.response_modalities(&[ResponseModality::Audio]);
+        self
+    }
+
+    /// Choose the voice, or one voice per speaker, and enable audio output.
+    pub fn speech(mut self, speech_config: SpeechConfig) -> Self {
+        self.response_modalities(&[ResponseModality::Audio]);
+        self.generation_config.speech_config = Some(speech_config);
+        self
+    }
+}
@@ -0,0 +1,219 @@
+use crate::error::{AiError, AiResponse, AiResult, ConfigErrorKind};
+#[cfg(feature = "stream")]
+use crate::{
+    core::sse,
+    error::AiProvider,
+    stream::{AiStream, SseJsonEvent},
+};
+
+use super::{
+    client::decode_gemini_error, CompatibleGenerateContentRequest, GeminiClient,
+    GeminiGenerateContentModel, GeminiModelConfig, GeminiModelId, GenerateContentResponse,
+};
+
+/// Marker IDs are unchecked constants, so validate them before building paths.
+fn marker_model_id<M: GeminiGenerateContentModel>() -> AiResult<GeminiModelId> {
+    GeminiModelId::new(M::ID).map_err(|error| AiError::Config {
+        kind: ConfigErrorKind::InvalidModel,
+        message: error.to_string(),
+    })
+}
+
+/// Compile-time checked `generateContent` calls for typed model markers.
+pub struct ModelsResource<'a> {
+    client: &'a GeminiClient,
+}
+
+impl<'a> ModelsResource<'a> {
+    pub(crate) fn new(client: &'a GeminiClient) -> Self {
+        Self { client }
+    }
+
+    /// Generate content with a typed model configuration. Requests that use
+    /// tools, JSON schemas, or cached content only compile for models with
+    /// the matching capability.
+    pub async fn generate_content<M, Request>(
+        &self,
+        config: GeminiModelConfig<M>,
+        request: Request,
+    ) -> AiResult<AiResponse<GenerateContentResponse>>
+    where
+        M: GeminiGenerateContentModel,
+        Request: CompatibleGenerateContentRequest<M>,
+    {
+        let [collection, action] = marker_model_id::<M>()?.action_segments("generateContent");
+        self.client
+            .transport()
+            .post_json_segments(
+                "models.generate_content",
+                &[&collection, &action],
+                &request.prepare(config),
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    /// Stream a typed `generateContent` call as server-sent events.
+    #[cfg(feature = "stream")]
+    pub async fn stream_generate_content<M, Request>(
+        &self,
+        config: GeminiModelConfig<M>,
+        request: Request,
+    ) -> AiResult<AiResponse<AiStream<SseJsonEvent<GenerateContentResponse>>>>
+    where
+        M: GeminiGenerateContentModel,
+        Request: CompatibleGenerateContentRequest<M>,
+    {
+        let [collection, action] = marker_model_id::<M>()?.action_segments("streamGenerateContent");
+        let response = self
+            .client
+            .transport()
+            .post_json_stream_segments_with_query(
+                "models.stream_generate_content_sse",
+                &[&collection, &action],
+                &[("alt".to_owned(), "sse".to_owned())],
+                &request.prepare(config),
+                decode_gemini_error,
+            )
+            .await?;
+        let (bytes, metadata) = response.into_parts();
+        Ok(AiResponse::new(
+            sse::json_events(
+                bytes,
+                AiProvider::Gemini,
+                "models.stream_generate_content_sse",
+            ),
+            metadata,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        core::test_support::{gemini_client, json_response, one_shot_server, request_json},
+        gemini::{
+            CachedContentName, FunctionDeclaration, Gemini2_5Flash, Gemini2_5FlashImage,
+            Gemini2_5FlashPreviewTts, Gemini3_1ProPreview, GeminiAspectRatio,
+            GeminiGenerateContentRequest, GeminiImageConfig, ProThinkingLevel, SpeechConfig,
+            ThinkingBudget, VoiceName,
+        },
+    };
+
+    const RESPONSE: &str =
+        r#"{"candidates":[],"usageMetadata":{"promptTokenCount":1,"totalTokenCount":1}}"#;
+
+    #[tokio::test]
+    async fn typed_request_merges_model_config_and_request_capabilities() {
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], RESPONSE)).await;
+        let request = GeminiGenerateContentRequest::builder()
+            .system_instruction("Be brief.")
+            .user_text("Weather in Paris?")
+            .function(FunctionDeclaration {
+                name: "weather".into(),
+                description: "Look up weather".into(),
+                parameters: None,
+                parameters_json_schema: Some(json!({"type":"object"})),
+            })
+            .response_json_schema(json!({"type":"object"}))
+            .cached_content(CachedContentName::new("abc").unwrap())
+            .build();
+        gemini_client(base_url)
+            .models()
+            .generate_content(
+                Gemini2_5Flash::config()
+                    .temperature(0.0)
+                    .include_thoughts()
+                    .thinking(ThinkingBudget::Tokens(512)),
+                request,
+            )
+            .await
+            .unwrap();
+        let captured = captured.await.unwrap();
+        assert!(captured
+            .starts_with("POST /v1beta/models/gemini-2.5-flash:generateContent HTTP/1.1\r\n"));
+        assert_eq!(
+            request_json(&captured),
+            json!({
+                "contents":[{"parts":[{"text":"Weather in Paris?"}],"role":"user"}],
+                "systemInstruction":{"parts":[{"text":"Be brief."}]},
+                "tools":[{"functionDeclarations":[{
+                    "name":"weather",
+                    "description":"Look up weather",
+                    "parametersJsonSchema":{"type":"object"}
+                }]}],
+                "generationConfig":{
+                    "temperature":0.0,
+                    "responseMimeType":"application/json",
+                    "responseJsonSchema":{"type":"object"},
+                    "thinkingConfig":{"includeThoughts":true,"thinkingBudget":512}
+                },
+                "cachedContent":"cachedContents/abc"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn image_and_thinking_level_settings_reach_the_wire() {
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], RESPONSE)).await;
+        gemini_client(base_url)
+            .models()
+            .generate_content(
+                Gemini2_5FlashImage::config().image_config(GeminiImageConfig {
+                    aspect_ratio: Some(GeminiAspectRatio::Landscape16x9),
+                    image_size: None,
+                }),
+                GeminiGenerateContentRequest::builder().user_text("A lighthouse"),
+            )
+            .await
+            .unwrap();
+        let captured = captured.await.unwrap();
+        assert!(captured.starts_with(
+            "POST /v1beta/models/gemini-2.5-flash-image:generateContent HTTP/1.1\r\n"
+        ));
+        assert_eq!(
+            request_json(&captured)["generationConfig"],
+            json!({"responseModalities":["TEXT","IMAGE"],"imageConfig":{"aspectRatio":"16:9"}})
+        );
+
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], RESPONSE)).await;
+        gemini_client(base_url)
+            .models()
+            .generate_content(
+                Gemini3_1ProPreview::config().thinking(ProThinkingLevel::Low),
+                GeminiGenerateContentRequest::builder().user_text("hi"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            request_json(&captured.await.unwrap())["generationConfig"],
+            json!({"thinkingConfig":{"thinkingLevel":"low"}})
+        );
+    }
+
+    #[tokio::test]
+    async fn speech_config_enables_audio_output() {
+        let (base_url, captured) =
+            one_shot_server("v1beta", json_response("200 OK", &[], RESPONSE)).await;
+        gemini_client(base_url)
+            .models()
+            .generate_content(
+                Gemini2_5FlashPreviewTts::config().speech(SpeechConfig::voice(VoiceName::Kore)),
+                GeminiGenerateContentRequest::builder().user_text("Say hello"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            request_json(&captured.await.unwrap())["generationConfig"],
+            json!({
+                "responseModalities":["AUDIO"],
+                "speechConfig":{"voiceConfig":{"prebuiltVoiceConfig":{"voiceName":"Kore"}}}
+            })
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use std::{fmt, str::FromStr};
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{GeminiEmbeddingModel, GeminiModel};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GeminiNameError {
+    #[error("cached content name must be `cachedContents/{{id}}` with a non-empty ID and no whitespace, control characters, or extra `/`")]
+    InvalidCachedContentName,
+    #[error("model ID must be `{{id}}`, `models/{{id}}`, or `tunedModels/{{id}}` with a non-empty ID and no whitespace, control characters, `:`, or extra `/`")]
+    InvalidModelId,
+    #[error("batch name must be `batches/{{id}}` with a non-empty ID and no whitespace, control characters, or extra `/`")]
+    InvalidBatchName,
+    #[error("file name must be `files/{{id}}` with a non-empty ID and no whitespace, control characters, or extra `/`")]
+    InvalidFileName,
+    #[error("operation name must end in `operations/{{id}}` and contain only non-empty segments without whitespace or control characters")]
+    InvalidOperationName,
+}
+
+/// Gemini resource names have the form `{collection}/{id}`. The ID is opaque
+/// but must be a single non-empty path segment.
+fn valid_resource_id(id: &str) -> bool {
+    !id.is_empty()
+        && !matches!(id, "." | "..")
+        && !id.chars().any(|character| {
+            character == '/' || character.is_whitespace() || character.is_control()
+        })
+}
+
+macro_rules! resource_name {
+    ($name:ident, $collection:literal, $error:expr) => {
+        #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(String);
+
+        impl $name {
+            pub const COLLECTION: &'static str = $collection;
+
+            /// Accepts either the full resource name or the bare resource ID.
+            pub fn new(value: impl Into<String>) -> Result<Self, GeminiNameError> {
+                let value = value.into();
+                let id = value
+                    .strip_prefix(concat!($collection, "/"))
+                    .unwrap_or(&value);
+                if !valid_resource_id(id) {
+                    return Err($error);
+                }
+                Ok(Self(format!(concat!($collection, "/{}"), id)))
+            }
+
+            /// The full resource name, for example `cachedContents/abc123`.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// The resource ID without its collection prefix.
+            pub fn id(&self) -> &str {
+                &self.0[$collection.len() + 1..]
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter
+                    .debug_tuple(stringify!($name))
+                    .field(&self.0)
+                    .finish()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str(&self.0)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = String::deserialize(deserializer)?;
+                Self::new(value).map_err(D::Error::custom)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = GeminiNameError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = GeminiNameError;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+    };
+}
+
+resource_name!(
+    CachedContentName,
+    "cachedContents",
+    GeminiNameError::InvalidCachedContentName
+);
+resource_name!(BatchName, "batches", GeminiNameError::InvalidBatchName);
+resource_name!(FileName, "files", GeminiNameError::InvalidFileName);
+
+/// Name of a long-running operation, for example
+/// `models/veo-3.1-generate-preview/operations/abc123` or `operations/abc123`.
+///
+/// Operations are nested under the resource that started them, so unlike
+/// other names this one may contain several segments.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OperationName(String);
+
+impl OperationName {
+    pub fn new(value: impl Into<String>) -> Result<Self, GeminiNameError> {
+        let value = value.into();
+        let segments: Vec<_> = value.split('/').collect();
+        let valid = segments.len() >= 2
+            && segments[segments.len() - 2] == "operations"
+            && segments.iter().all(|segment| valid_resource_id(segment));
+        if !valid {
+            return Err(GeminiNameError::InvalidOperationName);
+        }
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub(crate) fn segments(&self) -> Vec<&str> {
+        self.0.split('/').collect()
+    }
+}
+
+impl fmt::Debug for OperationName {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("OperationName")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl fmt::Display for OperationName {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(&self.0)
+    }
+}
+
+impl Serialize for OperationName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for OperationName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::new(value).map_err(D::Error::custom)
+    }
+}
+
+/// A validated Gemini model resource name.
+///
+/// Accepts a bare base-model ID such as `gemini-2.5-flash`, a full
+/// `models/{id}` name, or a tuned model name `tunedModels/{id}`. This lets
+/// callers use preview, tuned, and future models without a crate release;
+/// the known [`GeminiModel`] values convert into it.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GeminiModelId {
+    name: String,
+    id_start: usize,
+}
+
+impl GeminiModelId {
+    pub const BASE_MODELS: &'static str = "models";
+    pub const TUNED_MODELS: &'static str = "tunedModels";
+
+    pub fn new(value: impl Into<String>) -> Result<Self, GeminiNameError> {
+        let value = value.into();
+        let (collection, id) = match value.split_once('/') {
+            Some((collection @ (Self::BASE_MODELS | Self::TUNED_MODELS), id)) => (collection, id),
+            Some(_) => return Err(GeminiNameError::InvalidModelId),
+            None => (Self::BASE_MODELS, value.as_str()),
+        };
+        if !valid_resource_id(id) || id.contains(':') {
+            return Err(GeminiNameError::InvalidModelId);
+        }
+        Ok(Self {
+            name: format!("{collection}/{id}"),
+            id_start: collection.len() + 1,
+        })
+    }
+
+    /// The full resource name, for example `models/gemini-2.5-flash`.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// The model ID without its collection prefix.
+    pub fn id(&self) -> &str {
+        &self.name[self.id_start..]
+    }
+
+    /// `models` or `tunedModels`.
+    pub fn collection(&self) -> &str {
+        &self.name[..self.id_start - 1]
+    }
+
+    pub fn is_tuned(&self) -> bool {
+        self.collection() == Self::TUNED_MODELS
+    }
+
+    /// The checked-in model this ID names, if any.
+    pub fn known(&self) -> Option<GeminiModel> {
+        if self.is_tuned() {
+            return None;
+        }
+        self.id().parse().ok()
+    }
+
+    /// Request path segments for a custom method such as `generateContent`.
+    pub(crate) fn action_segments(&self, action: &str) -> [String; 2] {
+        [
+            self.collection().to_owned(),
+            format!("{}:{action}", self.id()),
+        ]
+    }
+}
+
+impl fmt::Debug for GeminiModelId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("GeminiModelId")
+            .field(&self.name)
+            .finish()
+    }
+}
+
+impl fmt::Display for GeminiModelId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(&self.name)
+    }
+}
+
+impl FromStr for GeminiModelId {
+    type Err = GeminiNameError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for GeminiModelId {
+    type Error = GeminiNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&str> for GeminiModelId {
+    type Error = GeminiNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<GeminiModel> for GeminiModelId {
+    fn from(model: GeminiModel) -> Self {
+        Self::new(model.to_string()).expect("known Gemini model IDs are valid")
+    }
+}
+
+impl From<GeminiEmbeddingModel> for GeminiModelId {
+    fn from(model: GeminiEmbeddingModel) -> Self {
+        Self::new(model.to_string()).expect("known Gemini model IDs are valid")
+    }
+}
+
+impl Serialize for GeminiModelId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.name)
+    }
+}
+
+impl<'de> Deserialize<'de> for GeminiModelId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::new(value).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn model_ids_accept_bare_base_and_tuned_names() {
+        let bare = GeminiModelId::new("gemini-2.5-flash").unwrap();
+        assert_eq!(bare, GeminiModelId::new("models/gemini-2.5-flash").unwrap());
+        assert_eq!(bare, GeminiModel::Gemini2_5Flash.into());
+        assert_eq!(bare.as_str(), "models/gemini-2.5-flash");
+        assert_eq!(bare.id(), "gemini-2.5-flash");
+        assert_eq!(bare.known(), Some(GeminiModel::Gemini2_5Flash));
+        assert_eq!(
+            bare.action_segments("generateContent"),
+            [
+                "models".to_owned(),
+                "gemini-2.5-flash:generateContent".to_owned()
+            ]
+        );
+
+        let preview = GeminiModelId::new("gemini-4-flash-preview-11-2026").unwrap();
+        assert_eq!(preview.known(), None);
+
+        let tuned = GeminiModelId::new("tunedModels/my-model-123").unwrap();
+        assert!(tuned.is_tuned());
+        assert_eq!(tuned.collection(), "tunedModels");
+        assert_eq!(tuned.id(), "my-model-123");
+        assert_eq!(
+            serde_json::to_value(&tuned).unwrap(),
+            "tunedModels/my-model-123"
+        );
+
+        for invalid in [
+            "",
+            "models/",
+            "cachedContents/x",
+            "models/a/b",
+            "gemini 2",
+            "gemini-2.5-flash:generateContent",
+            "..",
+        ] {
+            assert!(GeminiModelId::new(invalid).is_err(), "accepted {invalid:?}");
+        }
+        assert!(serde_json::from_str::<GeminiModelId>(r#""files/x""#).is_err());
+    }
+
+    #[test]
+    fn operation_names_allow_parent_segments() {
+        let nested = OperationName::new("models/veo-3.1-generate-preview/operations/abc").unwrap();
+        assert_eq!(
+            nested.segments(),
+            ["models", "veo-3.1-generate-preview", "operations", "abc"]
+        );
+        assert!(OperationName::new("operations/abc").is_ok());
+        for invalid in [
+            "",
+            "abc",
+            "operations/",
+            "models/x/abc",
+            "operations/a b",
+            "../operations/x",
+        ] {
+            assert!(OperationName::new(invalid).is_err(), "accepted {invalid:?}");
+        }
+    }
+
+    #[test]
+    fn resource_names_accept_full_or_bare_ids_and_reject_extra_segments() {
+        let full = CachedContentName::new("cachedContents/abc123").unwrap();
+        let bare = CachedContentName::new("abc123").unwrap();
+        assert_eq!(full, bare);
+        assert_eq!(full.as_str(), "cachedContents/abc123");
+        assert_eq!(full.id(), "abc123");
+        for invalid in [
+            "",
+            "cachedContents/",
+            "cachedContents/a/b",
+            "a b",
+            "..",
+            "cachedContents/x\n",
+        ] {
+            assert!(
+                CachedContentName::new(invalid).is_err(),
+                "accepted {invalid:?}"
+            );
+        }
+        assert!(serde_json::from_str::<CachedContentName>(r#""models/x""#).is_err());
+        assert_eq!(serde_json::to_value(full).unwrap(), "cachedContents/abc123");
+    }
+}
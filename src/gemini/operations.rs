@@ -0,0 +1,201 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+pub use crate::core::poll::PollBackoff;
+use crate::{
+    core::poll::poll_until,
+    error::{AiProvider, AiResponse, AiResult},
+};
+
+use super::{client::decode_gemini_error, GeminiClient, OperationName};
+
+/// A `google.rpc.Status` describing why an operation or request failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[serde(rename_all = "camelCase")]
+#[error("{message} (code {code})")]
+pub struct RpcStatus {
+    #[serde(default)]
+    pub code: i32,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<serde_json::Value>,
+}
+
+/// A long-running operation whose successful response decodes as `T`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "OperationWire<T>", bound(deserialize = "T: Deserialize<'de>"))]
+pub struct Operation<T> {
+    pub name: OperationName,
+    pub done: bool,
+    /// Provider-specific progress information.
+    pub metadata: Option<serde_json::Value>,
+    /// Set once `done` is true.
+    pub result: Option<Result<T, RpcStatus>>,
+}
+
+impl<T> Operation<T> {
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// The response or error, or `None` while the operation is running.
+    pub fn into_result(self) -> Option<Result<T, RpcStatus>> {
+        self.result
+    }
+}
+
+#[derive(Deserialize)]
+struct OperationWire<T> {
+    name: OperationName,
+    #[serde(default)]
+    done: bool,
+    #[serde(default)]
+    metadata: Option<serde_json::Value>,
+    #[serde(default = "Option::default")]
+    response: Option<T>,
+    #[serde(default)]
+    error: Option<RpcStatus>,
+}
+
+impl<T> From<OperationWire<T>> for Operation<T> {
+    fn from(wire: OperationWire<T>) -> Self {
+        let result = match (wire.error, wire.response) {
+            (Some(error), _) => Some(Err(error)),
+            (None, Some(response)) => Some(Ok(response)),
+            (None, None) => None,
+        };
+        Self {
+            name: wire.name,
+            done: wire.done,
+            metadata: wire.metadata,
+            result,
+        }
+    }
+}
+
+/// Gemini's long-running operations API.
+#[derive(Clone, Copy)]
+pub struct OperationsResource<'a> {
+    client: &'a GeminiClient,
+}
+
+impl<'a> OperationsResource<'a> {
+    pub(crate) fn new(client: &'a GeminiClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        name: &OperationName,
+    ) -> AiResult<AiResponse<Operation<T>>> {
+        self.client
+            .transport()
+            .get_json_segments("operations.get", &name.segments(), &[], decode_gemini_error)
+            .await
+    }
+
+    /// Poll until the operation is done, sleeping between polls according to
+    /// `backoff`. Returns the final operation, which may hold an error.
+    pub async fn wait<T: DeserializeOwned>(
+        &self,
+        name: &OperationName,
+        backoff: PollBackoff,
+    ) -> AiResult<AiResponse<Operation<T>>> {
+        poll_until(
+            AiProvider::Gemini,
+            "operations.wait",
+            backoff,
+            || self.get::<T>(name),
+            |operation| operation.done,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        core::test_support::{gemini_client, json_response, one_shot_server, sequence_server},
+        error::AiError,
+    };
+
+    fn fast_backoff() -> PollBackoff {
+        PollBackoff {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn operations_decode_running_succeeded_and_failed_states() {
+        let running: Operation<serde_json::Value> =
+            serde_json::from_value(json!({"name":"operations/a"})).unwrap();
+        assert!(!running.is_done());
+        assert_eq!(running.into_result(), None);
+
+        let failed: Operation<serde_json::Value> = serde_json::from_value(
+            json!({"name":"operations/a","done":true,"error":{"code":13,"message":"boom"}}),
+        )
+        .unwrap();
+        assert_eq!(failed.into_result().unwrap().unwrap_err().code, 13);
+    }
+
+    #[tokio::test]
+    async fn wait_polls_until_done() {
+        let pending = r#"{"name":"models/veo/operations/op1","done":false}"#;
+        let done = r#"{"name":"models/veo/operations/op1","done":true,"response":{"value":7}}"#;
+        let (base_url, requests) = sequence_server(
+            "v1beta",
+            vec![
+                json_response("200 OK", &[], pending),
+                json_response("200 OK", &[], pending),
+                json_response("200 OK", &[], done),
+            ],
+        )
+        .await;
+        let name = OperationName::new("models/veo/operations/op1").unwrap();
+        let operation = gemini_client(base_url)
+            .operations()
+            .wait::<serde_json::Value>(&name, fast_backoff())
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(operation.into_result(), Some(Ok(json!({"value":7}))));
+        let requests = requests.await.unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("GET /v1beta/models/veo/operations/op1 HTTP/1.1\r\n"));
+    }
+
+    #[tokio::test]
+    async fn wait_times_out_while_running() {
+        let (base_url, _) = one_shot_server(
+            "v1beta",
+            json_response("200 OK", &[], r#"{"name":"operations/op1"}"#),
+        )
+        .await;
+        let error = gemini_client(base_url)
+            .operations()
+            .wait::<serde_json::Value>(
+                &OperationName::new("operations/op1").unwrap(),
+                PollBackoff {
+                    timeout: Some(Duration::ZERO),
+                    ..fast_backoff()
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            AiError::Timeout {
+                operation: "operations.wait",
+                ..
+            }
+        ));
+    }
+}
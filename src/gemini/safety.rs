@@ -0,0 +1,273 @@
+use std::fmt::Display;
+
+use super::{
+    BlockReason, Candidate, FinishReason, GeminiImage, GeminiImageError, GenerateContentResponse,
+    Part, SafetyRating,
+};
+
+/// Why a response carries no usable output.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GeminiBlockReason {
+    /// The prompt itself was rejected; no candidates were generated.
+    Prompt(BlockReason),
+    /// A candidate stopped for a safety-related finish reason.
+    Candidate(FinishReason),
+}
+
+impl Display for GeminiBlockReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeminiBlockReason::Prompt(reason) => write!(f, "{reason}"),
+            GeminiBlockReason::Candidate(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+/// A prompt or candidate that was blocked by Gemini's safety filters.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{}: {reason}", match candidate_index {
+    Some(index) => format!("candidate {index} was blocked"),
+    None => "prompt was blocked".to_owned(),
+})]
+pub struct GeminiBlocked {
+    pub reason: GeminiBlockReason,
+    /// Ratings flagged as blocked, or every rating when none is flagged.
+    pub safety_ratings: Vec<SafetyRating>,
+    /// Index of the blocked candidate, or `None` when the prompt was blocked.
+    pub candidate_index: Option<u64>,
+}
+
+impl FinishReason {
+    /// Whether the candidate was stopped by a safety or policy filter.
+    pub fn is_blocked(&self) -> bool {
+        matches!(
+            self,
+            FinishReason::Safety
+                | FinishReason::Recitation
+                | FinishReason::Blocklist
+                | FinishReason::ProhibitedContent
+                | FinishReason::Spii
+                | FinishReason::ImageSafety
+                | FinishReason::ImageProhibitedContent
+                | FinishReason::ImageRecitation
+        )
+    }
+}
+
+/// Output of an unblocked response, returned by
+/// [`GenerateContentResponse::checked_output`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeminiOutput<'a> {
+    /// The first candidate, or `None` for chunks that only carry metadata.
+    pub candidate: Option<&'a Candidate>,
+}
+
+impl<'a> GeminiOutput<'a> {
+    /// Concatenated text parts of the candidate.
+    pub fn text(&self) -> String {
+        self.parts().filter_map(Part::as_text).collect()
+    }
+
+    pub fn images(&self) -> Result<Vec<GeminiImage>, GeminiImageError> {
+        self.candidate
+            .map(Candidate::images)
+            .unwrap_or_else(|| Ok(Vec::new()))
+    }
+
+    fn parts(&self) -> impl Iterator<Item = &'a Part> {
+        self.candidate
+            .and_then(|candidate| candidate.content.as_ref())
+            .into_iter()
+            .flat_map(|content| &content.parts)
+    }
+}
+
+impl Candidate {
+    /// The safety block on this candidate, if any.
+    pub fn blocked(&self) -> Option<GeminiBlocked> {
+        let reason = self
+            .finish_reason
+            .as_ref()
+            .filter(|reason| reason.is_blocked())?;
+        Some(GeminiBlocked {
+            reason: GeminiBlockReason::Candidate(reason.clone()),
+            safety_ratings: offending(&self.safety_ratings),
+            candidate_index: Some(self.index),
+        })
+    }
+}
+
+impl GenerateContentResponse {
+    /// The prompt block, or the block on the first blocked candidate, if any.
+    ///
+    /// Only the fields of `self` are inspected, so a streamed chunk and the
+    /// response accumulated from it report the same block.
+    pub fn blocked(&self) -> Option<GeminiBlocked> {
+        if let Some(feedback) = &self.prompt_feedback {
+            if let Some(reason) = feedback.block_reason {
+                return Some(GeminiBlocked {
+                    reason: GeminiBlockReason::Prompt(reason),
+                    safety_ratings: offending(feedback.safety_ratings.as_deref().unwrap_or(&[])),
+                    candidate_index: None,
+                });
+            }
+        }
+        self.candidates.iter().find_map(Candidate::blocked)
+    }
+
+    /// The first candidate's output, or the reason the prompt or any
+    /// candidate was blocked.
+    pub fn checked_output(&self) -> Result<GeminiOutput<'_>, GeminiBlocked> {
+        match self.blocked() {
+            Some(blocked) => Err(blocked),
+            None => Ok(GeminiOutput {
+                candidate: self.candidates.first(),
+            }),
+        }
+    }
+
+    /// Shorthand for `checked_output().map(|output| output.text())`.
+    pub fn checked_text(&self) -> Result<String, GeminiBlocked> {
+        self.checked_output().map(|output| output.text())
+    }
+}
+
+fn offending(ratings: &[SafetyRating]) -> Vec<SafetyRating> {
+    let blocked: Vec<_> = ratings
+        .iter()
+        .filter(|rating| rating.blocked())
+        .cloned()
+        .collect();
+    if blocked.is_empty() {
+        ratings.to_vec()
+    } else {
+        blocked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::gemini::{GeminiStreamAccumulator, HarmCategory};
+
+    fn response(value: serde_json::Value) -> GenerateContentResponse {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn prompt_block_reports_reason_and_ratings() {
+        let response = response(json!({
+            "promptFeedback":{"blockReason":"SAFETY","safetyRatings":[
+                {"category":"HARM_CATEGORY_HARASSMENT","probability":"NEGLIGIBLE"},
+                {"category":"HARM_CATEGORY_DANGEROUS_CONTENT","probability":"HIGH","blocked":true}
+            ]},
+            "usageMetadata":{"promptTokenCount":3,"totalTokenCount":3}
+        }));
+        let blocked = response.checked_output().unwrap_err();
+        assert_eq!(
+            blocked.reason,
+            GeminiBlockReason::Prompt(BlockReason::Safety)
+        );
+        assert_eq!(blocked.candidate_index, None);
+        assert_eq!(blocked.safety_ratings.len(), 1);
+        assert_eq!(
+            blocked.safety_ratings[0].category,
+            HarmCategory::DangerousContent
+        );
+        assert!(blocked.to_string().starts_with("prompt was blocked: "));
+    }
+
+    #[test]
+    fn candidate_block_matches_between_chunks_and_accumulated_response() {
+        let chunks = [
+            json!({
+                "candidates":[{"content":{"role":"model","parts":[{"text":"Sure, "}]},"index":0}],
+                "usageMetadata":{"promptTokenCount":3,"totalTokenCount":4}
+            }),
+            json!({
+                "candidates":[{"finishReason":"SAFETY","index":0,"safetyRatings":[
+                    {"category":"HARM_CATEGORY_HATE_SPEECH","probability":"MEDIUM"}
+                ]}],
+                "usageMetadata":{"promptTokenCount":3,"totalTokenCount":5}
+            }),
+        ];
+        let mut accumulator = GeminiStreamAccumulator::new();
+        let mut chunk_results = Vec::new();
+        for chunk in chunks {
+            let chunk = response(chunk);
+            chunk_results.push(chunk.checked_text());
+            accumulator.push(chunk).unwrap();
+        }
+        assert_eq!(chunk_results[0].as_deref(), Ok("Sure, "));
+        let from_chunk = chunk_results[1].clone().unwrap_err();
+        let accumulated = accumulator.response().unwrap().checked_text().unwrap_err();
+        assert_eq!(from_chunk, accumulated);
+        assert_eq!(
+            accumulated.reason,
+            GeminiBlockReason::Candidate(FinishReason::Safety)
+        );
+        assert_eq!(accumulated.candidate_index, Some(0));
+        assert_eq!(accumulated.safety_ratings.len(), 1);
+    }
+
+    #[test]
+    fn any_blocked_candidate_blocks_the_response() {
+        let response = response(json!({
+            "candidates":[
+                {"content":{"role":"model","parts":[{"text":"Hi"}]},"finishReason":"STOP","index":0},
+                {"finishReason":"IMAGE_PROHIBITED_CONTENT","index":1}
+            ],
+            "usageMetadata":{"promptTokenCount":1,"totalTokenCount":2}
+        }));
+        let blocked = response.checked_output().unwrap_err();
+        assert_eq!(
+            blocked.reason,
+            GeminiBlockReason::Candidate(FinishReason::ImageProhibitedContent)
+        );
+        assert_eq!(blocked.candidate_index, Some(1));
+    }
+
+    #[test]
+    fn finish_reasons_keep_new_values() {
+        let reasons: Vec<FinishReason> = serde_json::from_value(json!([
+            "IMAGE_RECITATION",
+            "NO_IMAGE",
+            "TOO_MANY_TOOL_CALLS",
+            "SOME_FUTURE_REASON"
+        ]))
+        .unwrap();
+        assert_eq!(
+            reasons,
+            [
+                FinishReason::ImageRecitation,
+                FinishReason::NoImage,
+                FinishReason::TooManyToolCalls,
+                FinishReason::Unknown("SOME_FUTURE_REASON".into()),
+            ]
+        );
+        assert!(reasons[0].is_blocked());
+        assert!(!reasons[1].is_blocked());
+        assert!(!reasons[3].is_blocked());
+        assert_eq!(
+            serde_json::to_value(&reasons[3]).unwrap(),
+            json!("SOME_FUTURE_REASON")
+        );
+    }
+
+    #[test]
+    fn unblocked_output_exposes_text_and_metadata_only_chunks() {
+        let ok = response(json!({
+            "candidates":[{"content":{"role":"model","parts":[{"text":"Hi"}]},"finishReason":"STOP","index":0}],
+            "usageMetadata":{"promptTokenCount":1,"totalTokenCount":2}
+        }));
+        assert_eq!(ok.checked_text().unwrap(), "Hi");
+        assert!(ok.checked_output().unwrap().images().unwrap().is_empty());
+
+        let usage_only = response(json!({
+            "usageMetadata":{"promptTokenCount":1,"totalTokenCount":2}
+        }));
+        assert_eq!(usage_only.checked_text().unwrap(), "");
+    }
+}
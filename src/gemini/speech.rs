@@ -0,0 +1,443 @@
+use std::{fmt, io, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::utils::base64_decode;
+
+use super::{Candidate, GenerateContentResponse};
+
+/// Voice selection for text-to-speech output.
+///
+/// Set either a single voice or a multi-speaker mapping, not both.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeechConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_config: Option<VoiceConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_speaker_voice_config: Option<MultiSpeakerVoiceConfig>,
+    /// BCP-47 language code, for example `en-US`. Detected automatically when
+    /// omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl SpeechConfig {
+    /// Speak every line with one prebuilt voice.
+    pub fn voice(voice_name: VoiceName) -> Self {
+        Self {
+            voice_config: Some(VoiceConfig::prebuilt(voice_name)),
+            ..Default::default()
+        }
+    }
+
+    /// Map speaker names used in the prompt, such as `Joe` in `Joe: Hi!`, to
+    /// voices. The API currently accepts two speakers.
+    pub fn multi_speaker<S>(speakers: impl IntoIterator<Item = (S, VoiceName)>) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            multi_speaker_voice_config: Some(MultiSpeakerVoiceConfig {
+                speaker_voice_configs: speakers
+                    .into_iter()
+                    .map(|(speaker, voice_name)| SpeakerVoiceConfig {
+                        speaker: speaker.into(),
+                        voice_config: VoiceConfig::prebuilt(voice_name),
+                    })
+                    .collect(),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prebuilt_voice_config: Option<PrebuiltVoiceConfig>,
+}
+
+impl VoiceConfig {
+    pub fn prebuilt(voice_name: VoiceName) -> Self {
+        Self {
+            prebuilt_voice_config: Some(PrebuiltVoiceConfig { voice_name }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrebuiltVoiceConfig {
+    pub voice_name: VoiceName,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiSpeakerVoiceConfig {
+    pub speaker_voice_configs: Vec<SpeakerVoiceConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeakerVoiceConfig {
+    /// Speaker name as it appears in the prompt.
+    pub speaker: String,
+    pub voice_config: VoiceConfig,
+}
+
+macro_rules! voices {
+    ($($(#[$meta:meta])* $name:ident),+ $(,)?) => {
+        /// Prebuilt text-to-speech voices.
+        ///
+        /// `Other` carries voice names added after this crate was released.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum VoiceName {
+            $($(#[$meta])* $name,)+
+            Other(String),
+        }
+
+        impl VoiceName {
+            pub const ALL: &'static [Self] = &[$(Self::$name),+];
+
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$name => stringify!($name),)+
+                    Self::Other(name) => name,
+                }
+            }
+        }
+
+        impl FromStr for VoiceName {
+            type Err = std::convert::Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok(match value {
+                    $(stringify!($name) => Self::$name,)+
+                    other => Self::Other(other.to_owned()),
+                })
+            }
+        }
+    };
+}
+
+voices! {
+    /// Bright.
+    Zephyr,
+    /// Upbeat.
+    Puck,
+    /// Informative.
+    Charon,
+    /// Firm.
+    Kore,
+    /// Excitable.
+    Fenrir,
+    /// Youthful.
+    Leda,
+    /// Firm.
+    Orus,
+    /// Breezy.
+    Aoede,
+    /// Easy-going.
+    Callirrhoe,
+    /// Bright.
+    Autonoe,
+    /// Breathy.
+    Enceladus,
+    /// Clear.
+    Iapetus,
+    /// Easy-going.
+    Umbriel,
+    /// Smooth.
+    Algieba,
+    /// Smooth.
+    Despina,
+    /// Clear.
+    Erinome,
+    /// Gravelly.
+    Algenib,
+    /// Informative.
+    Rasalgethi,
+    /// Upbeat.
+    Laomedeia,
+    /// Soft.
+    Achernar,
+    /// Firm.
+    Alnilam,
+    /// Even.
+    Schedar,
+    /// Mature.
+    Gacrux,
+    /// Forward.
+    Pulcherrima,
+    /// Friendly.
+    Achird,
+    /// Casual.
+    Zubenelgenubi,
+    /// Gentle.
+    Vindemiatrix,
+    /// Lively.
+    Sadachbia,
+    /// Knowledgeable.
+    Sadaltager,
+    /// Warm.
+    Sulafat,
+}
+
+impl fmt::Display for VoiceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for VoiceName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for VoiceName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(value.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GeminiAudioError {
+    #[error("audio part is not valid base64")]
+    InvalidBase64,
+    #[error("audio parts use different formats: `{first}` and `{other}`")]
+    MixedFormats { first: String, other: String },
+}
+
+/// Raw audio returned by a text-to-speech model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeminiAudio {
+    /// MIME type reported by the provider, for example
+    /// `audio/L16;codec=pcm;rate=24000`.
+    pub mime_type: String,
+    /// Little-endian signed 16-bit PCM samples.
+    pub pcm: Vec<u8>,
+}
+
+impl GeminiAudio {
+    /// Sample rate from the MIME type's `rate` parameter, defaulting to the
+    /// 24 kHz that Gemini speech models produce.
+    pub fn sample_rate(&self) -> u32 {
+        self.mime_type
+            .split(';')
+            .filter_map(|parameter| parameter.trim().strip_prefix("rate="))
+            .find_map(|rate| rate.parse().ok())
+            .unwrap_or(WavSpec::GEMINI_TTS.sample_rate)
+    }
+
+    pub fn wav_spec(&self) -> WavSpec {
+        WavSpec {
+            sample_rate: self.sample_rate(),
+            ..WavSpec::GEMINI_TTS
+        }
+    }
+
+    /// Package the PCM samples as a playable WAV file.
+    pub fn to_wav(&self) -> io::Result<Vec<u8>> {
+        let mut wav = Vec::with_capacity(WAV_HEADER_LEN + self.pcm.len());
+        write_wav(&mut wav, self.wav_spec(), &self.pcm)?;
+        Ok(wav)
+    }
+}
+
+impl Candidate {
+    /// Concatenated audio from this candidate's `audio/*` inline data parts.
+    ///
+    /// Returns `Ok(None)` when the candidate contains no audio.
+    pub fn audio(&self) -> Result<Option<GeminiAudio>, GeminiAudioError> {
+        let mut audio: Option<GeminiAudio> = None;
+        let parts = self.content.iter().flat_map(|content| &content.parts);
+        for part in parts {
+            let Some((mime_type, data)) = part
+                .as_blob_base64()
+                .filter(|(mime_type, _)| mime_type.starts_with("audio/"))
+            else {
+                continue;
+            };
+            let bytes = base64_decode(data).map_err(|_| GeminiAudioError::InvalidBase64)?;
+            match &mut audio {
+                Some(audio) if audio.mime_type != mime_type => {
+                    return Err(GeminiAudioError::MixedFormats {
+                        first: audio.mime_type.clone(),
+                        other: mime_type.to_owned(),
+                    })
+                }
+                Some(audio) => audio.pcm.extend(bytes),
+                None => {
+                    audio = Some(GeminiAudio {
+                        mime_type: mime_type.to_owned(),
+                        pcm: bytes,
+                    })
+                }
+            }
+        }
+        Ok(audio)
+    }
+}
+
+impl GenerateContentResponse {
+    /// Audio from the first candidate, as returned for `AUDIO` response
+    /// modality requests.
+    pub fn audio(&self) -> Result<Option<GeminiAudio>, GeminiAudioError> {
+        match self.candidates.first() {
+            Some(candidate) => candidate.audio(),
+            None => Ok(None),
+        }
+    }
+}
+
+const WAV_HEADER_LEN: usize = 44;
+
+/// Layout of little-endian integer PCM data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WavSpec {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub bits_per_sample: u16,
+}
+
+impl WavSpec {
+    /// Gemini speech output: 24 kHz, mono, 16-bit.
+    pub const GEMINI_TTS: Self = Self {
+        sample_rate: 24_000,
+        channels: 1,
+        bits_per_sample: 16,
+    };
+}
+
+/// Write `pcm` as a canonical RIFF/WAVE file with a 44-byte header.
+pub fn write_wav<W: io::Write>(mut writer: W, spec: WavSpec, pcm: &[u8]) -> io::Result<()> {
+    let too_large = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "PCM data or layout exceeds WAV limits",
+        )
+    };
+    let data_len = u32::try_from(pcm.len()).map_err(|_| too_large())?;
+    let riff_len = data_len.checked_add(36).ok_or_else(too_large)?;
+    let block_align = spec
+        .channels
+        .checked_mul(spec.bits_per_sample.div_ceil(8))
+        .ok_or_else(too_large)?;
+    let byte_rate = spec
+        .sample_rate
+        .checked_mul(u32::from(block_align))
+        .ok_or_else(too_large)?;
+
+    writer.write_all(b"RIFF")?;
+    writer.write_all(&riff_len.to_le_bytes())?;
+    writer.write_all(b"WAVEfmt ")?;
+    writer.write_all(&16u32.to_le_bytes())?;
+    writer.write_all(&1u16.to_le_bytes())?;
+    writer.write_all(&spec.channels.to_le_bytes())?;
+    writer.write_all(&spec.sample_rate.to_le_bytes())?;
+    writer.write_all(&byte_rate.to_le_bytes())?;
+    writer.write_all(&block_align.to_le_bytes())?;
+    writer.write_all(&spec.bits_per_sample.to_le_bytes())?;
+    writer.write_all(b"data")?;
+    writer.write_all(&data_len.to_le_bytes())?;
+    writer.write_all(pcm)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::utils::base64_encode;
+
+    #[test]
+    fn speech_config_serializes_single_and_multi_speaker_voices() {
+        assert_eq!(
+            serde_json::to_value(SpeechConfig::voice(VoiceName::Kore)).unwrap(),
+            json!({"voiceConfig":{"prebuiltVoiceConfig":{"voiceName":"Kore"}}})
+        );
+        assert_eq!(
+            serde_json::to_value(SpeechConfig::multi_speaker([
+                ("Joe", VoiceName::Puck),
+                ("Jane", "Newvoice".parse().unwrap()),
+            ]))
+            .unwrap(),
+            json!({"multiSpeakerVoiceConfig":{"speakerVoiceConfigs":[
+                {"speaker":"Joe","voiceConfig":{"prebuiltVoiceConfig":{"voiceName":"Puck"}}},
+                {"speaker":"Jane","voiceConfig":{"prebuiltVoiceConfig":{"voiceName":"Newvoice"}}}
+            ]}})
+        );
+        assert_eq!(
+            serde_json::from_value::<VoiceName>(json!("Sulafat")).unwrap(),
+            VoiceName::Sulafat
+        );
+    }
+
+    #[test]
+    fn audio_parts_are_joined_and_packaged_as_wav() {
+        let response: GenerateContentResponse = serde_json::from_value(json!({
+            "candidates":[{"content":{"role":"model","parts":[
+                {"inlineData":{"mimeType":"audio/L16;codec=pcm;rate=24000","data":base64_encode(&[1, 0, 2, 0])}},
+                {"inlineData":{"mimeType":"audio/L16;codec=pcm;rate=24000","data":base64_encode(&[3, 0])}}
+            ]},"index":0}],
+            "usageMetadata":{"promptTokenCount":1,"totalTokenCount":1}
+        }))
+        .unwrap();
+        let audio = response.audio().unwrap().unwrap();
+        assert_eq!(audio.pcm, [1, 0, 2, 0, 3, 0]);
+        assert_eq!(audio.sample_rate(), 24_000);
+
+        let wav = audio.to_wav().unwrap();
+        assert_eq!(wav.len(), 44 + 6);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(wav[4..8].try_into().unwrap()), 36 + 6);
+        assert_eq!(&wav[8..16], b"WAVEfmt ");
+        assert_eq!(u16::from_le_bytes(wav[22..24].try_into().unwrap()), 1);
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 24_000);
+        assert_eq!(u32::from_le_bytes(wav[28..32].try_into().unwrap()), 48_000);
+        assert_eq!(u16::from_le_bytes(wav[32..34].try_into().unwrap()), 2);
+        assert_eq!(u16::from_le_bytes(wav[34..36].try_into().unwrap()), 16);
+        assert_eq!(&wav[36..40], b"data");
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 6);
+        assert_eq!(&wav[44..], [1, 0, 2, 0, 3, 0]);
+    }
+
+    #[test]
+    fn oversized_wav_layouts_are_rejected() {
+        for spec in [
+            WavSpec {
+                sample_rate: 24_000,
+                channels: u16::MAX,
+                bits_per_sample: 16,
+            },
+            WavSpec {
+                sample_rate: u32::MAX,
+                channels: 2,
+                bits_per_sample: 16,
+            },
+        ] {
+            let error = write_wav(Vec::new(), spec, &[0; 4]).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        }
+    }
+
+    #[test]
+    fn text_only_response_has_no_audio() {
+        let response: GenerateContentResponse = serde_json::from_value(json!({
+            "candidates":[{"content":{"parts":[{"text":"hi"}]},"index":0}],
+            "usageMetadata":{"promptTokenCount":1,"totalTokenCount":1}
+        }))
+        .unwrap();
+        assert_eq!(response.audio(), Ok(None));
+    }
+}
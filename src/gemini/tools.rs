@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+use super::open_enum::open_enum;
+
+/// A tool the model may use while generating a response.
+///
+/// Each entry normally sets one field. User-defined functions and the hosted
+/// built-in tools can be combined by sending several entries.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tool {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_declarations: Option<Vec<FunctionDeclaration>>,
+    /// Ground responses with Google Search results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_search: Option<GoogleSearch>,
+    /// Let the model write and run Python code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_execution: Option<CodeExecution>,
+    /// Let the model retrieve URLs referenced in the prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_context: Option<UrlContext>,
+}
+
+impl Tool {
+    /// Create a tool exposing the supplied function declarations.
+    pub fn functions(function_declarations: Vec<FunctionDeclaration>) -> Self {
+        Self {
+            function_declarations: Some(function_declarations),
+            ..Default::default()
+        }
+    }
+
+    /// Create the hosted Google Search grounding tool.
+    pub fn google_search() -> Self {
+        Self {
+            google_search: Some(GoogleSearch::default()),
+            ..Default::default()
+        }
+    }
+
+    /// Create the hosted code execution tool.
+    pub fn code_execution() -> Self {
+        Self {
+            code_execution: Some(CodeExecution::default()),
+            ..Default::default()
+        }
+    }
+
+    /// Create the hosted URL context tool.
+    pub fn url_context() -> Self {
+        Self {
+            url_context: Some(UrlContext::default()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoogleSearch {
+    /// Restrict results to a publication time range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_range_filter: Option<Interval>,
+}
+
+/// A time range with RFC 3339 timestamps.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Interval {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeExecution {}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UrlContext {}
+
+/// Code generated by the model for the code execution tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutableCode {
+    pub language: CodeLanguage,
+    pub code: String,
+}
+
+open_enum! {
+    pub enum CodeLanguage {
+        Unspecified => "LANGUAGE_UNSPECIFIED",
+        Python => "PYTHON",
+        @unknown
+        /// A language added after this crate was released.
+        Other,
+    }
+}
+
+/// The result of running `ExecutableCode`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeExecutionResult {
+    pub outcome: CodeExecutionOutcome,
+    /// Standard output on success, or standard error and other details on
+    /// failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+}
+
+open_enum! {
+    pub enum CodeExecutionOutcome {
+        Unspecified => "OUTCOME_UNSPECIFIED",
+        Ok => "OUTCOME_OK",
+        Failed => "OUTCOME_FAILED",
+        DeadlineExceeded => "OUTCOME_DEADLINE_EXCEEDED",
+        @unknown
+        /// An outcome added after this crate was released.
+        Other,
+    }
+}
+
+/// A user-defined function the model may call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionDeclaration {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// OpenAPI-subset schema describing the function arguments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+    /// JSON Schema describing the function arguments. Mutually exclusive with
+    /// `parameters`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters_json_schema: Option<serde_json::Value>,
+}
+
+/// A function call predicted by the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionCall {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// The result of a function call, sent back to the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    pub response: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_calling_config: Option<FunctionCallingConfig>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionCallingConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<FunctionCallingMode>,
+    /// Restricts the functions the model may call when `mode` is `Any`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_function_names: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FunctionCallingMode {
+    #[serde(rename = "MODE_UNSPECIFIED")]
+    Unspecified,
+    Auto,
+    Any,
+    None,
+    Validated,
+}
@@ -0,0 +1,427 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{AiResponse, AiResult},
+    utils::base64_encode,
+};
+
+use super::{client::decode_gemini_error, GeminiClient, GeminiModelId, Operation};
+
+/// Veo video generation models.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum VeoModel {
+    #[default]
+    Veo3_1Preview,
+    Veo3_1FastPreview,
+    Veo3,
+    Veo3Fast,
+    Veo2,
+}
+
+impl Display for VeoModel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            VeoModel::Veo3_1Preview => "veo-3.1-generate-preview",
+            VeoModel::Veo3_1FastPreview => "veo-3.1-fast-generate-preview",
+            VeoModel::Veo3 => "veo-3.0-generate-001",
+            VeoModel::Veo3Fast => "veo-3.0-fast-generate-001",
+            VeoModel::Veo2 => "veo-2.0-generate-001",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl From<VeoModel> for GeminiModelId {
+    fn from(model: VeoModel) -> Self {
+        Self::new(model.to_string()).expect("checked-in Veo model IDs are valid")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VideoAspectRatio {
+    #[serde(rename = "16:9")]
+    Landscape16x9,
+    #[serde(rename = "9:16")]
+    Portrait9x16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VideoResolution {
+    #[serde(rename = "720p")]
+    P720,
+    #[serde(rename = "1080p")]
+    P1080,
+}
+
+/// Whether generated videos may depict people.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonGeneration {
+    AllowAll,
+    AllowAdult,
+    DontAllow,
+}
+
+/// An inline image used as the first frame or as a reference.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoImage {
+    inline_data: VideoImageData,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VideoImageData {
+    mime_type: String,
+    data: String,
+}
+
+impl VideoImage {
+    pub fn new(mime_type: impl Into<String>, data: &[u8]) -> Self {
+        Self {
+            inline_data: VideoImageData {
+                mime_type: mime_type.into(),
+                data: base64_encode(data),
+            },
+        }
+    }
+}
+
+/// How the model uses a reference image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoReferenceType {
+    /// Keep a subject, character, or product consistent.
+    Asset,
+    /// Match the visual style.
+    Style,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoReferenceImage {
+    pub image: VideoImage,
+    pub reference_type: VideoReferenceType,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VideoInstance {
+    prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<VideoImage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    reference_images: Vec<VideoReferenceImage>,
+}
+
+/// Generation settings for a Veo request.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<VideoAspectRatio>,
+    /// Clip length. Supported values depend on the model, typically 4 to 8.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u32>,
+    /// Content the video should not contain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub negative_prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<VideoResolution>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub person_generation: Option<PersonGeneration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
+}
+
+/// Request body for `models.predictLongRunning` on Veo models.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GenerateVideosRequest {
+    instances: [VideoInstance; 1],
+    parameters: VideoParameters,
+}
+
+impl GenerateVideosRequest {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            instances: [VideoInstance {
+                prompt: prompt.into(),
+                ..Default::default()
+            }],
+            parameters: VideoParameters::default(),
+        }
+    }
+
+    /// Animate from this image as the first frame.
+    pub fn image(mut self, image: VideoImage) -> Self {
+        self.instances[0].image = Some(image);
+        self
+    }
+
+    /// Add a subject or style reference. Veo 3.1 accepts up to three.
+    pub fn reference_image(
+        mut self,
+        image: VideoImage,
+        reference_type: VideoReferenceType,
+    ) -> Self {
+        self.instances[0]
+            .reference_images
+            .push(VideoReferenceImage {
+                image,
+                reference_type,
+            });
+        self
+    }
+
+    pub fn parameters(mut self, parameters: VideoParameters) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    pub fn aspect_ratio(mut self, aspect_ratio: VideoAspectRatio) -> Self {
+        self.parameters.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    pub fn duration_seconds(mut self, duration_seconds: u32) -> Self {
+        self.parameters.duration_seconds = Some(duration_seconds);
+        self
+    }
+
+    pub fn negative_prompt(mut self, negative_prompt: impl Into<String>) -> Self {
+        self.parameters.negative_prompt = Some(negative_prompt.into());
+        self
+    }
+
+    pub fn resolution(mut self, resolution: VideoResolution) -> Self {
+        self.parameters.resolution = Some(resolution);
+        self
+    }
+
+    pub fn person_generation(mut self, person_generation: PersonGeneration) -> Self {
+        self.parameters.person_generation = Some(person_generation);
+        self
+    }
+
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.parameters.seed = Some(seed);
+        self
+    }
+}
+
+/// Final response of a Veo operation.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateVideosResponse {
+    #[serde(default)]
+    pub generate_video_response: GenerateVideoResponse,
+}
+
+impl GenerateVideosResponse {
+    pub fn videos(&self) -> impl Iterator<Item = &GeneratedVideo> {
+        self.generate_video_response
+            .generated_samples
+            .iter()
+            .filter_map(|sample| sample.video.as_ref())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateVideoResponse {
+    #[serde(default)]
+    pub generated_samples: Vec<GeneratedVideoSample>,
+    /// Number of videos removed by safety filters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rai_media_filtered_count: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rai_media_filtered_reasons: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedVideoSample {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<GeneratedVideo>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedVideo {
+    /// Download URI; fetch it with [`VideosResource::download`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+/// Veo video generation. Results arrive through a long-running operation;
+/// poll it with `GeminiClient::operations()`.
+#[derive(Clone, Copy)]
+pub struct VideosResource<'a> {
+    client: &'a GeminiClient,
+}
+
+impl<'a> VideosResource<'a> {
+    pub(crate) fn new(client: &'a GeminiClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn generate(
+        &self,
+        model: impl Into<GeminiModelId>,
+        request: &GenerateVideosRequest,
+    ) -> AiResult<AiResponse<Operation<GenerateVideosResponse>>> {
+        let [collection, action] = model.into().action_segments("predictLongRunning");
+        self.client
+            .transport()
+            .post_json_segments(
+                "videos.generate",
+                &[&collection, &action],
+                request,
+                decode_gemini_error,
+            )
+            .await
+    }
+
+    /// Download a generated video with the client's credentials. The URI must
+    /// be on the same origin as the client's base URL; a redirect to storage
+    /// on another host is followed without credentials.
+    pub async fn download(&self, uri: &str) -> AiResult<AiResponse<Vec<u8>>> {
+        self.client
+            .transport()
+            .get_bytes_url("videos.download", uri, decode_gemini_error)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        core::test_support::{
+            cross_origin_redirect_server, gemini_client, json_response, one_shot_server,
+            read_request,
+        },
+        error::{AiError, ConfigErrorKind},
+        gemini::OperationName,
+    };
+
+    #[tokio::test]
+    async fn generate_posts_typed_veo_parameters() {
+        let (base_url, captured) = one_shot_server(
+            "v1beta",
+            json_response(
+                "200 OK",
+                &[],
+                r#"{"name":"models/veo-3.1-generate-preview/operations/op1"}"#,
+            ),
+        )
+        .await;
+        let request = GenerateVideosRequest::new("A cat surfing")
+            .reference_image(
+                VideoImage::new("image/png", &[1, 2]),
+                VideoReferenceType::Asset,
+            )
+            .aspect_ratio(VideoAspectRatio::Portrait9x16)
+            .duration_seconds(8)
+            .negative_prompt("cartoon");
+        let operation = gemini_client(base_url)
+            .videos()
+            .generate(VeoModel::Veo3_1Preview, &request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(
+            operation.name,
+            OperationName::new("models/veo-3.1-generate-preview/operations/op1").unwrap()
+        );
+        assert!(!operation.is_done());
+
+        let captured = captured.await.unwrap();
+        assert!(captured.starts_with(
+            "POST /v1beta/models/veo-3.1-generate-preview:predictLongRunning HTTP/1.1\r\n"
+        ));
+        let (_, body) = captured.split_once("\r\n\r\n").unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(body).unwrap(),
+            json!({
+                "instances":[{
+                    "prompt":"A cat surfing",
+                    "referenceImages":[{
+                        "image":{"inlineData":{"mimeType":"image/png","data":"AQI="}},
+                        "referenceType":"asset"
+                    }]
+                }],
+                "parameters":{"aspectRatio":"9:16","durationSeconds":8,"negativePrompt":"cartoon"}
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn download_sends_credentials_only_to_the_client_origin() {
+        let video = "HTTP/1.1 200 OK\r\nContent-Type: video/mp4\r\nContent-Length: 4\r\nConnection: close\r\n\r\nmp4!";
+        let (base_url, captured) = one_shot_server("v1beta", video.to_owned()).await;
+        let operation: Operation<GenerateVideosResponse> = serde_json::from_value(json!({
+            "name":"operations/op1",
+            "done":true,
+            "response":{"generateVideoResponse":{"generatedSamples":[
+                {"video":{"uri":format!("{base_url}/files/abc:download?alt=media")}}
+            ]}}
+        }))
+        .unwrap();
+        let response = operation.into_result().unwrap().unwrap();
+        let uri = response.videos().next().unwrap().uri.as_deref().unwrap();
+        let client = gemini_client(base_url);
+        let bytes = client.videos().download(uri).await.unwrap();
+        assert_eq!(bytes.data(), b"mp4!");
+        let captured = captured.await.unwrap();
+        assert!(captured.starts_with("GET /v1beta/files/abc:download?alt=media HTTP/1.1\r\n"));
+        assert!(captured.contains("x-goog-api-key: test-key\r\n"));
+
+        let error = client
+            .videos()
+            .download("https://example.com/video.mp4")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            AiError::Config {
+                kind: ConfigErrorKind::InvalidBaseUrl,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn download_follows_storage_redirects_without_credentials() {
+        use tokio::io::AsyncWriteExt;
+
+        let server = cross_origin_redirect_server("v1beta").await;
+        let storage = tokio::spawn(async move {
+            let (mut socket, _) = server.redirect_target.accept().await.unwrap();
+            let request = read_request(&mut socket).await;
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: video/mp4\r\nContent-Length: 4\r\nConnection: close\r\n\r\nmp4!")
+                .await
+                .unwrap();
+            request
+        });
+        let uri = format!("{}/files/abc:download?alt=media", server.base_url);
+        let bytes = gemini_client(server.base_url)
+            .videos()
+            .download(&uri)
+            .await
+            .unwrap();
+        assert_eq!(bytes.data(), b"mp4!");
+
+        let origin = server.origin_request.await.unwrap();
+        assert!(origin.contains("x-goog-api-key: test-key\r\n"));
+        let storage = storage.await.unwrap();
+        assert!(storage.starts_with("GET /steal HTTP/1.1\r\n"));
+        assert!(!storage.to_ascii_lowercase().contains("x-goog-api-key"));
+    }
+}
@@ -0,0 +1,16 @@
+//! OpenAI's Audio API, starting with text-to-speech.
+//!
+//! Audio is returned encoded, exactly as the server produced it, alongside
+//! its declared media type. This crate does not decode or play audio.
+
+mod models;
+mod resource;
+mod speech;
+#[cfg(feature = "stream")]
+mod stream;
+
+pub use models::*;
+pub use resource::*;
+pub use speech::*;
+#[cfg(feature = "stream")]
+pub use stream::*;
@@ -0,0 +1,138 @@
+use std::{fmt, marker::PhantomData};
+
+use super::SpeechError;
+
+/// A model accepted by OpenAI's speech endpoint.
+///
+/// Implementing it for an unlisted voice model allows plain speech requests;
+/// `instructions` and SSE output stay off unless the model also implements
+/// [`SupportsSpeechInstructions`] or [`SupportsSpeechEvents`].
+pub trait OpenAISpeechModel: Send + Sync + 'static {
+    const ID: &'static str;
+}
+
+/// Models that accept `instructions` to steer tone, accent, and delivery.
+pub trait SupportsSpeechInstructions: OpenAISpeechModel {}
+/// Models that can stream audio as `speech.audio.delta` server-sent events.
+pub trait SupportsSpeechEvents: OpenAISpeechModel {}
+
+macro_rules! models {
+    ($($name:ident => $id:literal),+ $(,)?) => {$ (
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+        impl OpenAISpeechModel for $name { const ID: &'static str = $id; }
+        impl $name {
+            /// Start a compile-time checked speech configuration for this model.
+            pub fn config() -> SpeechModelConfig<Self> {
+                SpeechModelConfig::new()
+            }
+        }
+    )+ };
+}
+
+models! {
+    Gpt4oMiniTts => "gpt-4o-mini-tts",
+    Gpt4oMiniTts2025_12_15 => "gpt-4o-mini-tts-2025-12-15",
+    Tts1Hd => "tts-1-hd",
+    Tts1 => "tts-1",
+}
+
+pub const KNOWN_SPEECH_MODEL_IDS: &[&str] = &[
+    Gpt4oMiniTts::ID,
+    Gpt4oMiniTts2025_12_15::ID,
+    Tts1Hd::ID,
+    Tts1::ID,
+];
+
+// The pinned `CreateSpeechRequest` schema documents that neither
+// `instructions` nor `stream_format: "sse"` work with `tts-1` or `tts-1-hd`.
+
+macro_rules! impl_trait {
+    ($trait:ident: $($model:ty),+ $(,)?) => { $(impl $trait for $model {})+ };
+}
+
+impl_trait!(SupportsSpeechInstructions: Gpt4oMiniTts, Gpt4oMiniTts2025_12_15);
+impl_trait!(SupportsSpeechEvents: Gpt4oMiniTts, Gpt4oMiniTts2025_12_15);
+
+/// Most characters accepted in `input` or `instructions`.
+pub const MAX_SPEECH_CHARS: usize = 4096;
+
+/// Reusable, compile-time checked configuration for one speech model.
+///
+/// The text and voice live in the request, so one request can be spoken by
+/// different model configs.
+pub struct SpeechModelConfig<M: OpenAISpeechModel> {
+    pub(crate) instructions: Option<String>,
+    model: PhantomData<fn() -> M>,
+}
+
+impl<M: OpenAISpeechModel> Clone for SpeechModelConfig<M> {
+    fn clone(&self) -> Self {
+        Self {
+            instructions: self.instructions.clone(),
+            model: PhantomData,
+        }
+    }
+}
+
+impl<M: OpenAISpeechModel> fmt::Debug for SpeechModelConfig<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpeechModelConfig")
+            .field("model", &M::ID)
+            .field("instructions", &self.instructions)
+            .finish()
+    }
+}
+
+impl<M: OpenAISpeechModel> SpeechModelConfig<M> {
+    /// Start configuring a custom speech model marker.
+    pub fn new() -> Self {
+        Self {
+            instructions: None,
+            model: PhantomData,
+        }
+    }
+
+    pub fn model_id(&self) -> &'static str {
+        M::ID
+    }
+}
+
+impl<M: OpenAISpeechModel> Default for SpeechModelConfig<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: SupportsSpeechInstructions> SpeechModelConfig<M> {
+    /// Describe how the voice should sound, for example "Speak like a calm
+    /// radio host."
+    pub fn instructions(mut self, instructions: impl Into<String>) -> Result<Self, SpeechError> {
+        let instructions = instructions.into();
+        let chars = instructions.chars().count();
+        if chars > MAX_SPEECH_CHARS {
+            return Err(SpeechError::InstructionsTooLong { chars });
+        }
+        self.instructions = Some(instructions);
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instructions<M: SupportsSpeechInstructions>() {}
+    fn events<M: SupportsSpeechEvents>() {}
+
+    #[test]
+    fn representative_positive_capability_bounds_compile() {
+        instructions::<Gpt4oMiniTts>();
+        events::<Gpt4oMiniTts2025_12_15>();
+        assert_eq!(Tts1Hd::config().model_id(), "tts-1-hd");
+        assert!(matches!(
+            Gpt4oMiniTts::config().instructions("x".repeat(4097)),
+            Err(SpeechError::InstructionsTooLong { chars: 4097 })
+        ));
+    }
+}
@@ -0,0 +1,299 @@
+#[cfg(feature = "stream")]
+use crate::{core::sse, error::AiProvider};
+use crate::{
+    error::{AiResponse, AiResult},
+    openai::{client::decode_openai_error, OpenAIClient},
+};
+
+use super::{
+    CreateSpeechRequest, OpenAISpeechModel, SpeechAudio, SpeechModelConfig, SpeechWireRequest,
+};
+#[cfg(feature = "stream")]
+use super::{SpeechAudioStream, SpeechEventStream, SupportsSpeechEvents};
+
+/// OpenAI's Audio API.
+#[derive(Clone, Copy)]
+pub struct AudioResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> AudioResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    /// Text-to-speech generation.
+    pub fn speech(&self) -> SpeechResource<'a> {
+        SpeechResource {
+            client: self.client,
+        }
+    }
+}
+
+/// OpenAI's `POST /audio/speech`.
+#[derive(Clone, Copy)]
+pub struct SpeechResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl SpeechResource<'_> {
+    /// Generate speech and collect the whole encoded file.
+    pub async fn create<M>(
+        &self,
+        config: SpeechModelConfig<M>,
+        request: CreateSpeechRequest,
+    ) -> AiResult<AiResponse<SpeechAudio>>
+    where
+        M: OpenAISpeechModel,
+    {
+        let format = request.format();
+        let wire = SpeechWireRequest::new(config, request);
+        let response = self
+            .client
+            .transport()
+            .post_json_bytes("audio.speech", "audio/speech", &wire, decode_openai_error)
+            .await?;
+        let (data, metadata) = response.into_parts();
+        let audio = SpeechAudio {
+            format,
+            content_type: metadata.content_type.clone(),
+            data,
+        };
+        Ok(AiResponse::new(audio, metadata))
+    }
+
+    /// Generate speech and stream the encoded file as it is produced, so it
+    /// can be played or written without buffering it whole.
+    #[cfg(feature = "stream")]
+    pub async fn stream<M>(
+        &self,
+        config: SpeechModelConfig<M>,
+        request: CreateSpeechRequest,
+    ) -> AiResult<AiResponse<SpeechAudioStream>>
+    where
+        M: OpenAISpeechModel,
+    {
+        let format = request.format();
+        let wire = SpeechWireRequest::new(config, request);
+        let response = self
+            .client
+            .transport()
+            .post_json_stream(
+                "audio.speech_stream",
+                "audio/speech",
+                &wire,
+                decode_openai_error,
+            )
+            .await?;
+        let (bytes, metadata) = response.into_parts();
+        Ok(AiResponse::new(
+            SpeechAudioStream::new(format, metadata.content_type.clone(), bytes),
+            metadata,
+        ))
+    }
+
+    /// Generate speech as `speech.audio.delta` server-sent events followed by
+    /// a `speech.audio.done` event with usage.
+    #[cfg(feature = "stream")]
+    pub async fn stream_events<M>(
+        &self,
+        config: SpeechModelConfig<M>,
+        request: CreateSpeechRequest,
+    ) -> AiResult<AiResponse<SpeechEventStream>>
+    where
+        M: SupportsSpeechEvents,
+    {
+        let format = request.format();
+        let mut wire = SpeechWireRequest::new(config, request);
+        wire.stream_format = Some("sse");
+        let response = self
+            .client
+            .transport()
+            .post_json_stream(
+                "audio.speech_events",
+                "audio/speech",
+                &wire,
+                decode_openai_error,
+            )
+            .await?;
+        let (bytes, metadata) = response.into_parts();
+        Ok(AiResponse::new(
+            SpeechEventStream::new(
+                format,
+                sse::json_events(bytes, AiProvider::OpenAI, "audio.speech_events"),
+            ),
+            metadata,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_support::{json_response, one_shot_server, openai_client, request_json},
+        error::AiError,
+        openai::audio::{
+            Gpt4oMiniTts, SpeechError, SpeechFormat, SpeechSpeed, SpeechVoice, Tts1Hd,
+        },
+    };
+
+    fn audio_response(body: &[u8]) -> String {
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: audio/x-flac\r\nContent-Length: {}\r\nx-request-id: req_1\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            String::from_utf8_lossy(body)
+        )
+    }
+
+    #[tokio::test]
+    async fn create_sends_voice_settings_and_collects_audio_bytes() {
+        let (base_url, wire) = one_shot_server("v1", audio_response(b"fLaC\0\0")).await;
+        let config = Gpt4oMiniTts::config()
+            .instructions("Speak like a calm radio host.")
+            .unwrap();
+        let request = CreateSpeechRequest::new("Good evening.", SpeechVoice::Marin)
+            .unwrap()
+            .response_format(SpeechFormat::Flac)
+            .speed(SpeechSpeed::new(1.25).unwrap());
+        let response = openai_client(base_url)
+            .audio()
+            .speech()
+            .create(config, request)
+            .await
+            .unwrap();
+
+        let wire = wire.await.unwrap();
+        assert!(wire.starts_with("POST /v1/audio/speech HTTP/1.1\r\n"));
+        assert_eq!(
+            request_json(&wire),
+            serde_json::json!({
+                "model": "gpt-4o-mini-tts",
+                "input": "Good evening.",
+                "voice": "marin",
+                "instructions": "Speak like a calm radio host.",
+                "response_format": "flac",
+                "speed": 1.25
+            })
+        );
+        assert_eq!(response.metadata().request_id.as_deref(), Some("req_1"));
+        let audio = response.into_inner();
+        assert_eq!(audio.format, SpeechFormat::Flac);
+        assert_eq!(audio.media_type(), "audio/x-flac");
+        assert_eq!(audio.data, b"fLaC\0\0");
+    }
+
+    #[tokio::test]
+    async fn custom_voices_and_api_errors_use_the_common_shapes() {
+        let body = r#"{"error":{"message":"Voice not found","type":"invalid_request_error","code":"voice_not_found"}}"#;
+        let (base_url, wire) =
+            one_shot_server("v1", json_response("404 Not Found", &[], body)).await;
+        let request =
+            CreateSpeechRequest::new("Hi", SpeechVoice::Custom("voice_1234".into())).unwrap();
+        let error = openai_client(base_url)
+            .audio()
+            .speech()
+            .create(Tts1Hd::config(), request)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({
+                "model": "tts-1-hd",
+                "input": "Hi",
+                "voice": {"id": "voice_1234"},
+                "response_format": "mp3"
+            })
+        );
+        assert!(matches!(
+            &error,
+            AiError::Api { operation: "audio.speech", error, .. } if error.message() == "Voice not found"
+        ));
+        assert_eq!(
+            CreateSpeechRequest::new("", SpeechVoice::Alloy),
+            Err(SpeechError::EmptyInput)
+        );
+        assert_eq!(
+            CreateSpeechRequest::new("x".repeat(4097), SpeechVoice::Alloy),
+            Err(SpeechError::InputTooLong { chars: 4097 })
+        );
+        assert_eq!(SpeechSpeed::new(4.5), Err(SpeechError::InvalidSpeed(4.5)));
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_writes_audio_chunks_to_a_sink() {
+        use crate::core::test_support::chunked_server;
+
+        let (base_url, wire) = chunked_server(
+            "v1",
+            &[("Content-Type", "audio/L16; rate=24000")],
+            vec![vec![1, 2], vec![3], vec![4, 5, 6]],
+        )
+        .await;
+        let request = CreateSpeechRequest::new("Hello", SpeechVoice::Coral)
+            .unwrap()
+            .response_format(SpeechFormat::Pcm);
+        let audio = openai_client(base_url)
+            .audio()
+            .speech()
+            .stream(Gpt4oMiniTts::config(), request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(audio.format(), SpeechFormat::Pcm);
+        assert_eq!(audio.media_type(), "audio/L16");
+
+        let mut sink = Vec::new();
+        assert_eq!(audio.write_to(&mut sink).await.unwrap(), 6);
+        assert_eq!(sink, [1, 2, 3, 4, 5, 6]);
+        assert!(request_json(&wire.await.unwrap())
+            .get("stream_format")
+            .is_none());
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_events_decodes_audio_deltas_and_usage() {
+        use crate::core::test_support::chunked_server;
+
+        let events = concat!(
+            r#"data: {"type":"speech.audio.delta","audio":"AQI="}"#,
+            "\n\n",
+            r#"data: {"type":"speech.audio.heartbeat"}"#,
+            "\n\n",
+            r#"data: {"type":"speech.audio.delta","audio":"Aw=="}"#,
+            "\n\n",
+            r#"data: {"type":"speech.audio.done","usage":{"input_tokens":14,"output_tokens":101,"total_tokens":115}}"#,
+            "\n\n",
+        );
+        let (base_url, wire) = chunked_server("v1", &[], vec![events.as_bytes().to_vec()]).await;
+        let request = CreateSpeechRequest::new("Hello", SpeechVoice::Ash)
+            .unwrap()
+            .response_format(SpeechFormat::Wav);
+        let events = openai_client(base_url)
+            .audio()
+            .speech()
+            .stream_events(Gpt4oMiniTts::config(), request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(events.media_type(), "audio/wav");
+
+        let mut sink = Vec::new();
+        let usage = events.write_to(&mut sink).await.unwrap().unwrap();
+        assert_eq!(sink, [1, 2, 3]);
+        assert_eq!(usage.total_tokens, 115);
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({
+                "model": "gpt-4o-mini-tts",
+                "input": "Hello",
+                "voice": "ash",
+                "response_format": "wav",
+                "stream_format": "sse"
+            })
+        );
+    }
+}
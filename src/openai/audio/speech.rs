@@ -0,0 +1,319 @@
+use std::{fmt, io, path::Path};
+
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
+use tokio::io::AsyncWrite;
+
+use crate::{
+    openai::responses::tagged::lossless_tagged_enum,
+    utils::{save_with_extension, write_and_flush},
+};
+
+use super::{OpenAISpeechModel, SpeechModelConfig, MAX_SPEECH_CHARS};
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SpeechError {
+    #[error("speech input must not be empty")]
+    EmptyInput,
+    #[error("speech input is {chars} characters; the limit is 4096")]
+    InputTooLong { chars: usize },
+    #[error("speech instructions are {chars} characters; the limit is 4096")]
+    InstructionsTooLong { chars: usize },
+    #[error("speech speed must be within 0.25..=4.0, not {0}")]
+    InvalidSpeed(f32),
+}
+
+/// A built-in or custom voice for generated speech.
+///
+/// `Other` carries built-in voice names added after this crate was released.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpeechVoice {
+    Alloy,
+    Ash,
+    Ballad,
+    Coral,
+    Echo,
+    Fable,
+    Onyx,
+    Nova,
+    Sage,
+    Shimmer,
+    Verse,
+    Marin,
+    Cedar,
+    /// A custom voice ID such as `voice_1234`.
+    Custom(String),
+    Other(String),
+}
+
+impl SpeechVoice {
+    /// The built-in voice name, or the custom voice ID.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Alloy => "alloy",
+            Self::Ash => "ash",
+            Self::Ballad => "ballad",
+            Self::Coral => "coral",
+            Self::Echo => "echo",
+            Self::Fable => "fable",
+            Self::Onyx => "onyx",
+            Self::Nova => "nova",
+            Self::Sage => "sage",
+            Self::Shimmer => "shimmer",
+            Self::Verse => "verse",
+            Self::Marin => "marin",
+            Self::Cedar => "cedar",
+            Self::Custom(id) | Self::Other(id) => id,
+        }
+    }
+}
+
+impl fmt::Display for SpeechVoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for SpeechVoice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct CustomVoice<'a> {
+            id: &'a str,
+        }
+
+        match self {
+            Self::Custom(id) => CustomVoice { id }.serialize(serializer),
+            voice => serializer.serialize_str(voice.as_str()),
+        }
+    }
+}
+
+/// Encoding of the generated audio.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechFormat {
+    #[default]
+    Mp3,
+    Opus,
+    Aac,
+    Flac,
+    Wav,
+    /// Raw 24 kHz, 16-bit signed little-endian mono samples with no header.
+    Pcm,
+}
+
+impl SpeechFormat {
+    /// The media type of audio in this format.
+    pub fn media_type(self) -> &'static str {
+        match self {
+            Self::Mp3 => "audio/mpeg",
+            Self::Opus => "audio/opus",
+            Self::Aac => "audio/aac",
+            Self::Flac => "audio/flac",
+            Self::Wav => "audio/wav",
+            Self::Pcm => "audio/pcm",
+        }
+    }
+
+    /// The media type in `content_type`, without parameters, or this format's
+    /// media type when the server did not declare one.
+    pub(super) fn declared_media_type(self, content_type: Option<&str>) -> &str {
+        content_type
+            .and_then(|value| value.split(';').next())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(self.media_type())
+    }
+
+    /// File extension for this format, without the leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Mp3 => "mp3",
+            Self::Opus => "opus",
+            Self::Aac => "aac",
+            Self::Flac => "flac",
+            Self::Wav => "wav",
+            Self::Pcm => "pcm",
+        }
+    }
+}
+
+/// Playback speed within `0.25..=4.0`; the API default is `1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
+pub struct SpeechSpeed(f32);
+
+impl SpeechSpeed {
+    pub fn new(speed: f32) -> Result<Self, SpeechError> {
+        if (0.25..=4.0).contains(&speed) {
+            Ok(Self(speed))
+        } else {
+            Err(SpeechError::InvalidSpeed(speed))
+        }
+    }
+
+    pub fn get(self) -> f32 {
+        self.0
+    }
+}
+
+impl TryFrom<f32> for SpeechSpeed {
+    type Error = SpeechError;
+    fn try_from(speed: f32) -> Result<Self, Self::Error> {
+        Self::new(speed)
+    }
+}
+
+/// Text to speak with one voice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateSpeechRequest {
+    input: String,
+    voice: SpeechVoice,
+    response_format: SpeechFormat,
+    speed: Option<SpeechSpeed>,
+}
+
+impl CreateSpeechRequest {
+    /// Speak `input`, which must be 1 to 4096 characters, as mp3.
+    pub fn new(input: impl Into<String>, voice: SpeechVoice) -> Result<Self, SpeechError> {
+        let input = input.into();
+        let chars = input.chars().count();
+        if chars == 0 {
+            return Err(SpeechError::EmptyInput);
+        }
+        if chars > MAX_SPEECH_CHARS {
+            return Err(SpeechError::InputTooLong { chars });
+        }
+        Ok(Self {
+            input,
+            voice,
+            response_format: SpeechFormat::Mp3,
+            speed: None,
+        })
+    }
+
+    pub fn response_format(mut self, format: SpeechFormat) -> Self {
+        self.response_format = format;
+        self
+    }
+
+    pub fn speed(mut self, speed: SpeechSpeed) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    pub fn format(&self) -> SpeechFormat {
+        self.response_format
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct SpeechWireRequest {
+    model: &'static str,
+    input: String,
+    voice: SpeechVoice,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions: Option<String>,
+    response_format: SpeechFormat,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<SpeechSpeed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stream_format: Option<&'static str>,
+}
+
+impl SpeechWireRequest {
+    pub(crate) fn new<M: OpenAISpeechModel>(
+        config: SpeechModelConfig<M>,
+        request: CreateSpeechRequest,
+    ) -> Self {
+        Self {
+            model: M::ID,
+            input: request.input,
+            voice: request.voice,
+            instructions: config.instructions,
+            response_format: request.response_format,
+            speed: request.speed,
+            stream_format: None,
+        }
+    }
+}
+
+/// Encoded audio returned by the speech endpoint.
+///
+/// No decoding happens in this crate; `data` holds the file exactly as
+/// produced in [`Self::format`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeechAudio {
+    pub format: SpeechFormat,
+    /// `Content-Type` the server sent with the audio, if any.
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+impl SpeechAudio {
+    /// The media type the server declared, falling back to the one for
+    /// [`Self::format`].
+    pub fn media_type(&self) -> &str {
+        self.format
+            .declared_media_type(self.content_type.as_deref())
+    }
+
+    /// Save the audio as `path` with the extension of [`Self::format`] and
+    /// return the path written.
+    pub async fn save(&self, path: impl AsRef<Path>) -> io::Result<std::path::PathBuf> {
+        save_with_extension(path.as_ref(), self.format.extension(), &self.data).await
+    }
+
+    /// Write [`Self::data`] to `writer`, then flush.
+    pub async fn write_to<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        write_and_flush(writer, &self.data).await
+    }
+}
+
+lossless_tagged_enum! {
+    /// A server-sent event from speech requested with `stream_format: "sse"`.
+    #[derive(Debug, Clone)]
+    pub enum SpeechStreamEvent {
+        AudioDelta(SpeechAudioDeltaEvent) => "speech.audio.delta",
+        AudioDone(SpeechAudioDoneEvent) => "speech.audio.done",
+        @unknown
+    }
+}
+
+/// A chunk of encoded audio in the requested format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeechAudioDeltaEvent {
+    /// Base64-encoded audio bytes.
+    pub audio: String,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SpeechAudioDeltaEvent {
+    pub fn decode_audio(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        use base64::Engine as _;
+        base64::engine::general_purpose::STANDARD.decode(&self.audio)
+    }
+}
+
+/// Sent once every audio chunk has been streamed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeechAudioDoneEvent {
+    pub usage: SpeechUsage,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpeechUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub total_tokens: u64,
+}
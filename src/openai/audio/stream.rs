@@ -0,0 +1,142 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, StreamExt as _};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::stream::{AiStream, AiStreamError, SseJsonEvent};
+
+use super::{SpeechFormat, SpeechStreamEvent, SpeechUsage};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SpeechStreamError {
+    #[error(transparent)]
+    Stream(#[from] AiStreamError),
+    #[error("streamed audio is not valid base64")]
+    InvalidBase64(#[from] base64::DecodeError),
+    #[error("audio sink failed: {0}")]
+    Sink(#[from] io::Error),
+}
+
+/// Encoded audio bytes as the server sends them, tagged with their format.
+pub struct SpeechAudioStream {
+    format: SpeechFormat,
+    content_type: Option<String>,
+    bytes: AiStream<Vec<u8>>,
+}
+
+impl SpeechAudioStream {
+    pub(crate) fn new(
+        format: SpeechFormat,
+        content_type: Option<String>,
+        bytes: AiStream<Vec<u8>>,
+    ) -> Self {
+        Self {
+            format,
+            content_type,
+            bytes,
+        }
+    }
+
+    pub fn format(&self) -> SpeechFormat {
+        self.format
+    }
+
+    /// The media type the server declared, falling back to the one for
+    /// [`Self::format`].
+    pub fn media_type(&self) -> &str {
+        self.format
+            .declared_media_type(self.content_type.as_deref())
+    }
+
+    /// Copy every chunk to `writer` as it arrives and flush it, returning the
+    /// number of bytes written.
+    pub async fn write_to<W>(mut self, writer: &mut W) -> Result<u64, SpeechStreamError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut written = 0;
+        while let Some(chunk) = self.bytes.next().await {
+            let chunk = chunk?;
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+}
+
+impl Stream for SpeechAudioStream {
+    type Item = Result<Vec<u8>, AiStreamError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().bytes.poll_next_unpin(cx)
+    }
+}
+
+/// Typed `speech.audio.*` events, tagged with the format of their audio.
+pub struct SpeechEventStream {
+    format: SpeechFormat,
+    events: AiStream<SseJsonEvent<SpeechStreamEvent>>,
+}
+
+impl SpeechEventStream {
+    pub(crate) fn new(
+        format: SpeechFormat,
+        events: AiStream<SseJsonEvent<SpeechStreamEvent>>,
+    ) -> Self {
+        Self { format, events }
+    }
+
+    pub fn format(&self) -> SpeechFormat {
+        self.format
+    }
+
+    /// The media type of the decoded deltas.
+    ///
+    /// The response itself is `text/event-stream` and the deltas carry no
+    /// type of their own, so this follows the requested [`Self::format`].
+    pub fn media_type(&self) -> &'static str {
+        self.format.media_type()
+    }
+
+    /// Decode every audio delta to `writer` as it arrives and flush it,
+    /// returning the usage from the done event.
+    ///
+    /// Unknown events are skipped. Returns `None` if the stream ended without
+    /// a done event.
+    pub async fn write_to<W>(
+        mut self,
+        writer: &mut W,
+    ) -> Result<Option<SpeechUsage>, SpeechStreamError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut usage = None;
+        while let Some(event) = self.events.next().await {
+            match event?.into_data() {
+                SpeechStreamEvent::AudioDelta(delta) => {
+                    writer.write_all(&delta.decode_audio()?).await?;
+                }
+                SpeechStreamEvent::AudioDone(done) => {
+                    usage = Some(done.usage);
+                    break;
+                }
+                SpeechStreamEvent::Unknown(_) => {}
+            }
+        }
+        writer.flush().await?;
+        Ok(usage)
+    }
+}
+
+impl Stream for SpeechEventStream {
+    type Item = Result<SseJsonEvent<SpeechStreamEvent>, AiStreamError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().events.poll_next_unpin(cx)
+    }
+}
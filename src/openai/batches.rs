@@ -0,0 +1,765 @@
+//! OpenAI's Batches API, with typed JSONL helpers for `/v1/responses` batches.
+//!
+//! [`ResponsesBatchWriter`] turns typed Responses requests into the JSONL input
+//! file, applying the same model/request compatibility checks as
+//! `client.responses().create`. [`ResponsesBatchResults`] reads the output and
+//! error files back, keyed by `custom_id`.
+
+use std::{
+    collections::{btree_map, BTreeMap, BTreeSet},
+    io::Write,
+};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    error::{AiResponse, AiResult, BodySnippet, JsonDecodeError, ProviderApiError},
+    openai::{
+        client::decode_openai_error,
+        files::{FileExpiration, FileId},
+        id::validated_id,
+        responses::{OpenAIResponsesCreateResponse, ResponseModelFor},
+        OpenAIClient,
+    },
+};
+
+const MAX_BATCH_REQUESTS: usize = 50_000;
+const MAX_BATCH_FILE_BYTES: usize = 200 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum BatchError {
+    #[error("batch ID must be non-empty and contain no whitespace or control characters")]
+    InvalidBatchId,
+    #[error("page limit must be between 1 and 100")]
+    InvalidPageLimit,
+    #[error("batch metadata may contain at most 16 entries")]
+    TooManyMetadataEntries,
+}
+
+/// Why a batch JSONL file could not be written or read.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BatchFileError {
+    #[error("failed to write batch input: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("custom_id must not be empty")]
+    EmptyCustomId,
+    #[error("custom_id `{0}` is already used in this batch")]
+    DuplicateCustomId(String),
+    #[error("a batch may contain at most 50000 requests")]
+    TooManyRequests,
+    #[error("a batch input file may be at most 200 MB")]
+    TooLarge,
+    #[error("failed to serialize batch request `{custom_id}`: {source}")]
+    Serialize {
+        custom_id: String,
+        source: serde_json::Error,
+    },
+    #[error("batch result line {line} is malformed: {source}")]
+    MalformedLine {
+        line: usize,
+        source: serde_json::Error,
+    },
+}
+
+validated_id!(
+    /// A validated batch ID such as `batch_abc123`.
+    BatchId,
+    BatchError::InvalidBatchId
+);
+
+/// The API a batch's requests are sent to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BatchEndpoint {
+    Responses,
+    ChatCompletions,
+    Embeddings,
+    Completions,
+    Moderations,
+    Other(String),
+}
+
+impl BatchEndpoint {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Responses => "/v1/responses",
+            Self::ChatCompletions => "/v1/chat/completions",
+            Self::Embeddings => "/v1/embeddings",
+            Self::Completions => "/v1/completions",
+            Self::Moderations => "/v1/moderations",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl Serialize for BatchEndpoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BatchEndpoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "/v1/responses" => Self::Responses,
+            "/v1/chat/completions" => Self::ChatCompletions,
+            "/v1/embeddings" => Self::Embeddings,
+            "/v1/completions" => Self::Completions,
+            "/v1/moderations" => Self::Moderations,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreateBatchRequest {
+    input_file_id: FileId,
+    endpoint: BatchEndpoint,
+    completion_window: &'static str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_expires_after: Option<FileExpiration>,
+}
+
+impl CreateBatchRequest {
+    /// Run an uploaded JSONL file (purpose `batch`) against `endpoint` within
+    /// the 24 hour completion window.
+    pub fn new(input_file_id: FileId, endpoint: BatchEndpoint) -> Self {
+        Self {
+            input_file_id,
+            endpoint,
+            completion_window: "24h",
+            metadata: BTreeMap::new(),
+            output_expires_after: None,
+        }
+    }
+
+    /// Run a file written by [`ResponsesBatchWriter`].
+    pub fn responses(input_file_id: FileId) -> Self {
+        Self::new(input_file_id, BatchEndpoint::Responses)
+    }
+
+    pub fn metadata(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<Self, BatchError> {
+        self.metadata.insert(key.into(), value.into());
+        if self.metadata.len() > 16 {
+            return Err(BatchError::TooManyMetadataEntries);
+        }
+        Ok(self)
+    }
+
+    /// Expiry for the output and error files the batch creates.
+    pub fn output_expires_after(mut self, expiration: FileExpiration) -> Self {
+        self.output_expires_after = Some(expiration);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListBatchesOptions {
+    limit: Option<u8>,
+    after: Option<BatchId>,
+}
+
+impl ListBatchesOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn limit(mut self, limit: u8) -> Result<Self, BatchError> {
+        if !(1..=100).contains(&limit) {
+            return Err(BatchError::InvalidPageLimit);
+        }
+        self.limit = Some(limit);
+        Ok(self)
+    }
+
+    pub fn after(mut self, after: BatchId) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    pub(crate) fn query(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+        if let Some(limit) = self.limit {
+            query.push(("limit".into(), limit.to_string()));
+        }
+        if let Some(after) = &self.after {
+            query.push(("after".into(), after.as_str().into()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BatchStatus {
+    Validating,
+    Failed,
+    InProgress,
+    Finalizing,
+    Completed,
+    Expired,
+    Cancelling,
+    Cancelled,
+    Other(String),
+}
+
+impl BatchStatus {
+    /// Whether the batch has stopped and will not change again.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Failed | Self::Completed | Self::Expired | Self::Cancelled
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for BatchStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "validating" => Self::Validating,
+            "failed" => Self::Failed,
+            "in_progress" => Self::InProgress,
+            "finalizing" => Self::Finalizing,
+            "completed" => Self::Completed,
+            "expired" => Self::Expired,
+            "cancelling" => Self::Cancelling,
+            "cancelled" => Self::Cancelled,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct BatchRequestCounts {
+    pub total: u64,
+    pub completed: u64,
+    pub failed: u64,
+}
+
+/// A problem with the batch's input file, found while validating it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchValidationError {
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub param: Option<String>,
+    #[serde(default)]
+    pub line: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchValidationErrors {
+    pub object: String,
+    pub data: Vec<BatchValidationError>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Batch {
+    pub id: BatchId,
+    pub object: String,
+    pub endpoint: BatchEndpoint,
+    pub input_file_id: FileId,
+    pub completion_window: String,
+    pub status: BatchStatus,
+    pub created_at: u64,
+    #[serde(default)]
+    pub errors: Option<BatchValidationErrors>,
+    #[serde(default)]
+    pub output_file_id: Option<FileId>,
+    #[serde(default)]
+    pub error_file_id: Option<FileId>,
+    #[serde(default)]
+    pub in_progress_at: Option<u64>,
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+    #[serde(default)]
+    pub finalizing_at: Option<u64>,
+    #[serde(default)]
+    pub completed_at: Option<u64>,
+    #[serde(default)]
+    pub failed_at: Option<u64>,
+    #[serde(default)]
+    pub expired_at: Option<u64>,
+    #[serde(default)]
+    pub cancelling_at: Option<u64>,
+    #[serde(default)]
+    pub cancelled_at: Option<u64>,
+    #[serde(default)]
+    pub request_counts: Option<BatchRequestCounts>,
+    #[serde(default)]
+    pub metadata: Option<BTreeMap<String, String>>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchList {
+    pub object: String,
+    pub data: Vec<Batch>,
+    pub has_more: bool,
+    #[serde(default)]
+    pub first_id: Option<BatchId>,
+    #[serde(default)]
+    pub last_id: Option<BatchId>,
+}
+
+impl BatchList {
+    /// Options for the page after this one, or `None` on the last page.
+    pub fn next_page(&self, options: &ListBatchesOptions) -> Option<ListBatchesOptions> {
+        let last_id = self.last_id.clone()?;
+        self.has_more.then(|| options.clone().after(last_id))
+    }
+}
+
+/// Writes typed Responses requests as a batch input JSONL file.
+///
+/// Each request is prepared exactly as `client.responses().create` would
+/// prepare it, so a model/request mismatch is a compile error here too.
+pub struct ResponsesBatchWriter<W> {
+    writer: W,
+    custom_ids: BTreeSet<String>,
+    bytes_written: usize,
+}
+
+impl<W: Write> ResponsesBatchWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            custom_ids: BTreeSet::new(),
+            bytes_written: 0,
+        }
+    }
+
+    /// Append one request. `custom_id` must be unique within the batch and is
+    /// how its result is found in [`ResponsesBatchResults`].
+    pub fn push<Model, Request>(
+        &mut self,
+        custom_id: impl Into<String>,
+        model: Model,
+        request: Request,
+    ) -> Result<&mut Self, BatchFileError>
+    where
+        Model: ResponseModelFor<Request>,
+    {
+        let custom_id = custom_id.into();
+        if custom_id.is_empty() {
+            return Err(BatchFileError::EmptyCustomId);
+        }
+        if self.custom_ids.len() == MAX_BATCH_REQUESTS {
+            return Err(BatchFileError::TooManyRequests);
+        }
+        if self.custom_ids.contains(&custom_id) {
+            return Err(BatchFileError::DuplicateCustomId(custom_id));
+        }
+
+        let mut body = model.prepare(request);
+        body.wire_mut().stream = None;
+        body.wire_mut().stream_options = None;
+        let line = BatchInputLine {
+            custom_id: &custom_id,
+            method: "POST",
+            url: BatchEndpoint::Responses.as_str(),
+            body: &body,
+        };
+        let mut bytes = serde_json::to_vec(&line).map_err(|source| BatchFileError::Serialize {
+            custom_id: custom_id.clone(),
+            source,
+        })?;
+        bytes.push(b'\n');
+        if self.bytes_written + bytes.len() > MAX_BATCH_FILE_BYTES {
+            return Err(BatchFileError::TooLarge);
+        }
+        self.writer.write_all(&bytes)?;
+        self.bytes_written += bytes.len();
+        self.custom_ids.insert(custom_id);
+        Ok(self)
+    }
+
+    pub fn len(&self) -> usize {
+        self.custom_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.custom_ids.is_empty()
+    }
+
+    /// Flush and return the underlying writer.
+    pub fn finish(mut self) -> Result<W, BatchFileError> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[derive(Serialize)]
+struct BatchInputLine<'a, B> {
+    custom_id: &'a str,
+    method: &'static str,
+    url: &'a str,
+    body: &'a B,
+}
+
+/// Why a single batched request produced no response.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum BatchRequestError {
+    /// The endpoint rejected the request, as it would have outside a batch.
+    Api {
+        status_code: u16,
+        request_id: Option<String>,
+        error: ProviderApiError,
+    },
+    /// The endpoint succeeded but its body is not a Responses object this
+    /// crate can decode.
+    Decode {
+        status_code: u16,
+        request_id: Option<String>,
+        error: JsonDecodeError,
+    },
+    /// The batch never ran the request, for example because it expired.
+    Batch {
+        code: Option<String>,
+        message: Option<String>,
+    },
+}
+
+/// One line of a batch output or error file.
+#[derive(Debug, Clone)]
+pub struct ResponsesBatchResult {
+    /// The batch request ID, `batch_req_...`.
+    pub id: Option<String>,
+    pub result: Result<OpenAIResponsesCreateResponse, BatchRequestError>,
+}
+
+/// The results of a `/v1/responses` batch, keyed by `custom_id`.
+#[derive(Debug, Clone, Default)]
+pub struct ResponsesBatchResults {
+    results: BTreeMap<String, ResponsesBatchResult>,
+}
+
+impl ResponsesBatchResults {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse an output or error file.
+    pub fn from_jsonl(jsonl: &[u8]) -> Result<Self, BatchFileError> {
+        let mut results = Self::new();
+        results.read_jsonl(jsonl)?;
+        Ok(results)
+    }
+
+    /// Parse another output or error file into these results. A later line
+    /// for the same `custom_id` replaces an earlier one.
+    ///
+    /// Only a line that is not a batch output object fails the whole file; a
+    /// response body that does not decode becomes [`BatchRequestError::Decode`]
+    /// for that `custom_id`.
+    pub fn read_jsonl(&mut self, jsonl: &[u8]) -> Result<&mut Self, BatchFileError> {
+        for (index, line) in jsonl.split(|byte| *byte == b'\n').enumerate() {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let line_number = index + 1;
+            let malformed = |source| BatchFileError::MalformedLine {
+                line: line_number,
+                source,
+            };
+            let output: BatchOutputLine = serde_json::from_slice(line).map_err(malformed)?;
+            let result = match (output.response, output.error) {
+                (Some(response), _) if (200..300).contains(&response.status_code) => {
+                    serde_json::from_value(response.body).map_err(|error| {
+                        BatchRequestError::Decode {
+                            status_code: response.status_code,
+                            request_id: response.request_id,
+                            error: error.into(),
+                        }
+                    })
+                }
+                (Some(response), _) => {
+                    let body = serde_json::to_vec(&response.body).map_err(malformed)?;
+                    let snippet = BodySnippet::from_bytes(&body, false);
+                    Err(BatchRequestError::Api {
+                        status_code: response.status_code,
+                        request_id: response.request_id,
+                        error: decode_openai_error(&body, snippet),
+                    })
+                }
+                (None, error) => {
+                    let error = error.unwrap_or_default();
+                    Err(BatchRequestError::Batch {
+                        code: error.code,
+                        message: error.message,
+                    })
+                }
+            };
+            self.results.insert(
+                output.custom_id,
+                ResponsesBatchResult {
+                    id: output.id,
+                    result,
+                },
+            );
+        }
+        Ok(self)
+    }
+
+    pub fn get(&self, custom_id: &str) -> Option<&ResponsesBatchResult> {
+        self.results.get(custom_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub fn iter(&self) -> btree_map::Iter<'_, String, ResponsesBatchResult> {
+        self.results.iter()
+    }
+}
+
+impl IntoIterator for ResponsesBatchResults {
+    type Item = (String, ResponsesBatchResult);
+    type IntoIter = btree_map::IntoIter<String, ResponsesBatchResult>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+#[derive(Deserialize)]
+struct BatchOutputLine {
+    #[serde(default)]
+    id: Option<String>,
+    custom_id: String,
+    #[serde(default)]
+    response: Option<BatchOutputResponse>,
+    #[serde(default)]
+    error: Option<BatchOutputError>,
+}
+
+#[derive(Deserialize)]
+struct BatchOutputResponse {
+    status_code: u16,
+    #[serde(default)]
+    request_id: Option<String>,
+    body: Value,
+}
+
+#[derive(Default, Deserialize)]
+struct BatchOutputError {
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+pub struct BatchesResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> BatchesResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create(&self, request: CreateBatchRequest) -> AiResult<AiResponse<Batch>> {
+        self.client
+            .transport()
+            .post_json("batches.create", "batches", &request, decode_openai_error)
+            .await
+    }
+
+    pub async fn retrieve(&self, batch_id: &BatchId) -> AiResult<AiResponse<Batch>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "batches.retrieve",
+                &["batches", batch_id.as_str()],
+                &[],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn list(&self, options: ListBatchesOptions) -> AiResult<AiResponse<BatchList>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "batches.list",
+                &["batches"],
+                &options.query(),
+                decode_openai_error,
+            )
+            .await
+    }
+
+    /// Request cancellation. The batch moves to `cancelling` and then to
+    /// `cancelled`, keeping any results already produced.
+    pub async fn cancel(&self, batch_id: &BatchId) -> AiResult<AiResponse<Batch>> {
+        self.client
+            .transport()
+            .post_empty_segments(
+                "batches.cancel",
+                &["batches", batch_id.as_str(), "cancel"],
+                decode_openai_error,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_support::{json_response, one_shot_server, openai_client, request_json},
+        openai::responses::{CreateResponseRequest, Gpt4oMini},
+    };
+
+    const RESPONSE: &str = r#"{
+        "metadata": {}, "top_logprobs": 0, "temperature": 1.0, "top_p": 1.0,
+        "model": "gpt-4o-mini", "tools": [], "tool_choice": "auto",
+        "id": "resp_1", "object": "response", "status": "completed", "created_at": 1,
+        "completed_at": 2, "error": null, "incomplete_details": null, "output": [],
+        "reasoning": {"context": "current_turn", "effort": "none", "mode": "standard", "summary": null},
+        "instructions": null, "usage": null, "moderation": null, "parallel_tool_calls": true,
+        "max_output_tokens": null, "truncation": "disabled", "previous_response_id": null
+    }"#;
+
+    #[test]
+    fn writer_emits_prepared_responses_requests_with_unique_custom_ids() {
+        let mut writer = ResponsesBatchWriter::new(Vec::new());
+        let request = CreateResponseRequest::builder().input_text("hello").build();
+        writer
+            .push("row-1", Gpt4oMini::config(), request.clone())
+            .unwrap()
+            .push("row-2", Gpt4oMini::config(), request.clone())
+            .unwrap();
+        assert!(matches!(
+            writer.push("row-1", Gpt4oMini::config(), request),
+            Err(BatchFileError::DuplicateCustomId(id)) if id == "row-1"
+        ));
+        assert_eq!(writer.len(), 2);
+
+        let jsonl = String::from_utf8(writer.finish().unwrap()).unwrap();
+        let lines = jsonl
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines[0],
+            serde_json::json!({
+                "custom_id": "row-1",
+                "method": "POST",
+                "url": "/v1/responses",
+                "body": {"model": "gpt-4o-mini", "input": "hello"}
+            })
+        );
+        assert_eq!(lines[1]["custom_id"], "row-2");
+    }
+
+    #[test]
+    fn results_correlate_responses_and_errors_by_custom_id() {
+        let output = format!(
+            "{}\n{}\n",
+            serde_json::json!({
+                "id": "batch_req_1", "custom_id": "row-1", "error": null,
+                "response": {
+                    "status_code": 200, "request_id": "req_1",
+                    "body": serde_json::from_str::<serde_json::Value>(RESPONSE).unwrap()
+                }
+            }),
+            serde_json::json!({
+                "id": "batch_req_2", "custom_id": "row-2", "error": null,
+                "response": {
+                    "status_code": 400, "request_id": "req_2",
+                    "body": {"error": {"message": "bad input", "type": "invalid_request_error", "code": "bad"}}
+                }
+            })
+        );
+        let errors = r#"{"id":"batch_req_3","custom_id":"row-3","response":null,"error":{"code":"batch_expired","message":"expired"}}"#;
+
+        let mut results = ResponsesBatchResults::from_jsonl(output.as_bytes()).unwrap();
+        results.read_jsonl(errors.as_bytes()).unwrap();
+
+        assert_eq!(results.len(), 3);
+        let response = results.get("row-1").unwrap().result.as_ref().unwrap();
+        assert_eq!(response.id.as_str(), "resp_1");
+        assert!(matches!(
+            &results.get("row-2").unwrap().result,
+            Err(BatchRequestError::Api { status_code: 400, error, .. }) if error.message() == "bad input"
+        ));
+        assert!(matches!(
+            &results.get("row-3").unwrap().result,
+            Err(BatchRequestError::Batch { code: Some(code), .. }) if code == "batch_expired"
+        ));
+
+        let undecodable = r#"{"custom_id":"row-4","response":{"status_code":200,"request_id":"req_4","body":{"id":7}}}"#;
+        results.read_jsonl(undecodable.as_bytes()).unwrap();
+        assert!(matches!(
+            &results.get("row-4").unwrap().result,
+            Err(BatchRequestError::Decode { status_code: 200, request_id: Some(id), .. }) if id == "req_4"
+        ));
+        assert!(matches!(
+            ResponsesBatchResults::from_jsonl(b"{}\nnot json"),
+            Err(BatchFileError::MalformedLine { line: 1, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_targets_the_responses_endpoint_within_24_hours() {
+        let batch = r#"{
+            "id": "batch_1", "object": "batch", "endpoint": "/v1/responses",
+            "input_file_id": "file-in", "completion_window": "24h", "status": "completed",
+            "created_at": 1, "output_file_id": "file-out", "error_file_id": null,
+            "request_counts": {"total": 2, "completed": 1, "failed": 1}
+        }"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], batch)).await;
+        let request = CreateBatchRequest::responses(FileId::new("file-in").unwrap())
+            .metadata("run", "eval-7")
+            .unwrap();
+        let batch = openai_client(base_url)
+            .batches()
+            .create(request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({
+                "input_file_id": "file-in",
+                "endpoint": "/v1/responses",
+                "completion_window": "24h",
+                "metadata": {"run": "eval-7"}
+            })
+        );
+        assert!(batch.status.is_terminal());
+        assert_eq!(batch.endpoint, BatchEndpoint::Responses);
+        assert_eq!(batch.output_file_id.unwrap().as_str(), "file-out");
+        assert_eq!(batch.request_counts.unwrap().failed, 1);
+    }
+}
@@ -18,9 +18,17 @@ use crate::{
         AiError, AiProvider, AiResponse, AiResult, BodySnippet, ConfigErrorKind, ProviderApiError,
     },
     openai::{
+        audio::AudioResource,
+        batches::BatchesResource,
         conversations::ConversationsResource,
+        embeddings::EmbeddingsResource,
+        files::FilesResource,
+        images::ImagesResource,
         list_models::{OpenAIModelInfo, OpenAIModelsListResponse},
+        moderations::ModerationsResource,
         responses::{OpenAIResponsesCreateResponse, PreparedResponseRequest, ResponsesResource},
+        uploads::UploadsResource,
+        vector_stores::VectorStoresResource,
     },
 };
 #[cfg(feature = "stream")]
@@ -199,6 +207,46 @@ impl OpenAIClient {
         ResponsesResource::new(self)
     }
 
+    /// Access OpenAI's Audio API.
+    pub fn audio(&self) -> AudioResource<'_> {
+        AudioResource::new(self)
+    }
+
+    /// Access OpenAI's standalone Images API.
+    pub fn images(&self) -> ImagesResource<'_> {
+        ImagesResource::new(self)
+    }
+
+    /// Access OpenAI's Embeddings API.
+    pub fn embeddings(&self) -> EmbeddingsResource<'_> {
+        EmbeddingsResource::new(self)
+    }
+
+    /// Access OpenAI's Files API.
+    pub fn files(&self) -> FilesResource<'_> {
+        FilesResource::new(self)
+    }
+
+    /// Access OpenAI's Uploads API.
+    pub fn uploads(&self) -> UploadsResource<'_> {
+        UploadsResource::new(self)
+    }
+
+    /// Access OpenAI's Vector Stores API.
+    pub fn vector_stores(&self) -> VectorStoresResource<'_> {
+        VectorStoresResource::new(self)
+    }
+
+    /// Access OpenAI's Batches API.
+    pub fn batches(&self) -> BatchesResource<'_> {
+        BatchesResource::new(self)
+    }
+
+    /// Access OpenAI's Moderations API.
+    pub fn moderations(&self) -> ModerationsResource<'_> {
+        ModerationsResource::new(self)
+    }
+
     /// Access OpenAI's Conversations API.
     pub fn conversations(&self) -> ConversationsResource<'_> {
         ConversationsResource::new(self)
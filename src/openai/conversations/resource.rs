@@ -178,7 +178,7 @@ mod tests {
 
     use super::*;
     use crate::{
-        core::test_support::{json_response, one_shot_server},
+        core::test_support::{json_response, one_shot_server, openai_client, request_json},
         openai::conversations::ConversationMetadata,
         openai::responses::{ListOrder, OpenAIResponseInputItem, ResponseInclude, ResponseItemId},
     };
@@ -197,14 +197,6 @@ mod tests {
         "last_id":"item_last"
     }"#;
 
-    fn make_client(base_url: String) -> OpenAIClient {
-        OpenAIClient::builder()
-            .api_key("test-key".into())
-            .base_url(base_url)
-            .build()
-            .unwrap()
-    }
-
     fn json_ok(body: &str) -> String {
         json_response("200 OK", &[], body)
     }
@@ -227,11 +219,6 @@ mod tests {
             .unwrap()
     }
 
-    fn request_json(request: &str) -> serde_json::Value {
-        let (_, body) = request.split_once("\r\n\r\n").unwrap();
-        serde_json::from_str(body).unwrap()
-    }
-
     fn metadata() -> ConversationMetadata {
         ConversationMetadata::new(BTreeMap::from([("topic".into(), "testing".into())])).unwrap()
     }
@@ -251,7 +238,7 @@ mod tests {
 
         let (base_url, captured) = one_shot_server("v1", json_ok(CONVERSATION_BODY)).await;
         let request = CreateConversationRequest::new().metadata(metadata());
-        let client = make_client(base_url);
+        let client = openai_client(base_url);
         let response = ConversationsResource::new(&client)
             .create(&request)
             .await
@@ -267,7 +254,7 @@ mod tests {
 
         let encoded_id = ConversationId::new("conv/a?b#c%").unwrap();
         let (base_url, captured) = one_shot_server("v1", json_ok(CONVERSATION_BODY)).await;
-        let client = make_client(base_url);
+        let client = openai_client(base_url);
         ConversationsResource::new(&client)
             .retrieve(&encoded_id)
             .await
@@ -280,7 +267,7 @@ mod tests {
         );
 
         let (base_url, captured) = one_shot_server("v1", json_ok(CONVERSATION_BODY)).await;
-        let client = make_client(base_url);
+        let client = openai_client(base_url);
         ConversationsResource::new(&client)
             .update(&id, &UpdateConversationRequest::new(metadata()))
             .await
@@ -295,7 +282,7 @@ mod tests {
 
         let deleted = r#"{"object":"conversation.deleted","deleted":true,"id":"conv_123"}"#;
         let (base_url, captured) = one_shot_server("v1", json_ok(deleted)).await;
-        let client = make_client(base_url);
+        let client = openai_client(base_url);
         let response = ConversationsResource::new(&client)
             .delete(&id)
             .await
@@ -315,7 +302,7 @@ mod tests {
             .include(ResponseInclude::ReasoningEncryptedContent);
 
         let (base_url, captured) = one_shot_server("v1", json_ok(ITEM_LIST_BODY)).await;
-        let client = make_client(base_url);
+        let client = openai_client(base_url);
         ConversationItemsResource::new(&client)
             .create(
                 &conversation_id,
@@ -344,7 +331,7 @@ mod tests {
             .after(item_id.clone())
             .include(ResponseInclude::MessageOutputTextLogprobs);
         let (base_url, captured) = one_shot_server("v1", json_ok(ITEM_LIST_BODY)).await;
-        let client = make_client(base_url);
+        let client = openai_client(base_url);
         ConversationItemsResource::new(&client)
             .list(&conversation_id, &options)
             .await
@@ -359,7 +346,7 @@ mod tests {
         let encoded_item_id = ResponseItemId::new("item/a?b#c%").unwrap();
         let item = r#"{"type":"message","id":"item_123","role":"user","content":[{"type":"input_text","text":"hello"}],"status":"completed"}"#;
         let (base_url, captured) = one_shot_server("v1", json_ok(item)).await;
-        let client = make_client(base_url);
+        let client = openai_client(base_url);
         ConversationItemsResource::new(&client)
             .retrieve(&conversation_id, &encoded_item_id, &include)
             .await
@@ -372,7 +359,7 @@ mod tests {
         );
 
         let (base_url, captured) = one_shot_server("v1", json_ok(CONVERSATION_BODY)).await;
-        let client = make_client(base_url);
+        let client = openai_client(base_url);
         ConversationItemsResource::new(&client)
             .delete(&conversation_id, &item_id)
             .await
@@ -0,0 +1,532 @@
+//! OpenAI's Embeddings API with typed embedding-model markers.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    error::{AiError, AiResponse, AiResult},
+    openai::{client::decode_openai_error, OpenAIClient},
+};
+
+/// A model accepted by OpenAI's Embeddings resource.
+///
+/// Implement it for an embedding model this crate does not list yet, such as
+/// a newer snapshot; `ID` is sent unchanged as `model`.
+pub trait OpenAIEmbeddingModel: Send + Sync + 'static {
+    const ID: &'static str;
+}
+
+/// Models that can shorten their output with `dimensions`.
+pub trait SupportsEmbeddingDimensions: OpenAIEmbeddingModel {
+    /// The model's native, and largest accepted, dimension count.
+    const MAX_DIMENSIONS: u32;
+}
+
+macro_rules! models {
+    ($($name:ident => $id:literal),+ $(,)?) => {$ (
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+        impl OpenAIEmbeddingModel for $name { const ID: &'static str = $id; }
+        impl $name {
+            /// Start a compile-time checked Embeddings configuration for this model.
+            pub fn config() -> EmbeddingModelConfig<Self> {
+                EmbeddingModelConfig::new()
+            }
+        }
+    )+ };
+}
+
+models! {
+    TextEmbedding3Large => "text-embedding-3-large",
+    TextEmbedding3Small => "text-embedding-3-small",
+    TextEmbeddingAda002 => "text-embedding-ada-002",
+}
+
+pub const KNOWN_EMBEDDING_MODEL_IDS: &[&str] = &[
+    TextEmbedding3Large::ID,
+    TextEmbedding3Small::ID,
+    TextEmbeddingAda002::ID,
+];
+
+// `dimensions` is documented as "only supported in `text-embedding-3` and
+// later models" by the pinned `CreateEmbeddingRequest` schema.
+
+impl SupportsEmbeddingDimensions for TextEmbedding3Large {
+    const MAX_DIMENSIONS: u32 = 3072;
+}
+
+impl SupportsEmbeddingDimensions for TextEmbedding3Small {
+    const MAX_DIMENSIONS: u32 = 1536;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("{model} accepts 1..={max} dimensions, not {dimensions}")]
+pub struct EmbeddingDimensionsError {
+    pub model: &'static str,
+    pub dimensions: u32,
+    pub max: u32,
+}
+
+/// Reusable, compile-time checked configuration for one embedding model.
+///
+/// Inputs live in the request, so one request can be sent with different
+/// model configs.
+pub struct EmbeddingModelConfig<M: OpenAIEmbeddingModel> {
+    pub(crate) output_dimensions: Option<u32>,
+    model: PhantomData<fn() -> M>,
+}
+
+impl<M: OpenAIEmbeddingModel> Clone for EmbeddingModelConfig<M> {
+    fn clone(&self) -> Self {
+        Self {
+            output_dimensions: self.output_dimensions,
+            model: PhantomData,
+        }
+    }
+}
+
+impl<M: OpenAIEmbeddingModel> fmt::Debug for EmbeddingModelConfig<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmbeddingModelConfig")
+            .field("model", &M::ID)
+            .field("dimensions", &self.output_dimensions)
+            .finish()
+    }
+}
+
+impl<M: OpenAIEmbeddingModel> EmbeddingModelConfig<M> {
+    /// Start configuring a custom embedding model marker.
+    pub fn new() -> Self {
+        Self {
+            output_dimensions: None,
+            model: PhantomData,
+        }
+    }
+
+    pub fn model_id(&self) -> &'static str {
+        M::ID
+    }
+}
+
+impl<M: OpenAIEmbeddingModel> Default for EmbeddingModelConfig<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: SupportsEmbeddingDimensions> EmbeddingModelConfig<M> {
+    /// Shorten the returned vectors to `dimensions` entries.
+    pub fn dimensions(mut self, dimensions: u32) -> Result<Self, EmbeddingDimensionsError> {
+        if !(1..=M::MAX_DIMENSIONS).contains(&dimensions) {
+            return Err(EmbeddingDimensionsError {
+                model: M::ID,
+                dimensions,
+                max: M::MAX_DIMENSIONS,
+            });
+        }
+        self.output_dimensions = Some(dimensions);
+        Ok(self)
+    }
+}
+
+/// Most inputs accepted in one embeddings request.
+pub const MAX_EMBEDDING_INPUTS: usize = 2048;
+
+/// Why an embedding input was rejected before it was sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum EmbeddingInputError {
+    #[error("embedding input must not be empty")]
+    Empty,
+    #[error("embedding inputs must not be empty strings or token arrays")]
+    EmptyItem,
+    #[error("{count} embedding inputs exceed the limit of {MAX_EMBEDDING_INPUTS}")]
+    TooMany { count: usize },
+}
+
+/// Text or pre-tokenized input to embed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum EmbeddingInput {
+    Text(String),
+    Texts(Vec<String>),
+    Tokens(Vec<u32>),
+    TokenBatches(Vec<Vec<u32>>),
+}
+
+impl EmbeddingInput {
+    /// Number of embeddings the input produces.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Text(_) | Self::Tokens(_) => 1,
+            Self::Texts(texts) => texts.len(),
+            Self::TokenBatches(batches) => batches.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn check(&self) -> Result<(), EmbeddingInputError> {
+        let empty_item = match self {
+            Self::Text(text) => text.is_empty(),
+            Self::Texts(texts) => texts.iter().any(String::is_empty),
+            Self::Tokens(tokens) => tokens.is_empty(),
+            Self::TokenBatches(batches) => batches.iter().any(Vec::is_empty),
+        };
+        if self.is_empty() {
+            Err(EmbeddingInputError::Empty)
+        } else if empty_item {
+            Err(EmbeddingInputError::EmptyItem)
+        } else if self.len() > MAX_EMBEDDING_INPUTS {
+            Err(EmbeddingInputError::TooMany { count: self.len() })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl From<String> for EmbeddingInput {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for EmbeddingInput {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<Vec<String>> for EmbeddingInput {
+    fn from(texts: Vec<String>) -> Self {
+        Self::Texts(texts)
+    }
+}
+
+impl From<Vec<&str>> for EmbeddingInput {
+    fn from(texts: Vec<&str>) -> Self {
+        Self::Texts(texts.into_iter().map(str::to_string).collect())
+    }
+}
+
+impl From<Vec<u32>> for EmbeddingInput {
+    fn from(tokens: Vec<u32>) -> Self {
+        Self::Tokens(tokens)
+    }
+}
+
+impl From<Vec<Vec<u32>>> for EmbeddingInput {
+    fn from(batches: Vec<Vec<u32>>) -> Self {
+        Self::TokenBatches(batches)
+    }
+}
+
+/// Wire format for the returned vectors. Both decode to `Vec<f32>`; base64
+/// is smaller on the wire and cheaper to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingEncodingFormat {
+    #[default]
+    Float,
+    Base64,
+}
+
+/// Model-independent content of an embeddings request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateEmbeddingRequest {
+    input: EmbeddingInput,
+    encoding_format: Option<EmbeddingEncodingFormat>,
+    user: Option<String>,
+}
+
+impl CreateEmbeddingRequest {
+    pub fn new(input: impl Into<EmbeddingInput>) -> Self {
+        Self {
+            input: input.into(),
+            encoding_format: None,
+            user: None,
+        }
+    }
+
+    pub fn encoding_format(mut self, format: EmbeddingEncodingFormat) -> Self {
+        self.encoding_format = Some(format);
+        self
+    }
+
+    /// Stable end-user identifier used by OpenAI for abuse monitoring.
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    pub fn input(&self) -> &EmbeddingInput {
+        &self.input
+    }
+}
+
+/// Private wire body for `POST /embeddings`.
+#[derive(Debug, Serialize)]
+pub(crate) struct EmbeddingWireRequest {
+    input: EmbeddingInput,
+    model: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding_format: Option<EmbeddingEncodingFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dimensions: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+}
+
+impl EmbeddingWireRequest {
+    pub(crate) fn new<M: OpenAIEmbeddingModel>(
+        config: EmbeddingModelConfig<M>,
+        request: CreateEmbeddingRequest,
+    ) -> Result<Self, EmbeddingInputError> {
+        request.input.check()?;
+        Ok(Self {
+            input: request.input,
+            model: M::ID,
+            encoding_format: request.encoding_format,
+            dimensions: config.output_dimensions,
+            user: request.user,
+        })
+    }
+}
+
+/// Response from `POST /embeddings`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingsResponse {
+    pub data: Vec<Embedding>,
+    pub model: String,
+    pub usage: EmbeddingsUsage,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl EmbeddingsResponse {
+    /// The vectors in input order.
+    pub fn into_vectors(mut self) -> Vec<Vec<f32>> {
+        self.data.sort_by_key(|embedding| embedding.index);
+        self.data
+            .into_iter()
+            .map(|embedding| embedding.embedding)
+            .collect()
+    }
+}
+
+/// One embedding vector. Base64 responses are decoded while parsing, so the
+/// vector is the same whichever encoding format was requested.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Embedding {
+    pub index: u64,
+    #[serde(deserialize_with = "deserialize_vector")]
+    pub embedding: Vec<f32>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmbeddingsUsage {
+    pub prompt_tokens: u64,
+    pub total_tokens: u64,
+}
+
+fn deserialize_vector<'de, D>(deserializer: D) -> Result<Vec<f32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct VectorVisitor;
+
+    impl<'de> de::Visitor<'de> for VectorVisitor {
+        type Value = Vec<f32>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an array of floats or a base64 string")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let mut vector = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(value) = seq.next_element()? {
+                vector.push(value);
+            }
+            Ok(vector)
+        }
+
+        fn visit_str<E: de::Error>(self, data: &str) -> Result<Self::Value, E> {
+            decode_base64_vector(data).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_any(VectorVisitor)
+}
+
+/// Decodes little-endian `f32` values packed as base64.
+fn decode_base64_vector(data: &str) -> Result<Vec<f32>, String> {
+    use base64::Engine as _;
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .map_err(|error| format!("embedding is not valid base64: {error}"))?;
+    if bytes.len() % 4 != 0 {
+        return Err(format!(
+            "base64 embedding has {} bytes, not a multiple of 4",
+            bytes.len()
+        ));
+    }
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+/// OpenAI's Embeddings API.
+#[derive(Clone, Copy)]
+pub struct EmbeddingsResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> EmbeddingsResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    /// Embed one or more inputs. Empty or oversized batches are rejected
+    /// before the request is sent.
+    pub async fn create<M>(
+        &self,
+        config: EmbeddingModelConfig<M>,
+        request: CreateEmbeddingRequest,
+    ) -> AiResult<AiResponse<EmbeddingsResponse>>
+    where
+        M: OpenAIEmbeddingModel,
+    {
+        let wire = EmbeddingWireRequest::new(config, request)
+            .map_err(|error| AiError::invalid_request("embeddings.create", error))?;
+        self.client
+            .transport()
+            .post_json(
+                "embeddings.create",
+                "embeddings",
+                &wire,
+                decode_openai_error,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_support::{json_response, one_shot_server, openai_client, request_json};
+
+    #[tokio::test]
+    async fn create_sends_batch_with_dimensions_and_decodes_base64_vectors() {
+        let body = r#"{
+            "object": "list",
+            "data": [
+                {"object": "embedding", "index": 1, "embedding": "AAAAvw=="},
+                {"object": "embedding", "index": 0, "embedding": "AACAPwAAAL8="}
+            ],
+            "model": "text-embedding-3-small",
+            "usage": {"prompt_tokens": 8, "total_tokens": 8}
+        }"#;
+        let (base_url, wire) = one_shot_server(
+            "v1",
+            json_response("200 OK", &[("x-request-id", "req_embed")], body),
+        )
+        .await;
+        let config = TextEmbedding3Small::config().dimensions(256).unwrap();
+        let request = CreateEmbeddingRequest::new(vec!["first", "second"])
+            .encoding_format(EmbeddingEncodingFormat::Base64)
+            .user("user-1");
+        let response = openai_client(base_url)
+            .embeddings()
+            .create(config, request)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({
+                "input": ["first", "second"],
+                "model": "text-embedding-3-small",
+                "encoding_format": "base64",
+                "dimensions": 256,
+                "user": "user-1"
+            })
+        );
+        assert_eq!(response.metadata().request_id.as_deref(), Some("req_embed"));
+        let response = response.into_inner();
+        assert_eq!(response.usage.total_tokens, 8);
+        assert_eq!(response.into_vectors(), [vec![1.0, -0.5], vec![-0.5]]);
+    }
+
+    #[tokio::test]
+    async fn create_sends_token_batches_and_decodes_float_vectors() {
+        let body = r#"{"object":"list","data":[{"object":"embedding","index":0,"embedding":[0.25,-1]}],"model":"text-embedding-ada-002","usage":{"prompt_tokens":3,"total_tokens":3}}"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], body)).await;
+        let response = openai_client(base_url)
+            .embeddings()
+            .create(
+                TextEmbeddingAda002::config(),
+                CreateEmbeddingRequest::new(vec![vec![1212, 318, 257]]),
+            )
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({"input": [[1212, 318, 257]], "model": "text-embedding-ada-002"})
+        );
+        assert_eq!(response.data[0].embedding, [0.25, -1.0]);
+    }
+
+    #[tokio::test]
+    async fn invalid_inputs_and_dimensions_are_rejected_locally() {
+        let client = openai_client("http://127.0.0.1:9/v1".into());
+        for (input, expected) in [
+            (
+                CreateEmbeddingRequest::new(""),
+                EmbeddingInputError::EmptyItem,
+            ),
+            (
+                CreateEmbeddingRequest::new(Vec::<String>::new()),
+                EmbeddingInputError::Empty,
+            ),
+            (
+                CreateEmbeddingRequest::new(vec![Vec::<u32>::new()]),
+                EmbeddingInputError::EmptyItem,
+            ),
+            (
+                CreateEmbeddingRequest::new(vec!["x"; 2049]),
+                EmbeddingInputError::TooMany { count: 2049 },
+            ),
+        ] {
+            let result = client
+                .embeddings()
+                .create(TextEmbedding3Small::config(), input)
+                .await;
+            let Err(AiError::InvalidRequest { source, .. }) = result else {
+                panic!("expected a local rejection, got {result:?}");
+            };
+            assert_eq!(source.downcast_ref(), Some(&expected));
+        }
+
+        assert_eq!(
+            TextEmbedding3Large::config().dimensions(3073).unwrap_err(),
+            EmbeddingDimensionsError {
+                model: "text-embedding-3-large",
+                dimensions: 3073,
+                max: 3072
+            }
+        );
+        assert!(TextEmbedding3Small::config().dimensions(0).is_err());
+    }
+}
@@ -0,0 +1,672 @@
+//! OpenAI's Files API: upload, list, retrieve, delete, and download files.
+
+use std::{fmt, path::Path};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+#[cfg(feature = "stream")]
+use crate::stream::AiStream;
+use crate::{
+    error::{AiResponse, AiResult},
+    openai::{client::decode_openai_error, id::validated_id, responses::ListOrder, OpenAIClient},
+};
+
+const MAX_FILE_BYTES: usize = 512 * 1024 * 1024;
+const MIN_EXPIRATION_SECONDS: u32 = 3_600;
+const MAX_EXPIRATION_SECONDS: u32 = 2_592_000;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum FileError {
+    #[error("failed to read file for upload: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("file ID must be non-empty and contain no whitespace or control characters")]
+    InvalidFileId,
+    #[error("`{filename}` is empty")]
+    EmptyFile { filename: String },
+    #[error("`{filename}` is {bytes} bytes; files may be at most 512 MB")]
+    TooLarge { filename: String, bytes: usize },
+    #[error("file expiry must be between 3600 and 2592000 seconds, got {0}")]
+    InvalidExpiration(u32),
+    #[error("page limit must be between 1 and 10000")]
+    InvalidPageLimit,
+}
+
+validated_id!(
+    /// A validated OpenAI file ID such as `file-abc123`.
+    ///
+    /// Converts into [`crate::openai::responses::OpenAIFileSource`] and
+    /// [`crate::openai::responses::OpenAIImageSource`], so an uploaded file can be
+    /// referenced from Responses input content without re-parsing its ID.
+    FileId,
+    FileError::InvalidFileId
+);
+
+/// The purposes a file can be uploaded for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileUploadPurpose {
+    UserData,
+    Assistants,
+    Batch,
+    Vision,
+    Evals,
+    FineTune,
+}
+
+impl FileUploadPurpose {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::UserData => "user_data",
+            Self::Assistants => "assistants",
+            Self::Batch => "batch",
+            Self::Vision => "vision",
+            Self::Evals => "evals",
+            Self::FineTune => "fine-tune",
+        }
+    }
+}
+
+impl Serialize for FileUploadPurpose {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// A stored file's purpose, including provider-generated output purposes.
+/// Purposes this crate does not know yet are kept verbatim in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilePurpose {
+    UserData,
+    Assistants,
+    AssistantsOutput,
+    Batch,
+    BatchOutput,
+    Vision,
+    Evals,
+    FineTune,
+    FineTuneResults,
+    Other(String),
+}
+
+impl FilePurpose {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::UserData => "user_data",
+            Self::Assistants => "assistants",
+            Self::AssistantsOutput => "assistants_output",
+            Self::Batch => "batch",
+            Self::BatchOutput => "batch_output",
+            Self::Vision => "vision",
+            Self::Evals => "evals",
+            Self::FineTune => "fine-tune",
+            Self::FineTuneResults => "fine-tune-results",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<FileUploadPurpose> for FilePurpose {
+    fn from(value: FileUploadPurpose) -> Self {
+        match value {
+            FileUploadPurpose::UserData => Self::UserData,
+            FileUploadPurpose::Assistants => Self::Assistants,
+            FileUploadPurpose::Batch => Self::Batch,
+            FileUploadPurpose::Vision => Self::Vision,
+            FileUploadPurpose::Evals => Self::Evals,
+            FileUploadPurpose::FineTune => Self::FineTune,
+        }
+    }
+}
+
+impl Serialize for FilePurpose {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilePurpose {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "user_data" => Self::UserData,
+            "assistants" => Self::Assistants,
+            "assistants_output" => Self::AssistantsOutput,
+            "batch" => Self::Batch,
+            "batch_output" => Self::BatchOutput,
+            "vision" => Self::Vision,
+            "evals" => Self::Evals,
+            "fine-tune" => Self::FineTune,
+            "fine-tune-results" => Self::FineTuneResults,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FileStatus {
+    Uploaded,
+    Processed,
+    Error,
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for FileStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "uploaded" => Self::Uploaded,
+            "processed" => Self::Processed,
+            "error" => Self::Error,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+/// How long after creation an uploaded file expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileExpiration {
+    seconds: u32,
+}
+
+impl FileExpiration {
+    /// Expire the file `seconds` after it is created, between one hour and 30 days.
+    pub fn after_creation(seconds: u32) -> Result<Self, FileError> {
+        if !(MIN_EXPIRATION_SECONDS..=MAX_EXPIRATION_SECONDS).contains(&seconds) {
+            return Err(FileError::InvalidExpiration(seconds));
+        }
+        Ok(Self { seconds })
+    }
+
+    pub fn seconds(self) -> u32 {
+        self.seconds
+    }
+}
+
+impl Serialize for FileExpiration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct as _;
+
+        let mut state = serializer.serialize_struct("FileExpiration", 2)?;
+        state.serialize_field("anchor", "created_at")?;
+        state.serialize_field("seconds", &self.seconds)?;
+        state.end()
+    }
+}
+
+/// File contents and name for a multipart upload.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FileUpload {
+    filename: String,
+    bytes: Vec<u8>,
+}
+
+impl fmt::Debug for FileUpload {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("FileUpload")
+            .field("filename", &self.filename)
+            .field("bytes", &self.bytes.len())
+            .finish()
+    }
+}
+
+impl FileUpload {
+    pub fn from_bytes(
+        filename: impl Into<String>,
+        bytes: impl Into<Vec<u8>>,
+    ) -> Result<Self, FileError> {
+        let filename = filename.into();
+        let bytes = bytes.into();
+        if bytes.is_empty() {
+            return Err(FileError::EmptyFile { filename });
+        }
+        if bytes.len() > MAX_FILE_BYTES {
+            return Err(FileError::TooLarge {
+                filename,
+                bytes: bytes.len(),
+            });
+        }
+        Ok(Self { filename, bytes })
+    }
+
+    /// Read a file from disk, keeping its file name for the upload.
+    pub async fn from_file(path: impl AsRef<Path>) -> Result<Self, FileError> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path).await?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "file".to_string());
+        Self::from_bytes(filename, bytes)
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateFileRequest {
+    file: FileUpload,
+    purpose: FileUploadPurpose,
+    expires_after: Option<FileExpiration>,
+}
+
+impl CreateFileRequest {
+    pub fn new(file: FileUpload, purpose: FileUploadPurpose) -> Self {
+        Self {
+            file,
+            purpose,
+            expires_after: None,
+        }
+    }
+
+    pub fn expires_after(mut self, expiration: FileExpiration) -> Self {
+        self.expires_after = Some(expiration);
+        self
+    }
+
+    fn into_form(self) -> reqwest::multipart::Form {
+        let part = reqwest::multipart::Part::bytes(self.file.bytes).file_name(self.file.filename);
+        let mut form = reqwest::multipart::Form::new()
+            .text("purpose", self.purpose.as_str())
+            .part("file", part);
+        if let Some(expiration) = self.expires_after {
+            form = form
+                .text("expires_after[anchor]", "created_at")
+                .text("expires_after[seconds]", expiration.seconds.to_string());
+        }
+        form
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListFilesOptions {
+    purpose: Option<FilePurpose>,
+    limit: Option<u16>,
+    order: Option<ListOrder>,
+    after: Option<FileId>,
+}
+
+impl ListFilesOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn purpose(mut self, purpose: impl Into<FilePurpose>) -> Self {
+        self.purpose = Some(purpose.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Result<Self, FileError> {
+        if !(1..=10_000).contains(&limit) {
+            return Err(FileError::InvalidPageLimit);
+        }
+        self.limit = Some(limit);
+        Ok(self)
+    }
+
+    pub fn order(mut self, order: ListOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn after(mut self, after: FileId) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    pub(crate) fn query(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+        if let Some(purpose) = &self.purpose {
+            query.push(("purpose".into(), purpose.as_str().into()));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("limit".into(), limit.to_string()));
+        }
+        if let Some(order) = self.order {
+            query.push(("order".into(), order.as_str().into()));
+        }
+        if let Some(after) = &self.after {
+            query.push(("after".into(), after.as_str().into()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenAIFile {
+    pub id: FileId,
+    pub object: String,
+    pub bytes: u64,
+    pub created_at: u64,
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+    pub filename: String,
+    pub purpose: FilePurpose,
+    #[serde(default)]
+    pub status: Option<FileStatus>,
+    #[serde(default)]
+    pub status_details: Option<String>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FileList {
+    pub object: String,
+    pub data: Vec<OpenAIFile>,
+    pub has_more: bool,
+    #[serde(default)]
+    pub first_id: Option<FileId>,
+    #[serde(default)]
+    pub last_id: Option<FileId>,
+}
+
+impl FileList {
+    /// Options for the page after this one, or `None` on the last page.
+    pub fn next_page(&self, options: &ListFilesOptions) -> Option<ListFilesOptions> {
+        let last_id = self.last_id.clone()?;
+        self.has_more.then(|| options.clone().after(last_id))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeletedFile {
+    pub id: FileId,
+    pub object: String,
+    pub deleted: bool,
+}
+
+#[derive(Clone, Copy)]
+pub struct FilesResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> FilesResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    /// Upload a file as multipart form data.
+    pub async fn create(&self, request: CreateFileRequest) -> AiResult<AiResponse<OpenAIFile>> {
+        self.client
+            .transport()
+            .post_multipart(
+                "files.create",
+                "files",
+                request.into_form(),
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn list(&self, options: ListFilesOptions) -> AiResult<AiResponse<FileList>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "files.list",
+                &["files"],
+                &options.query(),
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn retrieve(&self, file_id: &FileId) -> AiResult<AiResponse<OpenAIFile>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "files.retrieve",
+                &["files", file_id.as_str()],
+                &[],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn delete(&self, file_id: &FileId) -> AiResult<AiResponse<DeletedFile>> {
+        self.client
+            .transport()
+            .delete_json_segments(
+                "files.delete",
+                &["files", file_id.as_str()],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    /// Download a file's contents into memory.
+    pub async fn content(&self, file_id: &FileId) -> AiResult<AiResponse<Vec<u8>>> {
+        self.client
+            .transport()
+            .get_bytes_segments(
+                "files.content",
+                &["files", file_id.as_str(), "content"],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    /// Download a file's contents as a stream of byte chunks.
+    #[cfg(feature = "stream")]
+    pub async fn content_stream(
+        &self,
+        file_id: &FileId,
+    ) -> AiResult<AiResponse<AiStream<Vec<u8>>>> {
+        self.client
+            .transport()
+            .get_stream_segments(
+                "files.content",
+                &["files", file_id.as_str(), "content"],
+                &[],
+                decode_openai_error,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_support::{json_response, one_shot_server, openai_client},
+        openai::responses::{OpenAIImageDetail, OpenAIResponsesInputContentPart},
+    };
+
+    const FILE: &str = r#"{
+        "id": "file-abc123",
+        "object": "file",
+        "bytes": 5,
+        "created_at": 1700000000,
+        "expires_at": 1700003600,
+        "filename": "notes.jsonl",
+        "purpose": "batch",
+        "status": "processed"
+    }"#;
+
+    fn form_field(wire: &str, name: &str) -> String {
+        let header = format!("Content-Disposition: form-data; name=\"{name}\"\r\n\r\n");
+        let start = wire.find(&header).unwrap() + header.len();
+        wire[start..].split("\r\n").next().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn create_uploads_multipart_with_purpose_and_expiry() {
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], FILE)).await;
+        let request = CreateFileRequest::new(
+            FileUpload::from_bytes("notes.jsonl", b"{}\n{}".to_vec()).unwrap(),
+            FileUploadPurpose::Batch,
+        )
+        .expires_after(FileExpiration::after_creation(3_600).unwrap());
+        let file = openai_client(base_url)
+            .files()
+            .create(request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        let wire = wire.await.unwrap();
+        assert!(wire.starts_with("POST /v1/files HTTP/1.1"));
+        assert_eq!(form_field(&wire, "purpose"), "batch");
+        assert_eq!(form_field(&wire, "expires_after[anchor]"), "created_at");
+        assert_eq!(form_field(&wire, "expires_after[seconds]"), "3600");
+        assert!(wire.contains("name=\"file\"; filename=\"notes.jsonl\""));
+        assert_eq!(file.id.as_str(), "file-abc123");
+        assert_eq!(file.purpose, FilePurpose::Batch);
+        assert_eq!(file.status, Some(FileStatus::Processed));
+    }
+
+    #[tokio::test]
+    async fn list_sends_filters_and_pages_forward() {
+        let body = format!(
+            r#"{{"object":"list","data":[{FILE}],"first_id":"file-abc123","last_id":"file-abc123","has_more":true}}"#
+        );
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], &body)).await;
+        let options = ListFilesOptions::new()
+            .purpose(FileUploadPurpose::UserData)
+            .limit(2)
+            .unwrap()
+            .order(ListOrder::Asc);
+        let page = openai_client(base_url)
+            .files()
+            .list(options.clone())
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert!(wire
+            .await
+            .unwrap()
+            .starts_with("GET /v1/files?purpose=user_data&limit=2&order=asc HTTP/1.1"));
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(
+            page.next_page(&options).unwrap().query().last().unwrap(),
+            &("after".to_string(), "file-abc123".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn retrieve_delete_and_content_address_the_file() {
+        let file_id = FileId::new("file-abc123").unwrap();
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], FILE)).await;
+        let file = openai_client(base_url)
+            .files()
+            .retrieve(&file_id)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(wire
+            .await
+            .unwrap()
+            .starts_with("GET /v1/files/file-abc123 HTTP/1.1"));
+        assert_eq!(file.filename, "notes.jsonl");
+
+        let deleted = r#"{"id":"file-abc123","object":"file","deleted":true}"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], deleted)).await;
+        let deleted = openai_client(base_url)
+            .files()
+            .delete(&file_id)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(wire
+            .await
+            .unwrap()
+            .starts_with("DELETE /v1/files/file-abc123 HTTP/1.1"));
+        assert!(deleted.deleted);
+
+        let (base_url, wire) =
+            one_shot_server("v1", json_response("200 OK", &[], "line one\n")).await;
+        let content = openai_client(base_url)
+            .files()
+            .content(&file_id)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(wire
+            .await
+            .unwrap()
+            .starts_with("GET /v1/files/file-abc123/content HTTP/1.1"));
+        assert_eq!(content, b"line one\n");
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn content_stream_yields_raw_chunks() {
+        use futures::StreamExt;
+
+        use crate::core::test_support::chunked_server;
+
+        let (base_url, _wire) =
+            chunked_server("v1", &[], vec![b"first ".to_vec(), b"second".to_vec()]).await;
+        let mut stream = openai_client(base_url)
+            .files()
+            .content_stream(&FileId::new("file-abc123").unwrap())
+            .await
+            .unwrap()
+            .into_inner();
+        let mut content = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            content.extend(chunk.unwrap());
+        }
+        assert_eq!(content, b"first second");
+    }
+
+    #[test]
+    fn local_validation_rejects_bad_ids_expiry_limits_and_empty_files() {
+        assert!(matches!(
+            FileId::new("file 1"),
+            Err(FileError::InvalidFileId)
+        ));
+        assert!(matches!(
+            FileExpiration::after_creation(60),
+            Err(FileError::InvalidExpiration(60))
+        ));
+        assert!(matches!(
+            ListFilesOptions::new().limit(0),
+            Err(FileError::InvalidPageLimit)
+        ));
+        assert!(matches!(
+            FileUpload::from_bytes("empty.txt", Vec::new()),
+            Err(FileError::EmptyFile { .. })
+        ));
+        assert!(serde_json::from_str::<FileId>(r#""""#).is_err());
+    }
+
+    #[test]
+    fn file_ids_are_usable_in_responses_input_content() {
+        let file_id = FileId::new("file-abc123").unwrap();
+        let parts = [
+            OpenAIResponsesInputContentPart::file(file_id.clone()),
+            OpenAIResponsesInputContentPart::image_file(file_id, OpenAIImageDetail::High),
+        ];
+        assert_eq!(
+            serde_json::to_value(parts).unwrap(),
+            serde_json::json!([
+                {"type": "input_file", "file_id": "file-abc123"},
+                {"type": "input_image", "detail": "high", "file_id": "file-abc123"}
+            ])
+        );
+    }
+}
@@ -0,0 +1,416 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::Serialize;
+
+use crate::openai::responses::{
+    OpenAIImageBackground, OpenAIImageFormat, OpenAIImageInputFidelity, OpenAIImageQuality,
+};
+
+use super::{
+    OpenAIImagesModel, SupportsImageModeration, SupportsImageOutputFormat, SupportsImageQuality,
+    SupportsImageResponseFormat, SupportsImageSize, SupportsImageStyle, SupportsInputFidelity,
+    SupportsMultipleImages, SupportsTransparentBackground,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ImageValueError {
+    #[error("image count must be within 1..=10")]
+    Count,
+    #[error("output compression must be within 0..=100")]
+    Compression,
+    #[error("partial image count must be within 0..=3")]
+    PartialImages,
+    #[error("custom image size {width}x{height} is not supported")]
+    Size { width: u32, height: u32 },
+}
+
+macro_rules! bounded_image_value {
+    ($name:ident, $error:ident, $min:literal, $max:literal) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name(u8);
+
+        impl $name {
+            pub fn new(value: u8) -> Result<Self, ImageValueError> {
+                if ($min..=$max).contains(&value) {
+                    Ok(Self(value))
+                } else {
+                    Err(ImageValueError::$error)
+                }
+            }
+
+            pub fn get(self) -> u8 {
+                self.0
+            }
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = ImageValueError;
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+    };
+}
+
+bounded_image_value!(ImageCount, Count, 1, 10);
+bounded_image_value!(ImageCompression, Compression, 0, 100);
+bounded_image_value!(PartialImageCount, PartialImages, 0, 3);
+
+/// Converts a model-family-specific size into the wire value.
+pub trait IntoImageSize {
+    fn into_image_size(self) -> String;
+}
+
+/// Converts a model-family-specific quality into the wire value.
+pub trait IntoImageQuality {
+    fn into_image_quality(self) -> &'static str;
+}
+
+impl IntoImageQuality for OpenAIImageQuality {
+    fn into_image_quality(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+/// Sizes accepted by `gpt-image-1`, `gpt-image-1-mini`, and `gpt-image-1.5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GptImageSize {
+    #[default]
+    Auto,
+    Square1024,
+    Landscape1536x1024,
+    Portrait1024x1536,
+}
+
+impl IntoImageSize for GptImageSize {
+    fn into_image_size(self) -> String {
+        match self {
+            Self::Auto => "auto",
+            Self::Square1024 => "1024x1024",
+            Self::Landscape1536x1024 => "1536x1024",
+            Self::Portrait1024x1536 => "1024x1536",
+        }
+        .to_string()
+    }
+}
+
+/// Sizes accepted by `gpt-image-2`: the standard sizes or a validated custom
+/// resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GptImage2Size {
+    Standard(GptImageSize),
+    Custom { width: u32, height: u32 },
+}
+
+impl GptImage2Size {
+    /// A custom `WIDTHxHEIGHT` resolution. Both edges must be divisible by 16,
+    /// the aspect ratio must be within 1:3 and 3:1, and the image must fit in
+    /// 3840x2160.
+    pub fn custom(width: u32, height: u32) -> Result<Self, ImageValueError> {
+        let (long, short) = (width.max(height), width.min(height));
+        let valid = width > 0
+            && height > 0
+            && width.is_multiple_of(16)
+            && height.is_multiple_of(16)
+            && long <= 3 * short
+            && long <= 3840
+            && short <= 2160;
+        if valid {
+            Ok(Self::Custom { width, height })
+        } else {
+            Err(ImageValueError::Size { width, height })
+        }
+    }
+}
+
+impl From<GptImageSize> for GptImage2Size {
+    fn from(size: GptImageSize) -> Self {
+        Self::Standard(size)
+    }
+}
+
+impl IntoImageSize for GptImage2Size {
+    fn into_image_size(self) -> String {
+        match self {
+            Self::Standard(size) => size.into_image_size(),
+            Self::Custom { width, height } => format!("{width}x{height}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DallE3Size {
+    #[default]
+    Square1024,
+    Landscape1792x1024,
+    Portrait1024x1792,
+}
+
+impl IntoImageSize for DallE3Size {
+    fn into_image_size(self) -> String {
+        match self {
+            Self::Square1024 => "1024x1024",
+            Self::Landscape1792x1024 => "1792x1024",
+            Self::Portrait1024x1792 => "1024x1792",
+        }
+        .to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DallE2Size {
+    Square256,
+    Square512,
+    #[default]
+    Square1024,
+}
+
+impl IntoImageSize for DallE2Size {
+    fn into_image_size(self) -> String {
+        match self {
+            Self::Square256 => "256x256",
+            Self::Square512 => "512x512",
+            Self::Square1024 => "1024x1024",
+        }
+        .to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DallE3Quality {
+    Standard,
+    Hd,
+    #[default]
+    Auto,
+}
+
+impl IntoImageQuality for DallE3Quality {
+    fn into_image_quality(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Hd => "hd",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageModeration {
+    Low,
+    #[default]
+    Auto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageResponseFormat {
+    #[default]
+    Url,
+    B64Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageStyle {
+    #[default]
+    Vivid,
+    Natural,
+}
+
+/// Output formats that can carry a transparent background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransparentImageFormat {
+    #[default]
+    Png,
+    Webp,
+}
+
+impl From<TransparentImageFormat> for OpenAIImageFormat {
+    fn from(format: TransparentImageFormat) -> Self {
+        match format {
+            TransparentImageFormat::Png => Self::Png,
+            TransparentImageFormat::Webp => Self::Webp,
+        }
+    }
+}
+
+/// Config state before a transparent background is selected.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnyBackground;
+
+/// Config state after selecting a transparent background. The output format
+/// was fixed to PNG or WebP at that point and can no longer change to JPEG.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TransparentBackground;
+
+/// Model-dependent settings shared by generation and edit requests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub(crate) struct ImageSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<OpenAIImageBackground>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<OpenAIImageFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_compression: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<ImageModeration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ImageResponseFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ImageStyle>,
+}
+
+/// Reusable, compile-time checked configuration for one Images model.
+///
+/// Prompt content lives in the request, so one request can be sent with
+/// different model configs.
+pub struct ImageModelConfig<M: OpenAIImagesModel, Background = AnyBackground> {
+    pub(crate) settings: ImageSettings,
+    pub(crate) input_fidelity: Option<OpenAIImageInputFidelity>,
+    state: PhantomData<fn() -> (M, Background)>,
+}
+
+impl<M: OpenAIImagesModel, Background> Clone for ImageModelConfig<M, Background> {
+    fn clone(&self) -> Self {
+        Self {
+            settings: self.settings.clone(),
+            input_fidelity: self.input_fidelity,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<M: OpenAIImagesModel, Background> fmt::Debug for ImageModelConfig<M, Background> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageModelConfig")
+            .field("model", &M::ID)
+            .field("settings", &self.settings)
+            .field("input_fidelity", &self.input_fidelity)
+            .finish()
+    }
+}
+
+impl<M: OpenAIImagesModel> ImageModelConfig<M, AnyBackground> {
+    /// Start configuring a custom Images model marker.
+    pub fn new() -> Self {
+        Self {
+            settings: ImageSettings::default(),
+            input_fidelity: None,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<M: OpenAIImagesModel> Default for ImageModelConfig<M, AnyBackground> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: OpenAIImagesModel, Background> ImageModelConfig<M, Background> {
+    pub fn model_id(&self) -> &'static str {
+        M::ID
+    }
+}
+
+impl<M: SupportsImageSize, Background> ImageModelConfig<M, Background> {
+    pub fn size(mut self, size: impl Into<M::Size>) -> Self {
+        self.settings.size = Some(size.into().into_image_size());
+        self
+    }
+}
+
+impl<M: SupportsImageQuality, Background> ImageModelConfig<M, Background> {
+    pub fn quality(mut self, quality: M::Quality) -> Self {
+        self.settings.quality = Some(quality.into_image_quality());
+        self
+    }
+}
+
+impl<M: SupportsMultipleImages, Background> ImageModelConfig<M, Background> {
+    /// Number of images to generate.
+    pub fn count(mut self, count: ImageCount) -> Self {
+        self.settings.n = Some(count.get());
+        self
+    }
+}
+
+impl<M: SupportsImageModeration, Background> ImageModelConfig<M, Background> {
+    /// Content moderation level for generations. Edits do not accept it, so
+    /// it is left out of edit requests.
+    pub fn moderation(mut self, moderation: ImageModeration) -> Self {
+        self.settings.moderation = Some(moderation);
+        self
+    }
+}
+
+impl<M: SupportsImageOutputFormat, Background> ImageModelConfig<M, Background> {
+    /// Compression level for JPEG and WebP output.
+    pub fn output_compression(mut self, compression: ImageCompression) -> Self {
+        self.settings.output_compression = Some(compression.get());
+        self
+    }
+}
+
+impl<M: SupportsImageOutputFormat> ImageModelConfig<M, AnyBackground> {
+    pub fn output_format(mut self, format: OpenAIImageFormat) -> Self {
+        self.settings.output_format = Some(format);
+        self
+    }
+}
+
+impl<M: SupportsTransparentBackground> ImageModelConfig<M, AnyBackground> {
+    pub fn opaque_background(mut self) -> Self {
+        self.settings.background = Some(OpenAIImageBackground::Opaque);
+        self
+    }
+
+    /// Request a transparent background in a format that supports one.
+    pub fn transparent_background(
+        mut self,
+        format: TransparentImageFormat,
+    ) -> ImageModelConfig<M, TransparentBackground> {
+        self.settings.background = Some(OpenAIImageBackground::Transparent);
+        self.settings.output_format = Some(format.into());
+        ImageModelConfig {
+            settings: self.settings,
+            input_fidelity: self.input_fidelity,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<M: SupportsImageResponseFormat, Background> ImageModelConfig<M, Background> {
+    /// Return URLs, valid for 60 minutes, or base64 data.
+    pub fn response_format(mut self, format: ImageResponseFormat) -> Self {
+        self.settings.response_format = Some(format);
+        self
+    }
+}
+
+impl<M: SupportsImageStyle, Background> ImageModelConfig<M, Background> {
+    pub fn style(mut self, style: ImageStyle) -> Self {
+        self.settings.style = Some(style);
+        self
+    }
+}
+
+impl<M: SupportsInputFidelity, Background> ImageModelConfig<M, Background> {
+    /// How closely edits preserve the input images. Sent only with edits.
+    pub fn input_fidelity(mut self, fidelity: OpenAIImageInputFidelity) -> Self {
+        self.input_fidelity = Some(fidelity);
+        self
+    }
+}
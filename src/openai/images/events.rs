@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::openai::responses::{
+    tagged::lossless_tagged_enum, OpenAIImageBackground, OpenAIImageFormat, OpenAIImageQuality,
+};
+
+use super::ImagesUsage;
+
+lossless_tagged_enum! {
+    /// A server-sent event from a streamed image generation or edit.
+    #[derive(Debug, Clone)]
+    pub enum ImageStreamEvent {
+        GenerationPartialImage(ImagePartialEvent) => "image_generation.partial_image",
+        GenerationCompleted(ImageCompletedEvent) => "image_generation.completed",
+        EditPartialImage(ImagePartialEvent) => "image_edit.partial_image",
+        EditCompleted(ImageCompletedEvent) => "image_edit.completed",
+        @unknown
+    }
+}
+
+impl ImageStreamEvent {
+    pub fn as_partial(&self) -> Option<&ImagePartialEvent> {
+        match self {
+            Self::GenerationPartialImage(event) | Self::EditPartialImage(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_completed(&self) -> Option<&ImageCompletedEvent> {
+        match self {
+            Self::GenerationCompleted(event) | Self::EditCompleted(event) => Some(event),
+            _ => None,
+        }
+    }
+}
+
+/// A progressively refined image sent before the final result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagePartialEvent {
+    pub b64_json: String,
+    pub created_at: u64,
+    pub size: String,
+    pub quality: OpenAIImageQuality,
+    pub background: OpenAIImageBackground,
+    pub output_format: OpenAIImageFormat,
+    /// 0-based index of this partial image.
+    pub partial_image_index: u64,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ImagePartialEvent {
+    pub fn decode_image(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        decode(&self.b64_json)
+    }
+}
+
+/// The final image, with token usage for the whole request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageCompletedEvent {
+    pub b64_json: String,
+    pub created_at: u64,
+    pub size: String,
+    pub quality: OpenAIImageQuality,
+    pub background: OpenAIImageBackground,
+    pub output_format: OpenAIImageFormat,
+    pub usage: ImagesUsage,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ImageCompletedEvent {
+    pub fn decode_image(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        decode(&self.b64_json)
+    }
+}
+
+fn decode(data: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD.decode(data)
+}
@@ -0,0 +1,293 @@
+use std::path::Path;
+
+/// File formats OpenAI accepts as image inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageInputFormat {
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl ImageInputFormat {
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+        }
+    }
+
+    /// Detect the format from the file signature.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(Self::Jpeg)
+        } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+            Some(Self::Webp)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ImageInputError {
+    #[error("failed to read image input: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("`{filename}` is not a PNG, JPEG, or WebP image")]
+    UnrecognizedFormat { filename: String },
+    #[error("`{filename}` is {actual:?} data, not {declared:?}")]
+    FormatMismatch {
+        filename: String,
+        declared: ImageInputFormat,
+        actual: ImageInputFormat,
+    },
+    #[error("`{filename}` is {format:?}; {model} accepts {accepted:?}")]
+    UnsupportedFormat {
+        filename: String,
+        format: ImageInputFormat,
+        model: &'static str,
+        accepted: &'static [ImageInputFormat],
+    },
+    #[error("`{filename}` is {size} bytes; the limit is {limit} bytes")]
+    TooLarge {
+        filename: String,
+        size: usize,
+        limit: usize,
+    },
+    #[error("{count} input images were provided; {model} accepts at most {max}")]
+    TooManyImages {
+        count: usize,
+        max: usize,
+        model: &'static str,
+    },
+    #[error("`{filename}` is {width}x{height}; {model} requires a square image")]
+    NotSquare {
+        filename: String,
+        width: u32,
+        height: u32,
+        model: &'static str,
+    },
+    #[error("mask `{filename}` must be a PNG")]
+    MaskNotPng { filename: String },
+    #[error("mask is {mask_width}x{mask_height} but the image is {width}x{height}")]
+    MaskDimensions {
+        mask_width: u32,
+        mask_height: u32,
+        width: u32,
+        height: u32,
+    },
+}
+
+/// An image file uploaded as multipart form data.
+///
+/// The format is always detected from the bytes, so a mislabelled file is
+/// rejected locally rather than uploaded with the wrong content type.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ImageInput {
+    filename: String,
+    format: ImageInputFormat,
+    bytes: Vec<u8>,
+}
+
+impl std::fmt::Debug for ImageInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImageInput")
+            .field("filename", &self.filename)
+            .field("format", &self.format)
+            .field("len", &self.bytes.len())
+            .finish()
+    }
+}
+
+impl ImageInput {
+    /// In-memory image data; the format is detected from the file signature.
+    pub fn from_bytes(
+        filename: impl Into<String>,
+        bytes: impl Into<Vec<u8>>,
+    ) -> Result<Self, ImageInputError> {
+        let filename = filename.into();
+        let bytes = bytes.into();
+        let format =
+            ImageInputFormat::sniff(&bytes).ok_or_else(|| ImageInputError::UnrecognizedFormat {
+                filename: filename.clone(),
+            })?;
+        Ok(Self {
+            filename,
+            format,
+            bytes,
+        })
+    }
+
+    /// In-memory image data that must match `format`.
+    pub fn with_format(
+        filename: impl Into<String>,
+        format: ImageInputFormat,
+        bytes: impl Into<Vec<u8>>,
+    ) -> Result<Self, ImageInputError> {
+        let input = Self::from_bytes(filename, bytes)?;
+        if input.format != format {
+            return Err(ImageInputError::FormatMismatch {
+                filename: input.filename,
+                declared: format,
+                actual: input.format,
+            });
+        }
+        Ok(input)
+    }
+
+    /// Read an image from disk, keeping its file name for the upload.
+    pub async fn from_file(path: impl AsRef<Path>) -> Result<Self, ImageInputError> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path).await?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "image".to_string());
+        Self::from_bytes(filename, bytes)
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn format(&self) -> ImageInputFormat {
+        self.format
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Width and height from the PNG header, or `None` for other formats.
+    pub fn png_dimensions(&self) -> Option<(u32, u32)> {
+        if self.format != ImageInputFormat::Png || self.bytes.get(12..16)? != b"IHDR" {
+            return None;
+        }
+        let width = u32::from_be_bytes(self.bytes.get(16..20)?.try_into().ok()?);
+        let height = u32::from_be_bytes(self.bytes.get(20..24)?.try_into().ok()?);
+        Some((width, height))
+    }
+
+    pub(crate) fn check(
+        &self,
+        model: &'static str,
+        limits: &ImageInputLimits,
+    ) -> Result<(), ImageInputError> {
+        if !limits.formats.contains(&self.format) {
+            return Err(ImageInputError::UnsupportedFormat {
+                filename: self.filename.clone(),
+                format: self.format,
+                model,
+                accepted: limits.formats,
+            });
+        }
+        check_size(self, limits.max_bytes)?;
+        if limits.square {
+            if let Some((width, height)) = self.png_dimensions().filter(|(w, h)| w != h) {
+                return Err(ImageInputError::NotSquare {
+                    filename: self.filename.clone(),
+                    width,
+                    height,
+                    model,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_mask(&self, image: &ImageInput) -> Result<(), ImageInputError> {
+        if self.format != ImageInputFormat::Png {
+            return Err(ImageInputError::MaskNotPng {
+                filename: self.filename.clone(),
+            });
+        }
+        check_size(self, MASK_MAX_BYTES)?;
+        match (self.png_dimensions(), image.png_dimensions()) {
+            (Some((mask_width, mask_height)), Some((width, height)))
+                if (mask_width, mask_height) != (width, height) =>
+            {
+                Err(ImageInputError::MaskDimensions {
+                    mask_width,
+                    mask_height,
+                    width,
+                    height,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn into_part(self) -> reqwest::multipart::Part {
+        let mime = self.format.mime_type();
+        reqwest::multipart::Part::bytes(self.bytes)
+            .file_name(self.filename)
+            .mime_str(mime)
+            .expect("image MIME types are valid")
+    }
+}
+
+const MB: usize = 1024 * 1024;
+const MASK_MAX_BYTES: usize = 4 * MB;
+
+fn check_size(input: &ImageInput, limit: usize) -> Result<(), ImageInputError> {
+    if input.bytes.len() < limit {
+        Ok(())
+    } else {
+        Err(ImageInputError::TooLarge {
+            filename: input.filename.clone(),
+            size: input.bytes.len(),
+            limit,
+        })
+    }
+}
+
+/// Input-image constraints for one model and endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageInputLimits {
+    pub max_images: usize,
+    /// Each file must be smaller than this.
+    pub max_bytes: usize,
+    pub formats: &'static [ImageInputFormat],
+    /// Whether PNG inputs must be square.
+    pub square: bool,
+}
+
+impl ImageInputLimits {
+    /// Up to 16 PNG, WebP, or JPEG files under 50 MB each.
+    pub const GPT_IMAGE: Self = Self {
+        max_images: 16,
+        max_bytes: 50 * MB,
+        formats: &[
+            ImageInputFormat::Png,
+            ImageInputFormat::Webp,
+            ImageInputFormat::Jpeg,
+        ],
+        square: false,
+    };
+
+    /// One square PNG under 4 MB.
+    pub const DALL_E_2: Self = Self {
+        max_images: 1,
+        max_bytes: 4 * MB,
+        formats: &[ImageInputFormat::Png],
+        square: true,
+    };
+
+    pub(crate) fn check(
+        &self,
+        model: &'static str,
+        images: &[ImageInput],
+    ) -> Result<(), ImageInputError> {
+        if images.len() > self.max_images {
+            return Err(ImageInputError::TooManyImages {
+                count: images.len(),
+                max: self.max_images,
+                model,
+            });
+        }
+        images.iter().try_for_each(|image| image.check(model, self))
+    }
+}
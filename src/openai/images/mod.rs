@@ -0,0 +1,21 @@
+//! OpenAI's standalone Images API with compile-time checked model settings.
+
+mod config;
+mod events;
+mod input;
+mod models;
+mod request;
+mod resource;
+mod response;
+#[cfg(feature = "stream")]
+mod stream;
+
+pub use config::*;
+pub use events::*;
+pub use input::*;
+pub use models::*;
+pub use request::*;
+pub use resource::*;
+pub use response::*;
+#[cfg(feature = "stream")]
+pub use stream::*;
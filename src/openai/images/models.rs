@@ -0,0 +1,136 @@
+use crate::openai::responses::OpenAIImageQuality;
+
+use super::{
+    DallE2Size, DallE3Quality, DallE3Size, GptImage2Size, GptImageSize, ImageInputLimits,
+    IntoImageQuality, IntoImageSize,
+};
+
+/// A model accepted by OpenAI's standalone Images resource.
+///
+/// Applications can implement it for image model snapshots this crate does
+/// not list; each option is then opted into through its `Supports*` trait.
+pub trait OpenAIImagesModel: Send + Sync + 'static {
+    const ID: &'static str;
+}
+
+pub trait SupportsImageSize: OpenAIImagesModel {
+    type Size: IntoImageSize;
+}
+pub trait SupportsImageQuality: OpenAIImagesModel {
+    type Quality: IntoImageQuality;
+}
+/// Models that accept `n > 1`.
+pub trait SupportsMultipleImages: OpenAIImagesModel {}
+/// Models that accept `background`, including transparent backgrounds.
+pub trait SupportsTransparentBackground: OpenAIImagesModel {}
+/// Models that accept `output_format` and `output_compression`.
+pub trait SupportsImageOutputFormat: OpenAIImagesModel {}
+pub trait SupportsImageModeration: OpenAIImagesModel {}
+/// Models that can return URLs instead of base64 data.
+pub trait SupportsImageResponseFormat: OpenAIImagesModel {}
+pub trait SupportsImageStyle: OpenAIImagesModel {}
+/// Models accepted by `POST /images/edits`.
+pub trait SupportsImageEdits: OpenAIImagesModel {
+    /// Checked locally before an edit is uploaded.
+    const EDIT_INPUT_LIMITS: ImageInputLimits;
+}
+/// Models that accept `input_fidelity` on edits.
+pub trait SupportsInputFidelity: SupportsImageEdits {}
+/// Models that can stream partial images from generations and edits.
+pub trait SupportsImageStreaming: OpenAIImagesModel {}
+/// Models accepted by `POST /images/variations`.
+pub trait SupportsImageVariations: OpenAIImagesModel {}
+
+macro_rules! models {
+    ($($name:ident => $id:literal),+ $(,)?) => {$ (
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+        impl OpenAIImagesModel for $name { const ID: &'static str = $id; }
+        impl $name {
+            /// Start a compile-time checked Images configuration for this model.
+            pub fn config() -> super::ImageModelConfig<Self> {
+                super::ImageModelConfig::new()
+            }
+        }
+    )+ };
+}
+
+models! {
+    GptImage2 => "gpt-image-2",
+    GptImage1_5 => "gpt-image-1.5",
+    GptImage1 => "gpt-image-1",
+    GptImage1Mini => "gpt-image-1-mini",
+    DallE3 => "dall-e-3",
+    DallE2 => "dall-e-2",
+}
+
+pub const KNOWN_IMAGE_MODEL_IDS: &[&str] = &[
+    GptImage2::ID,
+    GptImage1_5::ID,
+    GptImage1::ID,
+    GptImage1Mini::ID,
+    DallE3::ID,
+    DallE2::ID,
+];
+
+// Capability evidence reviewed against the pinned `CreateImageRequest`,
+// `CreateImageEditRequest`, and `CreateImageVariationRequest` schemas in
+// `specs/openai/openapi.documented.yml`.
+
+macro_rules! impl_trait {
+    ($trait:ident: $($model:ty),+ $(,)?) => { $(impl $trait for $model {})+ };
+}
+
+macro_rules! associated {
+    ($trait:ident::$assoc:ident = $ty:ty: $($model:ty),+ $(,)?) => { $(
+        impl $trait for $model { type $assoc = $ty; }
+    )+ };
+}
+
+macro_rules! edit_limits {
+    ($limits:ident: $($model:ty),+ $(,)?) => { $(
+        impl SupportsImageEdits for $model {
+            const EDIT_INPUT_LIMITS: ImageInputLimits = ImageInputLimits::$limits;
+        }
+    )+ };
+}
+
+associated!(SupportsImageSize::Size = GptImage2Size: GptImage2);
+associated!(SupportsImageSize::Size = GptImageSize: GptImage1_5, GptImage1, GptImage1Mini);
+associated!(SupportsImageSize::Size = DallE3Size: DallE3);
+associated!(SupportsImageSize::Size = DallE2Size: DallE2);
+associated!(SupportsImageQuality::Quality = OpenAIImageQuality: GptImage2, GptImage1_5, GptImage1, GptImage1Mini);
+associated!(SupportsImageQuality::Quality = DallE3Quality: DallE3);
+impl_trait!(SupportsMultipleImages: GptImage2, GptImage1_5, GptImage1, GptImage1Mini, DallE2);
+impl_trait!(SupportsTransparentBackground: GptImage2, GptImage1_5, GptImage1, GptImage1Mini);
+impl_trait!(SupportsImageOutputFormat: GptImage2, GptImage1_5, GptImage1, GptImage1Mini);
+impl_trait!(SupportsImageModeration: GptImage2, GptImage1_5, GptImage1, GptImage1Mini);
+impl_trait!(SupportsImageResponseFormat: DallE3, DallE2);
+impl_trait!(SupportsImageStyle: DallE3);
+edit_limits!(GPT_IMAGE: GptImage2, GptImage1_5, GptImage1, GptImage1Mini);
+edit_limits!(DALL_E_2: DallE2);
+impl_trait!(SupportsInputFidelity: GptImage2, GptImage1_5, GptImage1);
+impl_trait!(SupportsImageVariations: DallE2);
+impl_trait!(SupportsImageStreaming: GptImage2, GptImage1_5, GptImage1, GptImage1Mini);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transparent<M: SupportsTransparentBackground>() {}
+    fn multiple<M: SupportsMultipleImages>() {}
+    fn urls<M: SupportsImageResponseFormat>() {}
+    fn fidelity<M: SupportsInputFidelity>() {}
+
+    #[test]
+    fn representative_positive_capability_bounds_compile() {
+        transparent::<GptImage2>();
+        transparent::<GptImage1Mini>();
+        multiple::<GptImage1_5>();
+        multiple::<DallE2>();
+        urls::<DallE3>();
+        fidelity::<GptImage1>();
+        assert_eq!(GptImage1Mini::EDIT_INPUT_LIMITS.max_images, 16);
+        assert_eq!(DallE2::EDIT_INPUT_LIMITS.max_images, 1);
+    }
+}
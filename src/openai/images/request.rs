@@ -0,0 +1,206 @@
+use reqwest::multipart::Form;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{
+    ImageInput, ImageInputError, ImageInputLimits, ImageModelConfig, ImageSettings,
+    OpenAIImagesModel, SupportsImageEdits, SupportsImageVariations,
+};
+
+/// Model-independent content of an image generation request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateImageRequest {
+    prompt: String,
+    user: Option<String>,
+}
+
+impl CreateImageRequest {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            user: None,
+        }
+    }
+
+    /// Stable end-user identifier used by OpenAI for abuse monitoring.
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    pub fn prompt(&self) -> &str {
+        &self.prompt
+    }
+}
+
+/// Private wire body for `POST /images/generations`.
+#[derive(Debug, Serialize)]
+pub(crate) struct ImageGenerationWireRequest {
+    prompt: String,
+    model: &'static str,
+    #[serde(flatten)]
+    settings: ImageSettings,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stream: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) partial_images: Option<u8>,
+}
+
+impl ImageGenerationWireRequest {
+    pub(crate) fn new<M: OpenAIImagesModel, Background>(
+        config: ImageModelConfig<M, Background>,
+        request: CreateImageRequest,
+    ) -> Self {
+        Self {
+            prompt: request.prompt,
+            model: M::ID,
+            settings: config.settings,
+            user: request.user,
+            stream: None,
+            partial_images: None,
+        }
+    }
+}
+
+/// Model-independent content of an image edit request.
+///
+/// When several images are provided, the mask applies to the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateImageEditRequest {
+    prompt: String,
+    images: Vec<ImageInput>,
+    mask: Option<ImageInput>,
+    user: Option<String>,
+}
+
+impl CreateImageEditRequest {
+    pub fn new(image: ImageInput, prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            images: vec![image],
+            mask: None,
+            user: None,
+        }
+    }
+
+    /// Add another reference image, for models that accept several.
+    pub fn image(mut self, image: ImageInput) -> Self {
+        self.images.push(image);
+        self
+    }
+
+    /// A PNG whose fully transparent areas mark where the first image is
+    /// edited. It must match that image's dimensions.
+    pub fn mask(mut self, mask: ImageInput) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Stable end-user identifier used by OpenAI for abuse monitoring.
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    pub fn prompt(&self) -> &str {
+        &self.prompt
+    }
+
+    pub fn images(&self) -> &[ImageInput] {
+        &self.images
+    }
+
+    pub(crate) fn into_form<M: SupportsImageEdits, Background>(
+        self,
+        config: ImageModelConfig<M, Background>,
+    ) -> Result<Form, ImageInputError> {
+        M::EDIT_INPUT_LIMITS.check(M::ID, &self.images)?;
+        if let Some(mask) = &self.mask {
+            mask.check_mask(&self.images[0])?;
+        }
+
+        let mut form = Form::new().text("model", M::ID).text("prompt", self.prompt);
+        form = settings_fields(form, &config.settings);
+        if let Some(fidelity) = config.input_fidelity {
+            form = text_field(form, "input_fidelity", &fidelity);
+        }
+        if let Some(user) = self.user {
+            form = form.text("user", user);
+        }
+        let field = if self.images.len() == 1 {
+            "image"
+        } else {
+            "image[]"
+        };
+        for image in self.images {
+            form = form.part(field, image.into_part());
+        }
+        if let Some(mask) = self.mask {
+            form = form.part("mask", mask.into_part());
+        }
+        Ok(form)
+    }
+}
+
+/// Model-independent content of an image variation request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateImageVariationRequest {
+    image: ImageInput,
+    user: Option<String>,
+}
+
+impl CreateImageVariationRequest {
+    pub fn new(image: ImageInput) -> Self {
+        Self { image, user: None }
+    }
+
+    /// Stable end-user identifier used by OpenAI for abuse monitoring.
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    pub fn image(&self) -> &ImageInput {
+        &self.image
+    }
+
+    pub(crate) fn into_form<M: SupportsImageVariations, Background>(
+        self,
+        config: ImageModelConfig<M, Background>,
+    ) -> Result<Form, ImageInputError> {
+        self.image.check(M::ID, &ImageInputLimits::DALL_E_2)?;
+        let mut form = settings_fields(Form::new().text("model", M::ID), &config.settings);
+        if let Some(user) = self.user {
+            form = form.text("user", user);
+        }
+        Ok(form.part("image", self.image.into_part()))
+    }
+}
+
+/// Adds each set setting as a text field, using its JSON wire value.
+/// `moderation` is generation-only and is never sent.
+fn settings_fields(form: Form, settings: &ImageSettings) -> Form {
+    let Ok(Value::Object(mut fields)) = serde_json::to_value(settings) else {
+        return form;
+    };
+    fields.remove("moderation");
+    fields.into_iter().fold(form, |form, (name, value)| {
+        form.text(name, form_value(value))
+    })
+}
+
+fn text_field(form: Form, name: &'static str, value: &impl Serialize) -> Form {
+    match serde_json::to_value(value) {
+        Ok(value) => form.text(name, form_value(value)),
+        Err(_) => form,
+    }
+}
+
+fn form_value(value: Value) -> String {
+    match value {
+        Value::String(value) => value,
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,541 @@
+#[cfg(feature = "stream")]
+use crate::{
+    core::sse,
+    error::AiProvider,
+    stream::{AiStream, SseJsonEvent},
+};
+use crate::{
+    error::{AiError, AiResponse, AiResult},
+    openai::{client::decode_openai_error, OpenAIClient},
+};
+
+#[cfg(feature = "stream")]
+use super::{ImageStreamEvent, PartialImageCount, SupportsImageStreaming};
+
+use super::{
+    CreateImageEditRequest, CreateImageRequest, CreateImageVariationRequest,
+    ImageGenerationWireRequest, ImageModelConfig, ImagesResponse, OpenAIImagesModel,
+    SupportsImageEdits, SupportsImageVariations,
+};
+
+/// OpenAI's standalone Images API.
+#[derive(Clone, Copy)]
+pub struct ImagesResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> ImagesResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    /// Generate images from a prompt.
+    pub async fn generate<M, Background>(
+        &self,
+        config: ImageModelConfig<M, Background>,
+        request: CreateImageRequest,
+    ) -> AiResult<AiResponse<ImagesResponse>>
+    where
+        M: OpenAIImagesModel,
+    {
+        let wire = ImageGenerationWireRequest::new(config, request);
+        self.client
+            .transport()
+            .post_json(
+                "images.generate",
+                "images/generations",
+                &wire,
+                decode_openai_error,
+            )
+            .await
+    }
+
+    /// Generate an image and stream `partial_images` progressive frames
+    /// before the final image.
+    #[cfg(feature = "stream")]
+    pub async fn generate_stream<M, Background>(
+        &self,
+        config: ImageModelConfig<M, Background>,
+        request: CreateImageRequest,
+        partial_images: PartialImageCount,
+    ) -> AiResult<AiResponse<AiStream<SseJsonEvent<ImageStreamEvent>>>>
+    where
+        M: SupportsImageStreaming,
+    {
+        let mut wire = ImageGenerationWireRequest::new(config, request);
+        wire.stream = Some(true);
+        wire.partial_images = Some(partial_images.get());
+        let response = self
+            .client
+            .transport()
+            .post_json_stream(
+                "images.generate_stream",
+                "images/generations",
+                &wire,
+                decode_openai_error,
+            )
+            .await?;
+        let (bytes, metadata) = response.into_parts();
+        Ok(AiResponse::new(
+            sse::json_events(bytes, AiProvider::OpenAI, "images.generate_stream"),
+            metadata,
+        ))
+    }
+
+    /// Edit or extend one or more images from a prompt.
+    ///
+    /// Inputs are checked against the model's limits before upload.
+    pub async fn edit<M, Background>(
+        &self,
+        config: ImageModelConfig<M, Background>,
+        request: CreateImageEditRequest,
+    ) -> AiResult<AiResponse<ImagesResponse>>
+    where
+        M: SupportsImageEdits,
+    {
+        let form = request
+            .into_form(config)
+            .map_err(|error| AiError::invalid_request("images.edit", error))?;
+        self.client
+            .transport()
+            .post_multipart("images.edit", "images/edits", form, decode_openai_error)
+            .await
+    }
+
+    /// Edit images and stream `partial_images` progressive frames before the
+    /// final image.
+    #[cfg(feature = "stream")]
+    pub async fn edit_stream<M, Background>(
+        &self,
+        config: ImageModelConfig<M, Background>,
+        request: CreateImageEditRequest,
+        partial_images: PartialImageCount,
+    ) -> AiResult<AiResponse<AiStream<SseJsonEvent<ImageStreamEvent>>>>
+    where
+        M: SupportsImageEdits + SupportsImageStreaming,
+    {
+        let form = request
+            .into_form(config)
+            .map_err(|error| AiError::invalid_request("images.edit_stream", error))?
+            .text("stream", "true")
+            .text("partial_images", partial_images.get().to_string());
+        let response = self
+            .client
+            .transport()
+            .post_multipart_stream(
+                "images.edit_stream",
+                "images/edits",
+                form,
+                decode_openai_error,
+            )
+            .await?;
+        let (bytes, metadata) = response.into_parts();
+        Ok(AiResponse::new(
+            sse::json_events(bytes, AiProvider::OpenAI, "images.edit_stream"),
+            metadata,
+        ))
+    }
+
+    /// Create variations of a square PNG image.
+    pub async fn create_variation<M, Background>(
+        &self,
+        config: ImageModelConfig<M, Background>,
+        request: CreateImageVariationRequest,
+    ) -> AiResult<AiResponse<ImagesResponse>>
+    where
+        M: SupportsImageVariations,
+    {
+        let form = request
+            .into_form(config)
+            .map_err(|error| AiError::invalid_request("images.create_variation", error))?;
+        self.client
+            .transport()
+            .post_multipart(
+                "images.create_variation",
+                "images/variations",
+                form,
+                decode_openai_error,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_support::{json_response, one_shot_server, openai_client, request_json},
+        openai::{
+            images::{
+                DallE2, DallE2Size, DallE3, DallE3Quality, DallE3Size, GptImage1, GptImage1Mini,
+                GptImage2, GptImage2Size, ImageCompression, ImageCount, ImageInput,
+                ImageInputError, ImageInputFormat, ImageModeration, ImageResponseFormat,
+                ImageStyle, ImageValueError, TransparentImageFormat,
+            },
+            responses::{OpenAIImageFormat, OpenAIImageInputFidelity, OpenAIImageQuality},
+        },
+    };
+
+    const GPT_IMAGE_RESPONSE: &str = r#"{
+        "created": 1713833628,
+        "data": [{"b64_json": "iVBORw=="}],
+        "background": "transparent",
+        "output_format": "webp",
+        "size": "1024x1536",
+        "quality": "high",
+        "usage": {
+            "total_tokens": 100,
+            "input_tokens": 50,
+            "output_tokens": 50,
+            "input_tokens_details": {"text_tokens": 10, "image_tokens": 40}
+        }
+    }"#;
+
+    #[tokio::test]
+    async fn generate_sends_gpt_image_settings_and_decodes_usage() {
+        let (base_url, wire) =
+            one_shot_server("v1", json_response("200 OK", &[], GPT_IMAGE_RESPONSE)).await;
+        let config = GptImage2::config()
+            .size(GptImage2Size::custom(1536, 864).unwrap())
+            .quality(OpenAIImageQuality::High)
+            .count(ImageCount::new(2).unwrap())
+            .moderation(ImageModeration::Low)
+            .output_compression(ImageCompression::new(80).unwrap())
+            .transparent_background(TransparentImageFormat::Webp);
+        let response = openai_client(base_url)
+            .images()
+            .generate(config, CreateImageRequest::new("a sticker").user("user-1"))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let wire = wire.await.unwrap();
+        assert!(wire.starts_with("POST /v1/images/generations HTTP/1.1\r\n"));
+        assert_eq!(
+            request_json(&wire),
+            serde_json::json!({
+                "prompt": "a sticker",
+                "model": "gpt-image-2",
+                "n": 2,
+                "size": "1536x864",
+                "quality": "high",
+                "background": "transparent",
+                "output_format": "webp",
+                "output_compression": 80,
+                "moderation": "low",
+                "user": "user-1"
+            })
+        );
+        assert_eq!(response.output_format, Some(OpenAIImageFormat::Webp));
+        assert_eq!(
+            response.data[0].decode_image().unwrap().unwrap(),
+            [0x89, b'P', b'N', b'G']
+        );
+        let usage = response.usage.unwrap();
+        assert_eq!(usage.total_tokens, 100);
+        assert_eq!(usage.input_tokens_details.unwrap().image_tokens, 40);
+    }
+
+    #[tokio::test]
+    async fn generate_sends_dalle_settings_and_decodes_url_results() {
+        let body = r#"{"created":1,"data":[{"url":"https://example.com/a.png","revised_prompt":"A cat."}]}"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], body)).await;
+        let config = DallE3::config()
+            .size(DallE3Size::Landscape1792x1024)
+            .quality(DallE3Quality::Hd)
+            .style(ImageStyle::Natural)
+            .response_format(ImageResponseFormat::Url);
+        let response = openai_client(base_url)
+            .images()
+            .generate(config, CreateImageRequest::new("a cat"))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({
+                "prompt": "a cat",
+                "model": "dall-e-3",
+                "size": "1792x1024",
+                "quality": "hd",
+                "response_format": "url",
+                "style": "natural"
+            })
+        );
+        assert_eq!(response.data[0].decode_image().unwrap(), None);
+        assert_eq!(response.data[0].revised_prompt.as_deref(), Some("A cat."));
+    }
+
+    #[test]
+    fn bounded_values_and_custom_sizes_are_validated() {
+        assert_eq!(ImageCount::new(0), Err(ImageValueError::Count));
+        assert_eq!(ImageCount::new(11), Err(ImageValueError::Count));
+        assert_eq!(
+            ImageCompression::new(101),
+            Err(ImageValueError::Compression)
+        );
+        assert!(GptImage2Size::custom(3840, 2160).is_ok());
+        for (width, height) in [(1000, 1000), (3840, 1024), (4096, 2048), (0, 16)] {
+            assert_eq!(
+                GptImage2Size::custom(width, height),
+                Err(ImageValueError::Size { width, height })
+            );
+        }
+        let config = GptImage1Mini::config().output_format(OpenAIImageFormat::Jpeg);
+        assert_eq!(config.model_id(), "gpt-image-1-mini");
+    }
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes
+    }
+
+    fn form_field(wire: &str, name: &str) -> String {
+        let header = format!("Content-Disposition: form-data; name=\"{name}\"\r\n\r\n");
+        let start = wire.find(&header).unwrap() + header.len();
+        wire[start..].split("\r\n").next().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn edit_uploads_multiple_images_mask_and_settings_as_multipart() {
+        let (base_url, wire) =
+            one_shot_server("v1", json_response("200 OK", &[], GPT_IMAGE_RESPONSE)).await;
+        let config = GptImage1::config()
+            .quality(OpenAIImageQuality::High)
+            .input_fidelity(OpenAIImageInputFidelity::High)
+            .moderation(ImageModeration::Low)
+            .transparent_background(TransparentImageFormat::Webp);
+        let request = CreateImageEditRequest::new(
+            ImageInput::from_bytes("base.png", png(64, 32)).unwrap(),
+            "add a hat",
+        )
+        .image(ImageInput::from_bytes("hat.jpg", [0xFF, 0xD8, 0xFF, 0xE0]).unwrap())
+        .mask(ImageInput::with_format("mask.png", ImageInputFormat::Png, png(64, 32)).unwrap())
+        .user("user-1");
+        openai_client(base_url)
+            .images()
+            .edit(config, request)
+            .await
+            .unwrap();
+
+        let wire = wire.await.unwrap();
+        assert!(wire.starts_with("POST /v1/images/edits HTTP/1.1\r\n"));
+        assert!(wire.contains("content-type: multipart/form-data; boundary="));
+        assert_eq!(form_field(&wire, "model"), "gpt-image-1");
+        assert_eq!(form_field(&wire, "prompt"), "add a hat");
+        assert_eq!(form_field(&wire, "quality"), "high");
+        assert_eq!(form_field(&wire, "background"), "transparent");
+        assert_eq!(form_field(&wire, "output_format"), "webp");
+        assert_eq!(form_field(&wire, "input_fidelity"), "high");
+        assert_eq!(form_field(&wire, "user"), "user-1");
+        assert!(!wire.contains("name=\"moderation\""));
+        assert!(wire.contains(
+            "name=\"image[]\"; filename=\"base.png\"\r\nContent-Type: image/png\r\n\r\n"
+        ));
+        assert!(wire.contains(
+            "name=\"image[]\"; filename=\"hat.jpg\"\r\nContent-Type: image/jpeg\r\n\r\n"
+        ));
+        assert!(wire.contains("name=\"mask\"; filename=\"mask.png\"\r\nContent-Type: image/png"));
+    }
+
+    #[tokio::test]
+    async fn create_variation_uploads_one_square_png() {
+        let body = r#"{"created":1,"data":[{"url":"https://example.com/a.png"}]}"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], body)).await;
+        let config = DallE2::config()
+            .size(DallE2Size::Square512)
+            .count(ImageCount::new(3).unwrap())
+            .response_format(ImageResponseFormat::Url);
+        let request =
+            CreateImageVariationRequest::new(ImageInput::from_bytes("cat.png", png(8, 8)).unwrap());
+        openai_client(base_url)
+            .images()
+            .create_variation(config, request)
+            .await
+            .unwrap();
+
+        let wire = wire.await.unwrap();
+        assert!(wire.starts_with("POST /v1/images/variations HTTP/1.1\r\n"));
+        assert_eq!(form_field(&wire, "model"), "dall-e-2");
+        assert_eq!(form_field(&wire, "n"), "3");
+        assert_eq!(form_field(&wire, "size"), "512x512");
+        assert_eq!(form_field(&wire, "response_format"), "url");
+        assert!(wire.contains("name=\"image\"; filename=\"cat.png\"\r\nContent-Type: image/png"));
+    }
+
+    #[tokio::test]
+    async fn invalid_inputs_are_rejected_before_upload() {
+        // Nothing listens here; every request below must fail locally.
+        let client = openai_client("http://127.0.0.1:9/v1".into());
+        let png = |name, width, height| ImageInput::from_bytes(name, png(width, height)).unwrap();
+        let jpeg = ImageInput::from_bytes("a.jpg", [0xFF, 0xD8, 0xFF, 0xE0]).unwrap();
+
+        let rejected = [
+            client
+                .images()
+                .edit(
+                    DallE2::config(),
+                    CreateImageEditRequest::new(jpeg.clone(), "x"),
+                )
+                .await,
+            client
+                .images()
+                .edit(
+                    DallE2::config(),
+                    CreateImageEditRequest::new(png("a.png", 8, 8), "x").image(png("b.png", 8, 8)),
+                )
+                .await,
+            client
+                .images()
+                .edit(
+                    GptImage1::config(),
+                    CreateImageEditRequest::new(png("a.png", 8, 8), "x").mask(jpeg.clone()),
+                )
+                .await,
+            client
+                .images()
+                .edit(
+                    GptImage1::config(),
+                    CreateImageEditRequest::new(png("a.png", 8, 8), "x").mask(png("m.png", 8, 4)),
+                )
+                .await,
+            client
+                .images()
+                .create_variation(
+                    DallE2::config(),
+                    CreateImageVariationRequest::new(png("a.png", 16, 8)),
+                )
+                .await,
+        ];
+        let reasons: Vec<_> = rejected
+            .into_iter()
+            .map(|result| match result {
+                Err(AiError::InvalidRequest { source, .. }) => {
+                    *source.downcast::<ImageInputError>().unwrap()
+                }
+                other => panic!("expected a local rejection, got {other:?}"),
+            })
+            .collect();
+        assert!(matches!(
+            reasons[..],
+            [
+                ImageInputError::UnsupportedFormat { .. },
+                ImageInputError::TooManyImages { .. },
+                ImageInputError::MaskNotPng { .. },
+                ImageInputError::MaskDimensions { .. },
+                ImageInputError::NotSquare { .. },
+            ]
+        ));
+
+        assert!(matches!(
+            ImageInput::from_bytes("notes.txt", b"hello".to_vec()),
+            Err(ImageInputError::UnrecognizedFormat { .. })
+        ));
+        assert!(matches!(
+            ImageInput::with_format("a.png", ImageInputFormat::Png, [0xFF, 0xD8, 0xFF]),
+            Err(ImageInputError::FormatMismatch { .. })
+        ));
+    }
+
+    #[cfg(feature = "stream")]
+    const STREAM_EVENTS: &str = concat!(
+        "event: image_generation.partial_image\n",
+        r#"data: {"type":"image_generation.partial_image","b64_json":"AQI=","created_at":1,"size":"1024x1024","quality":"low","background":"opaque","output_format":"webp","partial_image_index":0}"#,
+        "\n\n",
+        r#"data: {"type":"image_generation.heartbeat"}"#,
+        "\n\n",
+        "event: image_generation.completed\n",
+        r#"data: {"type":"image_generation.completed","b64_json":"iVBORw==","created_at":2,"size":"1024x1024","quality":"low","background":"opaque","output_format":"webp","usage":{"total_tokens":3,"input_tokens":1,"output_tokens":2}}"#,
+        "\n\n",
+    );
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn generate_stream_decodes_partial_and_completed_frames() {
+        use crate::{core::test_support::chunked_server, openai::images::write_image_frames};
+
+        let (base_url, wire) =
+            chunked_server("v1", &[], vec![STREAM_EVENTS.as_bytes().to_vec()]).await;
+        let mut events = openai_client(base_url)
+            .images()
+            .generate_stream(
+                GptImage1Mini::config(),
+                CreateImageRequest::new("a fox"),
+                PartialImageCount::new(2).unwrap(),
+            )
+            .await
+            .unwrap()
+            .into_inner();
+
+        let mut frames = Vec::new();
+        let completed = write_image_frames(&mut events, |frame| {
+            frames.push(frame);
+            std::future::ready(Ok(()))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({
+                "prompt": "a fox",
+                "model": "gpt-image-1-mini",
+                "stream": true,
+                "partial_images": 2
+            })
+        );
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].partial_image_index, Some(0));
+        assert_eq!(frames[0].data, [1, 2]);
+        assert_eq!(frames[0].extension(), "webp");
+        assert!(frames[1].is_final());
+        assert_eq!(frames[1].data, [0x89, b'P', b'N', b'G']);
+        assert_eq!(completed.usage.total_tokens, 3);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn edit_stream_adds_stream_fields_and_decodes_edit_events() {
+        use futures::StreamExt as _;
+
+        use crate::core::test_support::chunked_server;
+
+        let events = STREAM_EVENTS.replace("image_generation.", "image_edit.");
+        let (base_url, wire) = chunked_server("v1", &[], vec![events.into_bytes()]).await;
+        let request = CreateImageEditRequest::new(
+            ImageInput::from_bytes("base.png", png(8, 8)).unwrap(),
+            "add snow",
+        );
+        let events: Vec<_> = openai_client(base_url)
+            .images()
+            .edit_stream(
+                GptImage1::config(),
+                request,
+                PartialImageCount::new(1).unwrap(),
+            )
+            .await
+            .unwrap()
+            .into_inner()
+            .map(|event| event.unwrap().into_data())
+            .collect()
+            .await;
+
+        let wire = wire.await.unwrap();
+        assert!(wire.starts_with("POST /v1/images/edits HTTP/1.1\r\n"));
+        assert_eq!(form_field(&wire, "stream"), "true");
+        assert_eq!(form_field(&wire, "partial_images"), "1");
+        assert!(matches!(events[0], ImageStreamEvent::EditPartialImage(_)));
+        assert!(
+            matches!(&events[1], ImageStreamEvent::Unknown(raw) if raw.tag() == "image_edit.heartbeat")
+        );
+        assert_eq!(events[2].as_completed().unwrap().usage.output_tokens, 2);
+        assert_eq!(
+            PartialImageCount::new(4),
+            Err(ImageValueError::PartialImages)
+        );
+    }
+}
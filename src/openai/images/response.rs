@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::openai::responses::{OpenAIImageBackground, OpenAIImageFormat, OpenAIImageQuality};
+
+/// Response from the image generation, edit, and variation endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagesResponse {
+    pub created: u64,
+    #[serde(default)]
+    pub data: Vec<GeneratedImage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<OpenAIImageBackground>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<OpenAIImageFormat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality: Option<OpenAIImageQuality>,
+    /// Token usage, reported for the GPT image models.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ImagesUsage>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One generated image, returned as base64 data or as a URL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedImage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub b64_json: Option<String>,
+    /// Valid for 60 minutes. Only returned by the DALL·E models.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The prompt `dall-e-3` actually used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revised_prompt: Option<String>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl GeneratedImage {
+    /// Decoded image bytes, or `None` for URL results.
+    pub fn decode_image(&self) -> Result<Option<Vec<u8>>, base64::DecodeError> {
+        use base64::Engine as _;
+        self.b64_json
+            .as_ref()
+            .map(|data| base64::engine::general_purpose::STANDARD.decode(data))
+            .transpose()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagesUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub total_tokens: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_tokens_details: Option<ImageTokensDetails>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_tokens_details: Option<ImageTokensDetails>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ImageTokensDetails {
+    #[serde(default)]
+    pub text_tokens: u64,
+    #[serde(default)]
+    pub image_tokens: u64,
+}
@@ -0,0 +1,101 @@
+use std::{future::Future, io, path::Path};
+
+use futures::StreamExt as _;
+use tokio::io::AsyncWrite;
+
+use crate::{
+    openai::responses::OpenAIImageFormat,
+    stream::{AiStream, AiStreamError, SseJsonEvent},
+    utils::{save_with_extension, write_and_flush},
+};
+
+use super::{ImageCompletedEvent, ImageStreamEvent};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImageFrameError {
+    #[error(transparent)]
+    Stream(#[from] AiStreamError),
+    #[error("streamed image is not valid base64")]
+    InvalidBase64(#[from] base64::DecodeError),
+    #[error("image sink failed: {0}")]
+    Sink(#[from] io::Error),
+}
+
+/// One decoded frame of a streamed image: a partial image or the final one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageFrame {
+    /// `None` for the final image.
+    pub partial_image_index: Option<u64>,
+    pub output_format: OpenAIImageFormat,
+    /// Decoded image bytes.
+    pub data: Vec<u8>,
+}
+
+impl ImageFrame {
+    pub fn is_final(&self) -> bool {
+        self.partial_image_index.is_none()
+    }
+
+    /// File extension matching the output format, without the leading dot.
+    pub fn extension(&self) -> &'static str {
+        match self.output_format {
+            OpenAIImageFormat::Png => "png",
+            OpenAIImageFormat::Jpeg => "jpg",
+            OpenAIImageFormat::Webp => "webp",
+        }
+    }
+
+    /// Save the frame as `path` with a [`Self::extension`] extension and
+    /// return the path written.
+    pub async fn save(&self, path: impl AsRef<Path>) -> io::Result<std::path::PathBuf> {
+        save_with_extension(path.as_ref(), self.extension(), &self.data).await
+    }
+
+    /// Write [`Self::data`] to `writer`, then flush.
+    pub async fn write_to<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        write_and_flush(writer, &self.data).await
+    }
+}
+
+/// Decode every partial and final image in `events` and pass each to `sink`
+/// as it arrives, returning the completed event for its usage metadata.
+///
+/// Unknown events are skipped. Returns `None` if the stream ended without a
+/// completed event.
+pub async fn write_image_frames<F, Fut>(
+    events: &mut AiStream<SseJsonEvent<ImageStreamEvent>>,
+    mut sink: F,
+) -> Result<Option<ImageCompletedEvent>, ImageFrameError>
+where
+    F: FnMut(ImageFrame) -> Fut,
+    Fut: Future<Output = io::Result<()>>,
+{
+    while let Some(event) = events.next().await {
+        match event?.into_data() {
+            ImageStreamEvent::GenerationPartialImage(partial)
+            | ImageStreamEvent::EditPartialImage(partial) => {
+                sink(ImageFrame {
+                    partial_image_index: Some(partial.partial_image_index),
+                    output_format: partial.output_format,
+                    data: partial.decode_image()?,
+                })
+                .await?;
+            }
+            ImageStreamEvent::GenerationCompleted(completed)
+            | ImageStreamEvent::EditCompleted(completed) => {
+                sink(ImageFrame {
+                    partial_image_index: None,
+                    output_format: completed.output_format,
+                    data: completed.decode_image()?,
+                })
+                .await?;
+                return Ok(Some(completed));
+            }
+            ImageStreamEvent::Unknown(_) => {}
+        }
+    }
+    Ok(None)
+}
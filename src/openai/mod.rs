@@ -1,9 +1,19 @@
+pub mod audio;
+pub mod batches;
 mod client;
 pub mod conversations;
+pub mod embeddings;
+pub mod files;
+mod id;
+pub mod images;
 mod model;
+pub mod moderations;
 pub mod responses;
 mod types;
+pub mod uploads;
+pub mod vector_stores;
 
+pub use crate::core::poll::PollBackoff;
 pub use client::*;
 pub use model::*;
 pub use types::*;
@@ -0,0 +1,479 @@
+//! OpenAI's Moderations API for pre-screening text and images.
+
+use std::{collections::BTreeMap, fmt, marker::PhantomData};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::{
+    error::{AiResponse, AiResult},
+    openai::{client::decode_openai_error, OpenAIClient},
+};
+
+/// A model accepted by OpenAI's Moderations resource.
+///
+/// A moderation snapshot released after this crate can be used by
+/// implementing it; image inputs also need [`SupportsModerationImages`].
+pub trait OpenAIModerationModel: Send + Sync + 'static {
+    const ID: &'static str;
+}
+
+/// Moderation models that classify images as well as text.
+pub trait SupportsModerationImages: OpenAIModerationModel {}
+
+macro_rules! models {
+    ($($name:ident => $id:literal),+ $(,)?) => {$ (
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+        impl OpenAIModerationModel for $name { const ID: &'static str = $id; }
+    )+ };
+}
+
+models! {
+    OmniModerationLatest => "omni-moderation-latest",
+    OmniModeration20240926 => "omni-moderation-2024-09-26",
+    TextModerationLatest => "text-moderation-latest",
+    TextModerationStable => "text-moderation-stable",
+}
+
+pub const KNOWN_MODERATION_MODEL_IDS: &[&str] = &[
+    OmniModerationLatest::ID,
+    OmniModeration20240926::ID,
+    TextModerationLatest::ID,
+    TextModerationStable::ID,
+];
+
+impl SupportsModerationImages for OmniModerationLatest {}
+impl SupportsModerationImages for OmniModeration20240926 {}
+
+/// Request state for text-only inputs, which every moderation model accepts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextInputs;
+
+/// Request state after adding an image, which only omni models accept.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MultiModalInputs;
+
+/// Models that accept a request in the given input state.
+pub trait ModerationModelFor<Inputs>: OpenAIModerationModel {}
+
+impl<M: OpenAIModerationModel> ModerationModelFor<TextInputs> for M {}
+impl<M: SupportsModerationImages> ModerationModelFor<MultiModalInputs> for M {}
+
+/// One piece of content to classify.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ModerationInput {
+    Text { text: String },
+    ImageUrl { image_url: ModerationImageUrl },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModerationImageUrl {
+    /// An image URL or a `data:` URL with base64 image data.
+    pub url: String,
+}
+
+/// Model-independent content of a moderation request.
+///
+/// Adding an image moves the request to [`MultiModalInputs`], so it can only
+/// be sent to a model that classifies images.
+pub struct CreateModerationRequest<Inputs = TextInputs> {
+    input: Vec<ModerationInput>,
+    state: PhantomData<fn() -> Inputs>,
+}
+
+impl<Inputs> Clone for CreateModerationRequest<Inputs> {
+    fn clone(&self) -> Self {
+        Self {
+            input: self.input.clone(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<Inputs> fmt::Debug for CreateModerationRequest<Inputs> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateModerationRequest")
+            .field("input", &self.input)
+            .finish()
+    }
+}
+
+impl CreateModerationRequest<TextInputs> {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::texts([text])
+    }
+
+    /// Several texts, classified independently with one result each.
+    pub fn texts<I>(texts: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            input: texts
+                .into_iter()
+                .map(|text| ModerationInput::Text { text: text.into() })
+                .collect(),
+            state: PhantomData,
+        }
+    }
+
+    /// Start from an image alone.
+    pub fn image_only(url: impl Into<String>) -> CreateModerationRequest<MultiModalInputs> {
+        Self::texts(Vec::<String>::new()).image_url(url)
+    }
+}
+
+impl<Inputs> CreateModerationRequest<Inputs> {
+    pub fn add_text(mut self, text: impl Into<String>) -> Self {
+        self.input.push(ModerationInput::Text { text: text.into() });
+        self
+    }
+
+    /// Add an image by URL or `data:` URL.
+    pub fn image_url(
+        mut self,
+        url: impl Into<String>,
+    ) -> CreateModerationRequest<MultiModalInputs> {
+        self.input.push(ModerationInput::ImageUrl {
+            image_url: ModerationImageUrl { url: url.into() },
+        });
+        CreateModerationRequest {
+            input: self.input,
+            state: PhantomData,
+        }
+    }
+
+    /// Add image bytes, sent inline as a base64 `data:` URL.
+    pub fn image_data(
+        self,
+        mime_type: &str,
+        data: &[u8],
+    ) -> CreateModerationRequest<MultiModalInputs> {
+        use base64::Engine as _;
+        let data = base64::engine::general_purpose::STANDARD.encode(data);
+        self.image_url(format!("data:{mime_type};base64,{data}"))
+    }
+
+    pub fn input(&self) -> &[ModerationInput] {
+        &self.input
+    }
+}
+
+/// Private wire body for `POST /moderations`. Text-only requests use the
+/// plain string forms that every moderation model accepts.
+#[derive(Debug, Serialize)]
+struct ModerationWireRequest {
+    input: ModerationWireInput,
+    model: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum ModerationWireInput {
+    Text(String),
+    Texts(Vec<String>),
+    Parts(Vec<ModerationInput>),
+}
+
+impl ModerationWireRequest {
+    fn new<M: OpenAIModerationModel, Inputs>(request: CreateModerationRequest<Inputs>) -> Self {
+        let texts: Option<Vec<String>> = request
+            .input
+            .iter()
+            .map(|input| match input {
+                ModerationInput::Text { text } => Some(text.clone()),
+                ModerationInput::ImageUrl { .. } => None,
+            })
+            .collect();
+        let input = match texts {
+            Some(mut texts) if texts.len() == 1 => ModerationWireInput::Text(texts.remove(0)),
+            Some(texts) => ModerationWireInput::Texts(texts),
+            None => ModerationWireInput::Parts(request.input),
+        };
+        Self {
+            input,
+            model: M::ID,
+        }
+    }
+}
+
+/// Response from `POST /moderations`, with one result per input.
+///
+/// Multi-modal inputs produce a single result for the combined content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationResponse {
+    pub id: String,
+    pub model: String,
+    pub results: Vec<ModerationResult>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ModerationResponse {
+    /// Whether any result was flagged.
+    pub fn flagged(&self) -> bool {
+        self.results.iter().any(|result| result.flagged)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationResult {
+    pub flagged: bool,
+    pub categories: ModerationCategories<bool>,
+    pub category_scores: ModerationCategories<f64>,
+    /// Which input types contributed to each category's score. Only omni
+    /// models report this.
+    #[serde(default)]
+    pub category_applied_input_types: ModerationCategories<Vec<ModerationInputType>>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ModerationResult {
+    /// Names of the flagged categories, including unknown ones.
+    pub fn flagged_categories(&self) -> impl Iterator<Item = &str> {
+        self.categories
+            .iter()
+            .filter(|(_, flagged)| *flagged == Some(&true))
+            .map(|(name, _)| name)
+    }
+}
+
+macro_rules! categories {
+    ($($(#[$meta:meta])* $field:ident => $name:literal),+ $(,)?) => {
+        /// One value per moderation category.
+        ///
+        /// Categories added after this crate was released are kept in
+        /// `other` under their wire names. `None` is a category the model
+        /// reported as `null` or left out, as older models do for categories
+        /// they do not score; it serializes back as `null`.
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        #[serde(bound(deserialize = "T: Deserialize<'de>"))]
+        pub struct ModerationCategories<T> {
+            $(
+                $(#[$meta])*
+                #[serde(rename = $name, default)]
+                pub $field: Option<T>,
+            )+
+            #[serde(flatten)]
+            pub other: BTreeMap<String, Option<T>>,
+        }
+
+        impl<T> Default for ModerationCategories<T> {
+            fn default() -> Self {
+                Self {
+                    $($field: None,)+
+                    other: BTreeMap::new(),
+                }
+            }
+        }
+
+        impl<T> ModerationCategories<T> {
+            /// Every category as `(wire name, value)`, known ones first.
+            pub fn iter(&self) -> impl Iterator<Item = (&str, Option<&T>)> {
+                [$(($name, self.$field.as_ref())),+]
+                    .into_iter()
+                    .chain(
+                        self.other
+                            .iter()
+                            .map(|(name, value)| (name.as_str(), value.as_ref())),
+                    )
+            }
+        }
+    };
+}
+
+categories! {
+    hate => "hate",
+    hate_threatening => "hate/threatening",
+    harassment => "harassment",
+    harassment_threatening => "harassment/threatening",
+    /// Not scored by the legacy text-moderation models.
+    illicit => "illicit",
+    /// Not scored by the legacy text-moderation models.
+    illicit_violent => "illicit/violent",
+    self_harm => "self-harm",
+    self_harm_intent => "self-harm/intent",
+    self_harm_instructions => "self-harm/instructions",
+    sexual => "sexual",
+    sexual_minors => "sexual/minors",
+    violence => "violence",
+    violence_graphic => "violence/graphic",
+}
+
+/// An input type that contributed to a category score.
+///
+/// `Other` carries types added after this crate was released.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModerationInputType {
+    Text,
+    Image,
+    Other(String),
+}
+
+impl ModerationInputType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Text => "text",
+            Self::Image => "image",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl Serialize for ModerationInputType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ModerationInputType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match String::deserialize(deserializer)?.as_str() {
+            "text" => Self::Text,
+            "image" => Self::Image,
+            other => Self::Other(other.to_owned()),
+        })
+    }
+}
+
+/// OpenAI's Moderations API.
+#[derive(Clone, Copy)]
+pub struct ModerationsResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> ModerationsResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    /// Classify text and, with an omni model, images.
+    pub async fn create<M, Inputs>(
+        &self,
+        _model: M,
+        request: CreateModerationRequest<Inputs>,
+    ) -> AiResult<AiResponse<ModerationResponse>>
+    where
+        M: ModerationModelFor<Inputs>,
+    {
+        let wire = ModerationWireRequest::new::<M, Inputs>(request);
+        self.client
+            .transport()
+            .post_json(
+                "moderations.create",
+                "moderations",
+                &wire,
+                decode_openai_error,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_support::{json_response, one_shot_server, openai_client, request_json};
+
+    #[tokio::test]
+    async fn create_sends_multimodal_input_and_decodes_categories_losslessly() {
+        let body = r#"{
+            "id": "modr-1",
+            "model": "omni-moderation-latest",
+            "results": [{
+                "flagged": true,
+                "categories": {
+                    "hate": false, "hate/threatening": false, "harassment": false,
+                    "harassment/threatening": false, "illicit": false,
+                    "illicit/violent": false, "self-harm": false,
+                    "self-harm/intent": false, "self-harm/instructions": false,
+                    "sexual": false, "sexual/minors": false, "violence": true,
+                    "violence/graphic": false, "weapons/future": true
+                },
+                "category_scores": {
+                    "hate": 0.01, "hate/threatening": 0.0, "harassment": 0.0,
+                    "harassment/threatening": 0.0, "illicit": 0.0,
+                    "illicit/violent": 0.0, "self-harm": 0.0,
+                    "self-harm/intent": 0.0, "self-harm/instructions": 0.0,
+                    "sexual": 0.0, "sexual/minors": 0.0, "violence": 0.92,
+                    "violence/graphic": 0.1, "weapons/future": 0.7
+                },
+                "category_applied_input_types": {
+                    "hate": ["text"], "violence": ["text", "image"],
+                    "weapons/future": ["video"]
+                }
+            }]
+        }"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], body)).await;
+        let request = CreateModerationRequest::text("caption")
+            .image_url("https://example.com/a.png")
+            .image_data("image/png", &[1, 2, 3]);
+        let response = openai_client(base_url)
+            .moderations()
+            .create(OmniModerationLatest, request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({
+                "input": [
+                    {"type": "text", "text": "caption"},
+                    {"type": "image_url", "image_url": {"url": "https://example.com/a.png"}},
+                    {"type": "image_url", "image_url": {"url": "data:image/png;base64,AQID"}}
+                ],
+                "model": "omni-moderation-latest"
+            })
+        );
+        assert!(response.flagged());
+        let result = &response.results[0];
+        assert_eq!(
+            result.flagged_categories().collect::<Vec<_>>(),
+            ["violence", "weapons/future"]
+        );
+        assert_eq!(result.category_scores.violence, Some(0.92));
+        assert_eq!(result.category_scores.other["weapons/future"], Some(0.7));
+        assert_eq!(
+            result.category_applied_input_types.violence,
+            Some(vec![ModerationInputType::Text, ModerationInputType::Image])
+        );
+        assert_eq!(
+            result.category_applied_input_types.other["weapons/future"],
+            Some(vec![ModerationInputType::Other("video".into())])
+        );
+        let round_trip = serde_json::to_value(&result.categories).unwrap();
+        assert_eq!(round_trip["weapons/future"], true);
+    }
+
+    #[tokio::test]
+    async fn text_requests_use_string_input_and_accept_null_legacy_categories() {
+        let body = r#"{"id":"modr-2","model":"text-moderation-007","results":[{"flagged":false,"categories":{"hate":false,"illicit":null},"category_scores":{"hate":0.02,"illicit":null}}]}"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], body)).await;
+        let response = openai_client(base_url)
+            .moderations()
+            .create(
+                TextModerationStable,
+                CreateModerationRequest::texts(["first", "second"]),
+            )
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({"input": ["first", "second"], "model": "text-moderation-stable"})
+        );
+        let result = &response.results[0];
+        assert_eq!(result.categories.hate, Some(false));
+        assert_eq!(result.categories.illicit, None);
+        assert_eq!(result.category_scores.hate, Some(0.02));
+        assert_eq!(result.category_applied_input_types.hate, None);
+        let round_trip = serde_json::to_value(&result.category_scores).unwrap();
+        assert_eq!(round_trip["hate"], 0.02);
+        assert!(round_trip["illicit"].is_null());
+    }
+}
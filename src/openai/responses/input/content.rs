@@ -5,6 +5,7 @@ use serde_json::Map;
 
 use super::super::tagged::ExtraFields;
 use super::items::OpenAIResponseInputItem;
+use crate::openai::files::FileId;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAIPromptCacheBreakpoint {
@@ -172,6 +173,14 @@ pub enum OpenAIImageSource {
     File { file_id: String },
 }
 
+impl From<FileId> for OpenAIImageSource {
+    fn from(file_id: FileId) -> Self {
+        Self::File {
+            file_id: file_id.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OpenAIFileSource {
@@ -180,6 +189,14 @@ pub enum OpenAIFileSource {
     Url { file_url: String },
 }
 
+impl From<FileId> for OpenAIFileSource {
+    fn from(file_id: FileId) -> Self {
+        Self::File {
+            file_id: file_id.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum OpenAIImageDetail {
@@ -232,6 +249,27 @@ impl OpenAIResponsesInputContentPart {
             extra: Map::new(),
         })
     }
+
+    /// Reference an uploaded image by its file ID.
+    pub fn image_file(file_id: FileId, detail: OpenAIImageDetail) -> Self {
+        Self::InputImage(OpenAIInputImageContent {
+            detail,
+            source: file_id.into(),
+            prompt_cache_breakpoint: None,
+            extra: Map::new(),
+        })
+    }
+
+    /// Reference an uploaded document by its file ID.
+    pub fn file(file_id: FileId) -> Self {
+        Self::InputFile(OpenAIInputFileContent {
+            source: file_id.into(),
+            filename: None,
+            detail: None,
+            prompt_cache_breakpoint: None,
+            extra: Map::new(),
+        })
+    }
 }
 
 #[cfg(test)]
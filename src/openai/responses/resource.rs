@@ -166,7 +166,7 @@ impl<'a> ResponsesResource<'a> {
         let response = self
             .client
             .transport()
-            .get_json_stream_segments(
+            .get_stream_segments(
                 "responses.retrieve_stream",
                 &["responses", response_id.as_str()],
                 &options.query(),
@@ -261,7 +261,7 @@ impl<'a> ResponsesResource<'a> {
 mod tests {
     use super::*;
     use crate::{
-        core::test_support::{json_response, one_shot_server},
+        core::test_support::{json_response, one_shot_server, openai_client, request_json},
         openai::{
             responses::{
                 CreateResponseRequest, ExtendedReasoningEffort, Gpt4oMini, Gpt5_2, ListOrder,
@@ -279,22 +279,10 @@ mod tests {
         )
     }
 
-    fn test_client(base_url: String) -> OpenAIClient {
-        OpenAIClient::builder()
-            .api_key("test-key".into())
-            .base_url(base_url)
-            .build()
-            .unwrap()
-    }
-
-    fn request_json(request: &str) -> serde_json::Value {
-        serde_json::from_str(request.split_once("\r\n\r\n").unwrap().1).unwrap()
-    }
-
     #[tokio::test]
     async fn create_owns_non_stream_wire_mode() {
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let request = CreateResponseRequest::builder().input_text("hello").build();
         let _ = client
             .responses()
@@ -312,7 +300,7 @@ mod tests {
     #[tokio::test]
     async fn create_combines_request_with_typed_model_config() {
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let request = CreateResponseRequest::builder()
             .input_text("private input")
             .prompt_cache_key("cache-key")
@@ -341,7 +329,7 @@ mod tests {
     #[tokio::test]
     async fn create_stream_owns_stream_wire_mode() {
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let request = CreateResponseRequest::builder().input_text("hello").build();
         let _ = client
             .responses()
@@ -359,7 +347,7 @@ mod tests {
     #[tokio::test]
     async fn create_stream_with_options_sends_obfuscation_setting() {
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let options = CreateResponseStreamOptions::new().include_obfuscation(false);
         let request = CreateResponseRequest::builder().input_text("hello").build();
         let _ = client
@@ -375,7 +363,7 @@ mod tests {
     #[tokio::test]
     async fn retrieve_encodes_id_and_repeated_includes() {
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let id = ResponseId::new("resp/a?b").unwrap();
         let options = RetrieveResponseOptions::new()
             .include(ResponseInclude::ReasoningEncryptedContent)
@@ -394,7 +382,7 @@ mod tests {
     #[tokio::test]
     async fn retrieve_stream_encodes_resume_and_obfuscation_options() {
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let id = ResponseId::new("resp_123").unwrap();
         let options = RetrieveResponseStreamOptions::new()
             .starting_after(7)
@@ -411,14 +399,14 @@ mod tests {
         let id = ResponseId::new("resp/a").unwrap();
 
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let _ = client.responses().delete(&id).await;
         let wire = wire.await.unwrap();
         assert!(wire.starts_with("DELETE /v1/responses/resp%2Fa HTTP/1.1\r\n"));
         assert!(wire.ends_with("\r\n\r\n"));
 
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let _ = client.responses().cancel(&id).await;
         let wire = wire.await.unwrap();
         assert!(wire.starts_with("POST /v1/responses/resp%2Fa/cancel HTTP/1.1\r\n"));
@@ -428,7 +416,7 @@ mod tests {
     #[tokio::test]
     async fn input_item_list_encodes_pagination_and_include() {
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let id = ResponseId::new("resp_123").unwrap();
         let options = ListResponseInputItemsOptions::new()
             .limit(100)
@@ -446,7 +434,7 @@ mod tests {
     #[tokio::test]
     async fn token_count_and_compact_use_exact_paths_and_bodies() {
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let count = OpenAIInputTokenCountRequest {
             model: Some("gpt-5".into()),
             input: Some(OpenAIResponsesInput::Text("hello".into())),
@@ -461,7 +449,7 @@ mod tests {
         );
 
         let (base_url, wire) = one_shot_server("v1", error_response()).await;
-        let client = test_client(base_url);
+        let client = openai_client(base_url);
         let compact = OpenAICompactRequest::new("gpt-5.1-codex-max");
         let _ = client.responses().compact(&compact).await;
         let wire = wire.await.unwrap();
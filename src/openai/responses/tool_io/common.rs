@@ -23,6 +23,30 @@ pub enum OpenAIVectorStoreAttributeValue {
     Boolean(bool),
 }
 
+impl From<String> for OpenAIVectorStoreAttributeValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for OpenAIVectorStoreAttributeValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl From<f64> for OpenAIVectorStoreAttributeValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<bool> for OpenAIVectorStoreAttributeValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
 pub type OpenAIVectorStoreFileAttributes = BTreeMap<String, OpenAIVectorStoreAttributeValue>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,716 @@
+//! OpenAI's Uploads API for files too large for a single Files request.
+//!
+//! An upload is created with the final size and MIME type, filled with parts
+//! of at most 64 MB, and completed with the part IDs in order, which turns it
+//! into an ordinary [`crate::openai::files::OpenAIFile`].
+
+#[cfg(feature = "stream")]
+use std::{io::SeekFrom, path::Path, time::Duration};
+
+use bytes::Bytes;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+#[cfg(feature = "stream")]
+use crate::{core::poll::PollBackoff, error::ResponseMetadata, openai::files::FileId};
+use crate::{
+    error::{AiError, AiResponse, AiResult},
+    openai::{
+        client::decode_openai_error,
+        files::{FileExpiration, FilePurpose, FileUploadPurpose, OpenAIFile},
+        id::validated_id,
+        OpenAIClient,
+    },
+};
+
+const MAX_PART_BYTES: usize = 64 * 1024 * 1024;
+const MAX_UPLOAD_BYTES: u64 = 8 * 1024 * 1024 * 1024;
+#[cfg(feature = "stream")]
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum UploadError {
+    #[error("upload ID must be non-empty and contain no whitespace or control characters")]
+    InvalidUploadId,
+    #[error("upload part ID must be non-empty and contain no whitespace or control characters")]
+    InvalidUploadPartId,
+    #[error("uploads must be between 1 byte and 8 GB, got {0} bytes")]
+    InvalidSize(u64),
+    #[error("upload parts must be between 1 byte and 64 MB, got {0} bytes")]
+    InvalidPartSize(usize),
+    #[error("upload concurrency must be at least 1")]
+    InvalidConcurrency,
+    #[error("each part must be attempted at least once")]
+    InvalidAttempts,
+}
+
+validated_id!(
+    /// A validated upload ID such as `upload_abc123`.
+    UploadId,
+    UploadError::InvalidUploadId
+);
+validated_id!(
+    /// A validated upload part ID such as `part_def456`.
+    UploadPartId,
+    UploadError::InvalidUploadPartId
+);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreateUploadRequest {
+    filename: String,
+    bytes: u64,
+    mime_type: String,
+    purpose: FileUploadPurpose,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_after: Option<FileExpiration>,
+}
+
+impl CreateUploadRequest {
+    /// Describe the file the parts will add up to. `bytes` is its exact total
+    /// size, at most 8 GB.
+    pub fn new(
+        filename: impl Into<String>,
+        bytes: u64,
+        mime_type: impl Into<String>,
+        purpose: FileUploadPurpose,
+    ) -> Result<Self, UploadError> {
+        if !(1..=MAX_UPLOAD_BYTES).contains(&bytes) {
+            return Err(UploadError::InvalidSize(bytes));
+        }
+        Ok(Self {
+            filename: filename.into(),
+            bytes,
+            mime_type: mime_type.into(),
+            purpose,
+            expires_after: None,
+        })
+    }
+
+    /// Expiry for the file the completed upload creates.
+    pub fn expires_after(mut self, expiration: FileExpiration) -> Self {
+        self.expires_after = Some(expiration);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CompleteUploadRequest {
+    part_ids: Vec<UploadPartId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    md5: Option<String>,
+}
+
+impl CompleteUploadRequest {
+    /// Complete with the parts in the order they make up the file.
+    pub fn new(part_ids: Vec<UploadPartId>) -> Self {
+        Self {
+            part_ids,
+            md5: None,
+        }
+    }
+
+    /// Hex MD5 of the whole file, checked by OpenAI against the assembled parts.
+    pub fn md5(mut self, md5: impl Into<String>) -> Self {
+        self.md5 = Some(md5.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UploadStatus {
+    Pending,
+    Completed,
+    Cancelled,
+    Expired,
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for UploadStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "pending" => Self::Pending,
+            "completed" => Self::Completed,
+            "cancelled" => Self::Cancelled,
+            "expired" => Self::Expired,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Upload {
+    pub id: UploadId,
+    pub object: String,
+    pub bytes: u64,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub filename: String,
+    pub purpose: FilePurpose,
+    pub status: UploadStatus,
+    /// The file created by completing the upload.
+    #[serde(default)]
+    pub file: Option<OpenAIFile>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UploadPart {
+    pub id: UploadPartId,
+    pub object: String,
+    pub created_at: u64,
+    pub upload_id: UploadId,
+}
+
+/// Tuning for [`UploadsResource::upload_large_file`].
+#[cfg(feature = "stream")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LargeFileUploadOptions {
+    part_size: usize,
+    concurrency: usize,
+    max_attempts: u32,
+    retry_delay: Duration,
+    mime_type: Option<String>,
+    expires_after: Option<FileExpiration>,
+}
+
+#[cfg(feature = "stream")]
+impl Default for LargeFileUploadOptions {
+    fn default() -> Self {
+        Self {
+            part_size: MAX_PART_BYTES,
+            concurrency: 4,
+            max_attempts: 3,
+            retry_delay: Duration::from_millis(500),
+            mime_type: None,
+            expires_after: None,
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+impl LargeFileUploadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bytes per part, at most 64 MB. Defaults to 64 MB.
+    pub fn part_size(mut self, part_size: usize) -> Result<Self, UploadError> {
+        if !(1..=MAX_PART_BYTES).contains(&part_size) {
+            return Err(UploadError::InvalidPartSize(part_size));
+        }
+        self.part_size = part_size;
+        Ok(self)
+    }
+
+    /// Parts read and sent at once. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Result<Self, UploadError> {
+        if concurrency == 0 {
+            return Err(UploadError::InvalidConcurrency);
+        }
+        self.concurrency = concurrency;
+        Ok(self)
+    }
+
+    /// Attempts per part before the upload is cancelled. Defaults to 3.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Result<Self, UploadError> {
+        if max_attempts == 0 {
+            return Err(UploadError::InvalidAttempts);
+        }
+        self.max_attempts = max_attempts;
+        Ok(self)
+    }
+
+    /// Delay before the first retry of a part; it doubles on each further
+    /// retry, up to 30 seconds. A rate limit with a `Retry-After` in seconds
+    /// waits that long instead.
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Defaults to a guess from the file extension.
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    pub fn expires_after(mut self, expiration: FileExpiration) -> Self {
+        self.expires_after = Some(expiration);
+        self
+    }
+}
+
+/// Reported after each part of a large upload is accepted.
+#[cfg(feature = "stream")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    pub bytes_uploaded: u64,
+    pub total_bytes: u64,
+    pub parts_uploaded: usize,
+    pub total_parts: usize,
+}
+
+#[cfg(feature = "stream")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum LargeUploadError {
+    #[error("failed to read file for upload: {0}")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Invalid(#[from] UploadError),
+    #[error(transparent)]
+    Api(#[from] AiError),
+}
+
+#[derive(Clone, Copy)]
+pub struct UploadsResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> UploadsResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create(&self, request: CreateUploadRequest) -> AiResult<AiResponse<Upload>> {
+        self.client
+            .transport()
+            .post_json("uploads.create", "uploads", &request, decode_openai_error)
+            .await
+    }
+
+    /// Add one part of at most 64 MB. Parts may be added in parallel; their
+    /// order is fixed only when the upload is completed.
+    ///
+    /// The part is sent from a [`Bytes`] buffer, so retrying with a clone of
+    /// it does not copy the data.
+    pub async fn add_part(
+        &self,
+        upload_id: &UploadId,
+        data: impl Into<Bytes>,
+    ) -> AiResult<AiResponse<UploadPart>> {
+        let data = data.into();
+        if !(1..=MAX_PART_BYTES).contains(&data.len()) {
+            return Err(AiError::invalid_request(
+                "uploads.add_part",
+                UploadError::InvalidPartSize(data.len()),
+            ));
+        }
+        let length = data.len() as u64;
+        let form = reqwest::multipart::Form::new().part(
+            "data",
+            reqwest::multipart::Part::stream_with_length(data, length).file_name("part"),
+        );
+        self.client
+            .transport()
+            .post_multipart_segments(
+                "uploads.add_part",
+                &["uploads", upload_id.as_str(), "parts"],
+                form,
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn complete(
+        &self,
+        upload_id: &UploadId,
+        request: CompleteUploadRequest,
+    ) -> AiResult<AiResponse<Upload>> {
+        self.client
+            .transport()
+            .post_json_segments(
+                "uploads.complete",
+                &["uploads", upload_id.as_str(), "complete"],
+                &request,
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn cancel(&self, upload_id: &UploadId) -> AiResult<AiResponse<Upload>> {
+        self.client
+            .transport()
+            .post_empty_segments(
+                "uploads.cancel",
+                &["uploads", upload_id.as_str(), "cancel"],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    /// Upload a file from disk through the Uploads API and return the ID of
+    /// the resulting file.
+    ///
+    /// The file is read part by part, so at most `concurrency` parts are held
+    /// in memory at once. Parts that fail with a timeout, transport error,
+    /// rate limit, or server error are retried with capped exponential
+    /// backoff; any other failure, including a failure to complete the
+    /// upload, cancels it. `on_progress` runs after each part is accepted.
+    #[cfg(feature = "stream")]
+    pub async fn upload_large_file<F>(
+        &self,
+        path: impl AsRef<Path>,
+        purpose: FileUploadPurpose,
+        options: LargeFileUploadOptions,
+        mut on_progress: F,
+    ) -> Result<FileId, LargeUploadError>
+    where
+        F: FnMut(UploadProgress),
+    {
+        use futures::{StreamExt as _, TryStreamExt as _};
+
+        let path = path.as_ref();
+        let total_bytes = tokio::fs::metadata(path).await?.len();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "file".to_string());
+        let mime_type = options
+            .mime_type
+            .clone()
+            .unwrap_or_else(|| guess_mime_type(path).to_string());
+        let mut request = CreateUploadRequest::new(filename, total_bytes, mime_type, purpose)?;
+        if let Some(expiration) = options.expires_after {
+            request = request.expires_after(expiration);
+        }
+        let upload = self.create(request).await?.into_inner();
+
+        let part_size = options.part_size as u64;
+        let total_parts = total_bytes.div_ceil(part_size) as usize;
+        let mut part_ids = vec![None; total_parts];
+        let mut progress = UploadProgress {
+            bytes_uploaded: 0,
+            total_bytes,
+            parts_uploaded: 0,
+            total_parts,
+        };
+        let mut parts = futures::stream::iter(0..total_parts)
+            .map(|index| {
+                let offset = index as u64 * part_size;
+                let length = part_size.min(total_bytes - offset);
+                let options = &options;
+                let upload_id = &upload.id;
+                async move {
+                    let data = read_part(path, offset, length as usize).await?;
+                    let part = self.add_part_with_retry(upload_id, data, options).await?;
+                    Ok::<_, LargeUploadError>((index, length, part))
+                }
+            })
+            .buffer_unordered(options.concurrency);
+        let result = loop {
+            match parts.try_next().await {
+                Ok(Some((index, length, part))) => {
+                    part_ids[index] = Some(part.id);
+                    progress.bytes_uploaded += length;
+                    progress.parts_uploaded += 1;
+                    on_progress(progress);
+                }
+                Ok(None) => break Ok(()),
+                Err(error) => break Err(error),
+            }
+        };
+        drop(parts);
+        if let Err(error) = result {
+            // Best effort: the original failure matters more than a failed cancel.
+            let _ = self.cancel(&upload.id).await;
+            return Err(error);
+        }
+
+        let part_ids = part_ids.into_iter().flatten().collect();
+        let completed = self
+            .client
+            .transport()
+            .post_json_segments::<_, CompletedUpload, _>(
+                "uploads.complete",
+                &["uploads", upload.id.as_str(), "complete"],
+                &CompleteUploadRequest::new(part_ids),
+                decode_openai_error,
+            )
+            .await;
+        match completed {
+            Ok(completed) => Ok(completed.into_inner().file.id),
+            Err(error) => {
+                let _ = self.cancel(&upload.id).await;
+                Err(error.into())
+            }
+        }
+    }
+
+    #[cfg(feature = "stream")]
+    async fn add_part_with_retry(
+        &self,
+        upload_id: &UploadId,
+        data: Bytes,
+        options: &LargeFileUploadOptions,
+    ) -> AiResult<UploadPart> {
+        let backoff = PollBackoff {
+            initial_delay: options.retry_delay,
+            max_delay: MAX_RETRY_DELAY.max(options.retry_delay),
+            ..PollBackoff::default()
+        };
+        let mut delay = backoff.initial_delay;
+        let mut attempt = 1;
+        loop {
+            match self.add_part(upload_id, data.clone()).await {
+                Ok(part) => return Ok(part.into_inner()),
+                Err(error) if attempt < options.max_attempts && is_retryable(&error) => {
+                    let wait = match &error {
+                        AiError::Api { metadata, .. } => retry_after(metadata),
+                        _ => None,
+                    };
+                    tokio::time::sleep(wait.unwrap_or(delay)).await;
+                    delay = backoff.next_delay(delay);
+                    attempt += 1;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+/// A completed upload always carries the file it created.
+#[cfg(feature = "stream")]
+#[derive(Deserialize)]
+struct CompletedUpload {
+    file: OpenAIFile,
+}
+
+#[cfg(feature = "stream")]
+async fn read_part(path: &Path, offset: u64, length: usize) -> std::io::Result<Bytes> {
+    use tokio::io::{AsyncReadExt as _, AsyncSeekExt as _};
+
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(SeekFrom::Start(offset)).await?;
+    let mut data = vec![0; length];
+    file.read_exact(&mut data).await?;
+    Ok(data.into())
+}
+
+#[cfg(feature = "stream")]
+fn is_retryable(error: &AiError) -> bool {
+    match error {
+        AiError::Timeout { .. } | AiError::Transport { .. } => true,
+        AiError::Api { metadata, .. } => {
+            metadata.status.as_u16() == 429 || metadata.status.is_server_error()
+        }
+        _ => false,
+    }
+}
+
+/// The `Retry-After` of a rate-limited response, when given in seconds.
+#[cfg(feature = "stream")]
+fn retry_after(metadata: &ResponseMetadata) -> Option<Duration> {
+    if metadata.status.as_u16() != 429 {
+        return None;
+    }
+    let seconds = metadata
+        .retry_after
+        .as_deref()?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(feature = "stream")]
+fn guess_mime_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("jsonl") => "text/jsonl",
+        Some("json") => "application/json",
+        Some("txt") => "text/plain",
+        Some("md") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("pdf") => "application/pdf",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("webp") => "image/webp",
+        Some("gif") => "image/gif",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_support::{json_response, one_shot_server, openai_client, request_json};
+
+    fn upload(status: &str, file: &str) -> String {
+        format!(
+            r#"{{"id":"upload_1","object":"upload","bytes":6,"created_at":1,"expires_at":3601,
+                "filename":"data.jsonl","purpose":"batch","status":"{status}","file":{file}}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn create_sends_size_mime_type_purpose_and_expiry() {
+        let (base_url, wire) = one_shot_server(
+            "v1",
+            json_response("200 OK", &[], &upload("pending", "null")),
+        )
+        .await;
+        let request =
+            CreateUploadRequest::new("data.jsonl", 6, "text/jsonl", FileUploadPurpose::Batch)
+                .unwrap()
+                .expires_after(FileExpiration::after_creation(7_200).unwrap());
+        let upload = openai_client(base_url)
+            .uploads()
+            .create(request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({
+                "filename": "data.jsonl",
+                "bytes": 6,
+                "mime_type": "text/jsonl",
+                "purpose": "batch",
+                "expires_after": {"anchor": "created_at", "seconds": 7200}
+            })
+        );
+        assert_eq!(upload.status, UploadStatus::Pending);
+        assert!(upload.file.is_none());
+        assert!(matches!(
+            CreateUploadRequest::new("empty", 0, "text/plain", FileUploadPurpose::UserData),
+            Err(UploadError::InvalidSize(0))
+        ));
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn upload_large_file_retries_parts_and_completes_in_order() {
+        use crate::core::test_support::sequence_server;
+
+        let path =
+            std::env::temp_dir().join(format!("ai-client-upload-{}.jsonl", std::process::id()));
+        tokio::fs::write(&path, b"{}\n{}\n").await.unwrap();
+        let part = |id: &str| {
+            format!(
+                r#"{{"id":"{id}","object":"upload.part","created_at":2,"upload_id":"upload_1"}}"#
+            )
+        };
+        let file = r#"{"id":"file-xyz","object":"file","bytes":6,"created_at":3,
+            "filename":"data.jsonl","purpose":"batch"}"#;
+        let (base_url, wire) = sequence_server(
+            "v1",
+            vec![
+                json_response("200 OK", &[], &upload("pending", "null")),
+                json_response(
+                    "503 Service Unavailable",
+                    &[],
+                    r#"{"error":{"message":"busy"}}"#,
+                ),
+                json_response("200 OK", &[], &part("part_a")),
+                json_response("200 OK", &[], &part("part_b")),
+                json_response("200 OK", &[], &upload("completed", file)),
+            ],
+        )
+        .await;
+        let options = LargeFileUploadOptions::new()
+            .part_size(4)
+            .unwrap()
+            .concurrency(1)
+            .unwrap()
+            .retry_delay(Duration::from_millis(1));
+        let mut progress = Vec::new();
+        let file_id = openai_client(base_url)
+            .uploads()
+            .upload_large_file(&path, FileUploadPurpose::Batch, options, |update| {
+                progress.push(update)
+            })
+            .await
+            .unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        let wire = wire.await.unwrap();
+        assert_eq!(file_id.as_str(), "file-xyz");
+        assert_eq!(request_json(&wire[0])["mime_type"], "text/jsonl");
+        assert!(wire[1].starts_with("POST /v1/uploads/upload_1/parts HTTP/1.1"));
+        assert!(wire[2].contains("{}\n{"));
+        assert!(wire[3].contains("}\n\r\n"));
+        assert_eq!(
+            request_json(&wire[4]),
+            serde_json::json!({"part_ids": ["part_a", "part_b"]})
+        );
+        assert_eq!(
+            progress
+                .iter()
+                .map(|update| update.bytes_uploaded)
+                .collect::<Vec<_>>(),
+            [4, 6]
+        );
+        assert_eq!(progress[1].parts_uploaded, progress[1].total_parts);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn rate_limited_parts_wait_for_retry_after_and_failed_completion_cancels() {
+        use crate::core::test_support::sequence_server;
+
+        let path = std::env::temp_dir().join(format!(
+            "ai-client-upload-cancel-{}.jsonl",
+            std::process::id()
+        ));
+        tokio::fs::write(&path, b"{}\n{}\n").await.unwrap();
+        let (base_url, wire) = sequence_server(
+            "v1",
+            vec![
+                json_response("200 OK", &[], &upload("pending", "null")),
+                json_response(
+                    "429 Too Many Requests",
+                    &[("retry-after", "0")],
+                    r#"{"error":{"message":"slow down"}}"#,
+                ),
+                json_response(
+                    "200 OK",
+                    &[],
+                    r#"{"id":"part_a","object":"upload.part","created_at":2,"upload_id":"upload_1"}"#,
+                ),
+                json_response(
+                    "400 Bad Request",
+                    &[],
+                    r#"{"error":{"message":"bytes do not match"}}"#,
+                ),
+                json_response("200 OK", &[], &upload("cancelled", "null")),
+            ],
+        )
+        .await;
+        // Without Retry-After the retry would wait a minute and time out.
+        let options = LargeFileUploadOptions::new().retry_delay(Duration::from_secs(60));
+        let result = tokio::time::timeout(
+            Duration::from_secs(10),
+            openai_client(base_url).uploads().upload_large_file(
+                &path,
+                FileUploadPurpose::Batch,
+                options,
+                |_| {},
+            ),
+        )
+        .await
+        .unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert!(matches!(
+            result,
+            Err(LargeUploadError::Api(AiError::Api {
+                operation: "uploads.complete",
+                ..
+            }))
+        ));
+        let wire = wire.await.unwrap();
+        assert!(wire[3].starts_with("POST /v1/uploads/upload_1/complete HTTP/1.1"));
+        assert!(wire[4].starts_with("POST /v1/uploads/upload_1/cancel HTTP/1.1"));
+    }
+}
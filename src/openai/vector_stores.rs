@@ -0,0 +1,1408 @@
+//! OpenAI's Vector Stores API: the stores behind the `file_search` tool, their
+//! files and file batches, and direct search.
+
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    core::poll::{poll_until, PollBackoff},
+    error::{AiProvider, AiResponse, AiResult},
+    openai::{
+        client::decode_openai_error,
+        files::FileId,
+        id::validated_id,
+        responses::{
+            tagged::lossless_tagged_enum, ListOrder, OpenAIFileSearchFilter,
+            OpenAIFileSearchRankingOptions, OpenAIVectorStoreAttributeValue,
+            OpenAIVectorStoreFileAttributes,
+        },
+        OpenAIClient,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum VectorStoreError {
+    #[error("vector store ID must be non-empty and contain no whitespace or control characters")]
+    InvalidVectorStoreId,
+    #[error(
+        "vector store file batch ID must be non-empty and contain no whitespace or control characters"
+    )]
+    InvalidFileBatchId,
+    #[error("page limit must be between 1 and 100")]
+    InvalidPageLimit,
+    #[error("vector store expiry must be between 1 and 365 days, got {0}")]
+    InvalidExpiration(u16),
+    #[error("static chunks must be between 100 and 4096 tokens, got {0}")]
+    InvalidChunkSize(u32),
+    #[error("chunk overlap of {overlap} tokens exceeds half of the {max} token chunk size")]
+    InvalidChunkOverlap { overlap: u32, max: u32 },
+    #[error("file batches must contain between 1 and 500 files, got {0}")]
+    InvalidFileBatchSize(usize),
+    #[error("search may return between 1 and 50 results, got {0}")]
+    InvalidMaxResults(u8),
+}
+
+validated_id!(
+    /// A validated vector store ID such as `vs_abc123`.
+    VectorStoreId,
+    VectorStoreError::InvalidVectorStoreId
+);
+validated_id!(
+    /// A validated vector store file batch ID such as `vsfb_abc123`.
+    VectorStoreFileBatchId,
+    VectorStoreError::InvalidFileBatchId
+);
+
+/// How long a vector store may sit idle before it expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct VectorStoreExpiration {
+    anchor: VectorStoreExpirationAnchor,
+    days: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum VectorStoreExpirationAnchor {
+    LastActiveAt,
+}
+
+impl VectorStoreExpiration {
+    /// Expire the store `days` after it was last used, between 1 and 365 days.
+    pub fn after_last_active(days: u16) -> Result<Self, VectorStoreError> {
+        if !(1..=365).contains(&days) {
+            return Err(VectorStoreError::InvalidExpiration(days));
+        }
+        Ok(Self {
+            anchor: VectorStoreExpirationAnchor::LastActiveAt,
+            days,
+        })
+    }
+
+    pub fn days(self) -> u16 {
+        self.days
+    }
+}
+
+lossless_tagged_enum! {
+    /// How files are split into chunks before they are embedded.
+    #[derive(Debug, Clone)]
+    pub enum ChunkingStrategy {
+        Auto(AutoChunkingStrategy) => "auto",
+        Static(StaticChunkingStrategy) => "static",
+        @unknown
+    }
+}
+
+impl ChunkingStrategy {
+    /// Let OpenAI choose; currently 800-token chunks with 400 tokens of overlap.
+    pub fn auto() -> Self {
+        Self::Auto(AutoChunkingStrategy {})
+    }
+
+    /// Fixed-size chunks. `max_chunk_size_tokens` must be between 100 and 4096,
+    /// and `chunk_overlap_tokens` at most half of it.
+    pub fn fixed(
+        max_chunk_size_tokens: u32,
+        chunk_overlap_tokens: u32,
+    ) -> Result<Self, VectorStoreError> {
+        if !(100..=4096).contains(&max_chunk_size_tokens) {
+            return Err(VectorStoreError::InvalidChunkSize(max_chunk_size_tokens));
+        }
+        if chunk_overlap_tokens > max_chunk_size_tokens / 2 {
+            return Err(VectorStoreError::InvalidChunkOverlap {
+                overlap: chunk_overlap_tokens,
+                max: max_chunk_size_tokens,
+            });
+        }
+        Ok(Self::Static(StaticChunkingStrategy {
+            config: StaticChunking {
+                max_chunk_size_tokens,
+                chunk_overlap_tokens,
+            },
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoChunkingStrategy {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaticChunkingStrategy {
+    #[serde(rename = "static")]
+    pub config: StaticChunking,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaticChunking {
+    pub max_chunk_size_tokens: u32,
+    pub chunk_overlap_tokens: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateVectorStoreRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    file_ids: Vec<FileId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_after: Option<VectorStoreExpiration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chunking_strategy: Option<ChunkingStrategy>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<String, String>,
+}
+
+impl CreateVectorStoreRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Attach an uploaded file when the store is created.
+    pub fn file(mut self, file_id: FileId) -> Self {
+        self.file_ids.push(file_id);
+        self
+    }
+
+    pub fn expires_after(mut self, expiration: VectorStoreExpiration) -> Self {
+        self.expires_after = Some(expiration);
+        self
+    }
+
+    /// Chunking for the files attached at creation.
+    pub fn chunking_strategy(mut self, strategy: ChunkingStrategy) -> Self {
+        self.chunking_strategy = Some(strategy);
+        self
+    }
+
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateVectorStoreRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_after: Option<Option<VectorStoreExpiration>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<BTreeMap<String, String>>,
+}
+
+impl UpdateVectorStoreRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn expires_after(mut self, expiration: VectorStoreExpiration) -> Self {
+        self.expires_after = Some(Some(expiration));
+        self
+    }
+
+    /// Remove the store's expiry policy.
+    pub fn never_expire(mut self) -> Self {
+        self.expires_after = Some(None);
+        self
+    }
+
+    /// Replace the store's metadata.
+    pub fn metadata(mut self, metadata: BTreeMap<String, String>) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListVectorStoresOptions {
+    limit: Option<u8>,
+    order: Option<ListOrder>,
+    after: Option<VectorStoreId>,
+    before: Option<VectorStoreId>,
+}
+
+impl ListVectorStoresOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn limit(mut self, limit: u8) -> Result<Self, VectorStoreError> {
+        self.limit = Some(check_page_limit(limit)?);
+        Ok(self)
+    }
+
+    pub fn order(mut self, order: ListOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn after(mut self, after: VectorStoreId) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    pub fn before(mut self, before: VectorStoreId) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    pub(crate) fn query(&self) -> Vec<(String, String)> {
+        page_query(
+            self.limit,
+            self.order,
+            self.after.as_ref().map(VectorStoreId::as_str),
+            self.before.as_ref().map(VectorStoreId::as_str),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListVectorStoreFilesOptions {
+    limit: Option<u8>,
+    order: Option<ListOrder>,
+    after: Option<FileId>,
+    before: Option<FileId>,
+    filter: Option<VectorStoreFileStatus>,
+}
+
+impl ListVectorStoreFilesOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn limit(mut self, limit: u8) -> Result<Self, VectorStoreError> {
+        self.limit = Some(check_page_limit(limit)?);
+        Ok(self)
+    }
+
+    pub fn order(mut self, order: ListOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn after(mut self, after: FileId) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    pub fn before(mut self, before: FileId) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Only list files with this status.
+    pub fn status(mut self, status: VectorStoreFileStatus) -> Self {
+        self.filter = Some(status);
+        self
+    }
+
+    pub(crate) fn query(&self) -> Vec<(String, String)> {
+        let mut query = page_query(
+            self.limit,
+            self.order,
+            self.after.as_ref().map(FileId::as_str),
+            self.before.as_ref().map(FileId::as_str),
+        );
+        if let Some(status) = &self.filter {
+            query.push(("filter".into(), status.as_str().into()));
+        }
+        query
+    }
+}
+
+fn check_page_limit(limit: u8) -> Result<u8, VectorStoreError> {
+    if !(1..=100).contains(&limit) {
+        return Err(VectorStoreError::InvalidPageLimit);
+    }
+    Ok(limit)
+}
+
+fn page_query(
+    limit: Option<u8>,
+    order: Option<ListOrder>,
+    after: Option<&str>,
+    before: Option<&str>,
+) -> Vec<(String, String)> {
+    let mut query = Vec::new();
+    if let Some(limit) = limit {
+        query.push(("limit".into(), limit.to_string()));
+    }
+    if let Some(order) = order {
+        query.push(("order".into(), order.as_str().into()));
+    }
+    if let Some(after) = after {
+        query.push(("after".into(), after.into()));
+    }
+    if let Some(before) = before {
+        query.push(("before".into(), before.into()));
+    }
+    query
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VectorStoreStatus {
+    Expired,
+    InProgress,
+    Completed,
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for VectorStoreStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "expired" => Self::Expired,
+            "in_progress" => Self::InProgress,
+            "completed" => Self::Completed,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+/// The status of a file in a vector store, or of a file batch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VectorStoreFileStatus {
+    InProgress,
+    Completed,
+    Cancelled,
+    Failed,
+    Other(String),
+}
+
+impl VectorStoreFileStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::InProgress => "in_progress",
+            Self::Completed => "completed",
+            Self::Cancelled => "cancelled",
+            Self::Failed => "failed",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VectorStoreFileStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "in_progress" => Self::InProgress,
+            "completed" => Self::Completed,
+            "cancelled" => Self::Cancelled,
+            "failed" => Self::Failed,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct VectorStoreFileCounts {
+    pub in_progress: u64,
+    pub completed: u64,
+    pub failed: u64,
+    pub cancelled: u64,
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStore {
+    pub id: VectorStoreId,
+    pub object: String,
+    pub created_at: u64,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub usage_bytes: u64,
+    pub file_counts: VectorStoreFileCounts,
+    pub status: VectorStoreStatus,
+    #[serde(default)]
+    pub expires_after: Option<VectorStoreExpiration>,
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+    #[serde(default)]
+    pub last_active_at: Option<u64>,
+    #[serde(default)]
+    pub metadata: Option<BTreeMap<String, String>>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStoreList {
+    pub object: String,
+    pub data: Vec<VectorStore>,
+    pub has_more: bool,
+    #[serde(default)]
+    pub first_id: Option<VectorStoreId>,
+    #[serde(default)]
+    pub last_id: Option<VectorStoreId>,
+}
+
+impl VectorStoreList {
+    /// Options for the page after this one, or `None` on the last page.
+    pub fn next_page(&self, options: &ListVectorStoresOptions) -> Option<ListVectorStoresOptions> {
+        let last_id = self.last_id.clone()?;
+        self.has_more.then(|| options.clone().after(last_id))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeletedVectorStore {
+    pub id: VectorStoreId,
+    pub object: String,
+    pub deleted: bool,
+}
+
+/// Attach an uploaded file to a vector store.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateVectorStoreFileRequest {
+    file_id: FileId,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    attributes: OpenAIVectorStoreFileAttributes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl CreateVectorStoreFileRequest {
+    pub fn new(file_id: FileId) -> Self {
+        Self {
+            file_id,
+            attributes: BTreeMap::new(),
+            chunking_strategy: None,
+        }
+    }
+
+    /// An attribute that `file_search` filters can match on.
+    pub fn attribute(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<OpenAIVectorStoreAttributeValue>,
+    ) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn chunking_strategy(mut self, strategy: ChunkingStrategy) -> Self {
+        self.chunking_strategy = Some(strategy);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStoreFileError {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStoreFile {
+    pub id: FileId,
+    pub object: String,
+    pub created_at: u64,
+    pub vector_store_id: VectorStoreId,
+    pub usage_bytes: u64,
+    pub status: VectorStoreFileStatus,
+    #[serde(default)]
+    pub last_error: Option<VectorStoreFileError>,
+    #[serde(default)]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+    #[serde(default)]
+    pub attributes: Option<OpenAIVectorStoreFileAttributes>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStoreFileList {
+    pub object: String,
+    pub data: Vec<VectorStoreFile>,
+    pub has_more: bool,
+    #[serde(default)]
+    pub first_id: Option<FileId>,
+    #[serde(default)]
+    pub last_id: Option<FileId>,
+}
+
+impl VectorStoreFileList {
+    /// Options for the page after this one, or `None` on the last page.
+    pub fn next_page(
+        &self,
+        options: &ListVectorStoreFilesOptions,
+    ) -> Option<ListVectorStoreFilesOptions> {
+        let last_id = self.last_id.clone()?;
+        self.has_more.then(|| options.clone().after(last_id))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeletedVectorStoreFile {
+    pub id: FileId,
+    pub object: String,
+    pub deleted: bool,
+}
+
+/// A chunk of parsed file text.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStoreContent {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStoreFileContent {
+    pub object: String,
+    pub data: Vec<VectorStoreContent>,
+    pub has_more: bool,
+    #[serde(default)]
+    pub next_page: Option<String>,
+}
+
+/// Request state for files that share the batch-wide attributes and
+/// chunking strategy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SharedFileOptions;
+
+/// Request state for files that each carry their own attributes and chunking
+/// strategy. The API ignores batch-wide ones in this mode, so they cannot be
+/// set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PerFileOptions;
+
+/// Attach up to 500 files in one request.
+#[derive(Debug, Clone, Serialize)]
+#[serde(bound = "")]
+pub struct CreateFileBatchRequest<Options = SharedFileOptions> {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    file_ids: Vec<FileId>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    files: Vec<CreateVectorStoreFileRequest>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    attributes: OpenAIVectorStoreFileAttributes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chunking_strategy: Option<ChunkingStrategy>,
+    #[serde(skip)]
+    state: PhantomData<fn() -> Options>,
+}
+
+impl CreateFileBatchRequest<SharedFileOptions> {
+    /// Files that share the batch-wide attributes and chunking strategy.
+    pub fn new(file_ids: Vec<FileId>) -> Result<Self, VectorStoreError> {
+        check_batch_size(file_ids.len())?;
+        Ok(Self {
+            file_ids,
+            files: Vec::new(),
+            attributes: BTreeMap::new(),
+            chunking_strategy: None,
+            state: PhantomData,
+        })
+    }
+
+    pub fn attribute(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<OpenAIVectorStoreAttributeValue>,
+    ) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn chunking_strategy(mut self, strategy: ChunkingStrategy) -> Self {
+        self.chunking_strategy = Some(strategy);
+        self
+    }
+}
+
+impl CreateFileBatchRequest<PerFileOptions> {
+    /// Files that each carry their own attributes and chunking strategy.
+    pub fn from_files(files: Vec<CreateVectorStoreFileRequest>) -> Result<Self, VectorStoreError> {
+        check_batch_size(files.len())?;
+        Ok(Self {
+            file_ids: Vec::new(),
+            files,
+            attributes: BTreeMap::new(),
+            chunking_strategy: None,
+            state: PhantomData,
+        })
+    }
+}
+
+fn check_batch_size(files: usize) -> Result<(), VectorStoreError> {
+    if !(1..=500).contains(&files) {
+        return Err(VectorStoreError::InvalidFileBatchSize(files));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStoreFileBatch {
+    pub id: VectorStoreFileBatchId,
+    pub object: String,
+    pub created_at: u64,
+    pub vector_store_id: VectorStoreId,
+    pub status: VectorStoreFileStatus,
+    pub file_counts: VectorStoreFileCounts,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum VectorStoreSearchQuery {
+    Text(String),
+    Texts(Vec<String>),
+}
+
+impl From<String> for VectorStoreSearchQuery {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for VectorStoreSearchQuery {
+    fn from(value: &str) -> Self {
+        Self::Text(value.into())
+    }
+}
+
+impl From<Vec<String>> for VectorStoreSearchQuery {
+    fn from(value: Vec<String>) -> Self {
+        Self::Texts(value)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VectorStoreSearchRequest {
+    query: VectorStoreSearchQuery,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filters: Option<OpenAIFileSearchFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_num_results: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ranking_options: Option<OpenAIFileSearchRankingOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rewrite_query: Option<bool>,
+}
+
+impl VectorStoreSearchRequest {
+    pub fn new(query: impl Into<VectorStoreSearchQuery>) -> Self {
+        Self {
+            query: query.into(),
+            filters: None,
+            max_num_results: None,
+            ranking_options: None,
+            rewrite_query: None,
+        }
+    }
+
+    /// Only search files whose attributes match.
+    pub fn filters(mut self, filters: OpenAIFileSearchFilter) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_num_results(mut self, max_num_results: u8) -> Result<Self, VectorStoreError> {
+        if !(1..=50).contains(&max_num_results) {
+            return Err(VectorStoreError::InvalidMaxResults(max_num_results));
+        }
+        self.max_num_results = Some(max_num_results);
+        Ok(self)
+    }
+
+    pub fn ranking_options(mut self, ranking_options: OpenAIFileSearchRankingOptions) -> Self {
+        self.ranking_options = Some(ranking_options);
+        self
+    }
+
+    /// Let OpenAI rewrite the query for retrieval.
+    pub fn rewrite_query(mut self, rewrite_query: bool) -> Self {
+        self.rewrite_query = Some(rewrite_query);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStoreSearchResult {
+    pub file_id: FileId,
+    pub filename: String,
+    pub score: f64,
+    #[serde(default)]
+    pub attributes: Option<OpenAIVectorStoreFileAttributes>,
+    pub content: Vec<VectorStoreContent>,
+    #[serde(default, flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VectorStoreSearchResults {
+    pub object: String,
+    /// The queries that were run, after any rewriting.
+    #[serde(deserialize_with = "deserialize_search_query")]
+    pub search_query: Vec<String>,
+    pub data: Vec<VectorStoreSearchResult>,
+    pub has_more: bool,
+    #[serde(default)]
+    pub next_page: Option<String>,
+}
+
+fn deserialize_search_query<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Query {
+        Text(String),
+        Texts(Vec<String>),
+    }
+    Ok(match Query::deserialize(deserializer)? {
+        Query::Text(text) => vec![text],
+        Query::Texts(texts) => texts,
+    })
+}
+
+#[derive(Clone, Copy)]
+pub struct VectorStoresResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> VectorStoresResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create(
+        &self,
+        request: CreateVectorStoreRequest,
+    ) -> AiResult<AiResponse<VectorStore>> {
+        self.client
+            .transport()
+            .post_json(
+                "vector_stores.create",
+                "vector_stores",
+                &request,
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn retrieve(
+        &self,
+        vector_store_id: &VectorStoreId,
+    ) -> AiResult<AiResponse<VectorStore>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "vector_stores.retrieve",
+                &["vector_stores", vector_store_id.as_str()],
+                &[],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn update(
+        &self,
+        vector_store_id: &VectorStoreId,
+        request: UpdateVectorStoreRequest,
+    ) -> AiResult<AiResponse<VectorStore>> {
+        self.client
+            .transport()
+            .post_json_segments(
+                "vector_stores.update",
+                &["vector_stores", vector_store_id.as_str()],
+                &request,
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn delete(
+        &self,
+        vector_store_id: &VectorStoreId,
+    ) -> AiResult<AiResponse<DeletedVectorStore>> {
+        self.client
+            .transport()
+            .delete_json_segments(
+                "vector_stores.delete",
+                &["vector_stores", vector_store_id.as_str()],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn list(
+        &self,
+        options: ListVectorStoresOptions,
+    ) -> AiResult<AiResponse<VectorStoreList>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "vector_stores.list",
+                &["vector_stores"],
+                &options.query(),
+                decode_openai_error,
+            )
+            .await
+    }
+
+    /// Search a store directly, without a model in the loop.
+    pub async fn search(
+        &self,
+        vector_store_id: &VectorStoreId,
+        request: VectorStoreSearchRequest,
+    ) -> AiResult<AiResponse<VectorStoreSearchResults>> {
+        self.client
+            .transport()
+            .post_json_segments(
+                "vector_stores.search",
+                &["vector_stores", vector_store_id.as_str(), "search"],
+                &request,
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub fn files(&self) -> VectorStoreFilesResource<'a> {
+        VectorStoreFilesResource::new(self.client)
+    }
+
+    pub fn file_batches(&self) -> VectorStoreFileBatchesResource<'a> {
+        VectorStoreFileBatchesResource::new(self.client)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct VectorStoreFilesResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> VectorStoreFilesResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    /// Attach a file; it is chunked and embedded in the background.
+    pub async fn create(
+        &self,
+        vector_store_id: &VectorStoreId,
+        request: CreateVectorStoreFileRequest,
+    ) -> AiResult<AiResponse<VectorStoreFile>> {
+        self.client
+            .transport()
+            .post_json_segments(
+                "vector_stores.files.create",
+                &["vector_stores", vector_store_id.as_str(), "files"],
+                &request,
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn list(
+        &self,
+        vector_store_id: &VectorStoreId,
+        options: ListVectorStoreFilesOptions,
+    ) -> AiResult<AiResponse<VectorStoreFileList>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "vector_stores.files.list",
+                &["vector_stores", vector_store_id.as_str(), "files"],
+                &options.query(),
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn retrieve(
+        &self,
+        vector_store_id: &VectorStoreId,
+        file_id: &FileId,
+    ) -> AiResult<AiResponse<VectorStoreFile>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "vector_stores.files.retrieve",
+                &[
+                    "vector_stores",
+                    vector_store_id.as_str(),
+                    "files",
+                    file_id.as_str(),
+                ],
+                &[],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    /// Detach a file from the store. The file itself is not deleted.
+    pub async fn delete(
+        &self,
+        vector_store_id: &VectorStoreId,
+        file_id: &FileId,
+    ) -> AiResult<AiResponse<DeletedVectorStoreFile>> {
+        self.client
+            .transport()
+            .delete_json_segments(
+                "vector_stores.files.delete",
+                &[
+                    "vector_stores",
+                    vector_store_id.as_str(),
+                    "files",
+                    file_id.as_str(),
+                ],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    /// The parsed text of a file, as the store sees it.
+    pub async fn content(
+        &self,
+        vector_store_id: &VectorStoreId,
+        file_id: &FileId,
+    ) -> AiResult<AiResponse<VectorStoreFileContent>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "vector_stores.files.content",
+                &[
+                    "vector_stores",
+                    vector_store_id.as_str(),
+                    "files",
+                    file_id.as_str(),
+                    "content",
+                ],
+                &[],
+                decode_openai_error,
+            )
+            .await
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct VectorStoreFileBatchesResource<'a> {
+    client: &'a OpenAIClient,
+}
+
+impl<'a> VectorStoreFileBatchesResource<'a> {
+    pub(crate) fn new(client: &'a OpenAIClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create<Options>(
+        &self,
+        vector_store_id: &VectorStoreId,
+        request: CreateFileBatchRequest<Options>,
+    ) -> AiResult<AiResponse<VectorStoreFileBatch>> {
+        self.client
+            .transport()
+            .post_json_segments(
+                "vector_stores.file_batches.create",
+                &["vector_stores", vector_store_id.as_str(), "file_batches"],
+                &request,
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn retrieve(
+        &self,
+        vector_store_id: &VectorStoreId,
+        batch_id: &VectorStoreFileBatchId,
+    ) -> AiResult<AiResponse<VectorStoreFileBatch>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "vector_stores.file_batches.retrieve",
+                &[
+                    "vector_stores",
+                    vector_store_id.as_str(),
+                    "file_batches",
+                    batch_id.as_str(),
+                ],
+                &[],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn cancel(
+        &self,
+        vector_store_id: &VectorStoreId,
+        batch_id: &VectorStoreFileBatchId,
+    ) -> AiResult<AiResponse<VectorStoreFileBatch>> {
+        self.client
+            .transport()
+            .post_empty_segments(
+                "vector_stores.file_batches.cancel",
+                &[
+                    "vector_stores",
+                    vector_store_id.as_str(),
+                    "file_batches",
+                    batch_id.as_str(),
+                    "cancel",
+                ],
+                decode_openai_error,
+            )
+            .await
+    }
+
+    pub async fn list_files(
+        &self,
+        vector_store_id: &VectorStoreId,
+        batch_id: &VectorStoreFileBatchId,
+        options: ListVectorStoreFilesOptions,
+    ) -> AiResult<AiResponse<VectorStoreFileList>> {
+        self.client
+            .transport()
+            .get_json_segments(
+                "vector_stores.file_batches.list_files",
+                &[
+                    "vector_stores",
+                    vector_store_id.as_str(),
+                    "file_batches",
+                    batch_id.as_str(),
+                    "files",
+                ],
+                &options.query(),
+                decode_openai_error,
+            )
+            .await
+    }
+
+    /// Poll until the batch is no longer in progress, sleeping between polls
+    /// according to `backoff`. Check the returned batch's status and file
+    /// counts for failures.
+    pub async fn wait(
+        &self,
+        vector_store_id: &VectorStoreId,
+        batch_id: &VectorStoreFileBatchId,
+        backoff: PollBackoff,
+    ) -> AiResult<AiResponse<VectorStoreFileBatch>> {
+        poll_until(
+            AiProvider::OpenAI,
+            "vector_stores.file_batches.wait",
+            backoff,
+            || self.retrieve(vector_store_id, batch_id),
+            |batch| batch.status != VectorStoreFileStatus::InProgress,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{
+        core::test_support::{
+            json_response, one_shot_server, openai_client, request_json, sequence_server,
+        },
+        openai::responses::{
+            OpenAIComparisonFilter, OpenAIComparisonOperator, OpenAIComparisonValue,
+        },
+    };
+
+    const STORE: &str = r#"{
+        "id": "vs_1", "object": "vector_store", "created_at": 1, "name": "docs",
+        "usage_bytes": 0, "status": "in_progress",
+        "file_counts": {"in_progress": 1, "completed": 0, "failed": 0, "cancelled": 0, "total": 1},
+        "expires_after": {"anchor": "last_active_at", "days": 7},
+        "metadata": {"team": "search"}
+    }"#;
+
+    #[tokio::test]
+    async fn create_sends_files_expiry_chunking_and_metadata() {
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], STORE)).await;
+        let request = CreateVectorStoreRequest::new()
+            .name("docs")
+            .file(FileId::new("file-1").unwrap())
+            .expires_after(VectorStoreExpiration::after_last_active(7).unwrap())
+            .chunking_strategy(ChunkingStrategy::fixed(800, 400).unwrap())
+            .metadata("team", "search");
+        let store = openai_client(base_url)
+            .vector_stores()
+            .create(request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            request_json(&wire.await.unwrap()),
+            serde_json::json!({
+                "name": "docs",
+                "file_ids": ["file-1"],
+                "expires_after": {"anchor": "last_active_at", "days": 7},
+                "chunking_strategy": {
+                    "type": "static",
+                    "static": {"max_chunk_size_tokens": 800, "chunk_overlap_tokens": 400}
+                },
+                "metadata": {"team": "search"}
+            })
+        );
+        assert_eq!(store.status, VectorStoreStatus::InProgress);
+        assert_eq!(store.file_counts.total, 1);
+        assert_eq!(store.expires_after.unwrap().days(), 7);
+    }
+
+    #[tokio::test]
+    async fn update_can_clear_expiry_and_list_pages_with_cursors() {
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], STORE)).await;
+        let vector_store_id = VectorStoreId::new("vs_1").unwrap();
+        openai_client(base_url)
+            .vector_stores()
+            .update(
+                &vector_store_id,
+                UpdateVectorStoreRequest::new()
+                    .name("renamed")
+                    .never_expire(),
+            )
+            .await
+            .unwrap();
+        let wire = wire.await.unwrap();
+        assert!(wire.starts_with("POST /v1/vector_stores/vs_1 HTTP/1.1"));
+        assert_eq!(
+            request_json(&wire),
+            serde_json::json!({"name": "renamed", "expires_after": null})
+        );
+
+        let body = format!(
+            r#"{{"object":"list","data":[{STORE}],"first_id":"vs_1","last_id":"vs_1","has_more":true}}"#
+        );
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], &body)).await;
+        let options = ListVectorStoresOptions::new()
+            .limit(1)
+            .unwrap()
+            .before(VectorStoreId::new("vs_9").unwrap());
+        let page = openai_client(base_url)
+            .vector_stores()
+            .list(options.clone())
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(wire
+            .await
+            .unwrap()
+            .starts_with("GET /v1/vector_stores?limit=1&before=vs_9 HTTP/1.1"));
+        assert_eq!(
+            page.next_page(&options).unwrap().query(),
+            [
+                ("limit".to_string(), "1".to_string()),
+                ("after".to_string(), "vs_1".to_string()),
+                ("before".to_string(), "vs_9".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn files_attach_with_attributes_and_read_parsed_content() {
+        let file = r#"{
+            "id": "file-1", "object": "vector_store.file", "created_at": 1,
+            "vector_store_id": "vs_1", "usage_bytes": 10, "status": "failed",
+            "last_error": {"code": "unsupported_file", "message": "no"},
+            "chunking_strategy": {"type": "other"},
+            "attributes": {"lang": "en", "year": 2024, "draft": false}
+        }"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], file)).await;
+        let vector_store_id = VectorStoreId::new("vs_1").unwrap();
+        let file_id = FileId::new("file-1").unwrap();
+        let request = CreateVectorStoreFileRequest::new(file_id.clone())
+            .attribute("lang", "en")
+            .attribute("year", 2024.0)
+            .chunking_strategy(ChunkingStrategy::auto());
+        let attached = openai_client(base_url)
+            .vector_stores()
+            .files()
+            .create(&vector_store_id, request)
+            .await
+            .unwrap()
+            .into_inner();
+        let wire = wire.await.unwrap();
+        assert!(wire.starts_with("POST /v1/vector_stores/vs_1/files HTTP/1.1"));
+        assert_eq!(
+            request_json(&wire),
+            serde_json::json!({
+                "file_id": "file-1",
+                "attributes": {"lang": "en", "year": 2024.0},
+                "chunking_strategy": {"type": "auto"}
+            })
+        );
+        assert_eq!(attached.status, VectorStoreFileStatus::Failed);
+        assert_eq!(attached.last_error.unwrap().code, "unsupported_file");
+        assert!(matches!(
+            attached.chunking_strategy,
+            Some(ChunkingStrategy::Unknown(raw)) if raw.tag() == "other"
+        ));
+
+        let content = r#"{"object":"vector_store.file_content.page",
+            "data":[{"type":"text","text":"hello"}],"has_more":false,"next_page":null}"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], content)).await;
+        let content = openai_client(base_url)
+            .vector_stores()
+            .files()
+            .content(&vector_store_id, &file_id)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(wire
+            .await
+            .unwrap()
+            .starts_with("GET /v1/vector_stores/vs_1/files/file-1/content HTTP/1.1"));
+        assert_eq!(content.data[0].text, "hello");
+    }
+
+    #[tokio::test]
+    async fn file_batch_wait_polls_until_the_batch_settles() {
+        let batch = |status: &str| {
+            format!(
+                r#"{{"id":"vsfb_1","object":"vector_store.files_batch","created_at":1,
+                    "vector_store_id":"vs_1","status":"{status}",
+                    "file_counts":{{"in_progress":0,"completed":2,"failed":0,"cancelled":0,"total":2}}}}"#
+            )
+        };
+        let (base_url, wire) = sequence_server(
+            "v1",
+            vec![
+                json_response("200 OK", &[], &batch("in_progress")),
+                json_response("200 OK", &[], &batch("completed")),
+            ],
+        )
+        .await;
+        let batch = openai_client(base_url)
+            .vector_stores()
+            .file_batches()
+            .wait(
+                &VectorStoreId::new("vs_1").unwrap(),
+                &VectorStoreFileBatchId::new("vsfb_1").unwrap(),
+                PollBackoff {
+                    initial_delay: Duration::from_millis(1),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap()
+            .into_inner();
+
+        let wire = wire.await.unwrap();
+        assert_eq!(wire.len(), 2);
+        assert!(wire[1].starts_with("GET /v1/vector_stores/vs_1/file_batches/vsfb_1 HTTP/1.1"));
+        assert_eq!(batch.status, VectorStoreFileStatus::Completed);
+        assert_eq!(batch.file_counts.completed, 2);
+    }
+
+    #[tokio::test]
+    async fn search_sends_filters_and_ranking_options() {
+        let body = r#"{
+            "object": "vector_store.search_results.page",
+            "search_query": ["return policy"],
+            "data": [{
+                "file_id": "file-1", "filename": "faq.md", "score": 0.8,
+                "attributes": {"lang": "en"},
+                "content": [{"type": "text", "text": "Returns within 30 days."}]
+            }],
+            "has_more": false,
+            "next_page": null
+        }"#;
+        let (base_url, wire) = one_shot_server("v1", json_response("200 OK", &[], body)).await;
+        let request = VectorStoreSearchRequest::new("return policy")
+            .filters(OpenAIFileSearchFilter::Comparison(OpenAIComparisonFilter {
+                operator: OpenAIComparisonOperator::Eq,
+                key: "lang".into(),
+                value: OpenAIComparisonValue::String("en".into()),
+            }))
+            .max_num_results(5)
+            .unwrap()
+            .ranking_options(OpenAIFileSearchRankingOptions {
+                ranker: Some("auto".into()),
+                score_threshold: Some(0.5),
+                hybrid_search: None,
+            })
+            .rewrite_query(true);
+        let results = openai_client(base_url)
+            .vector_stores()
+            .search(&VectorStoreId::new("vs_1").unwrap(), request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        let wire = wire.await.unwrap();
+        assert!(wire.starts_with("POST /v1/vector_stores/vs_1/search HTTP/1.1"));
+        assert_eq!(
+            request_json(&wire),
+            serde_json::json!({
+                "query": "return policy",
+                "filters": {"type": "eq", "key": "lang", "value": "en"},
+                "max_num_results": 5,
+                "ranking_options": {"ranker": "auto", "score_threshold": 0.5},
+                "rewrite_query": true
+            })
+        );
+        assert_eq!(results.search_query, ["return policy"]);
+        assert_eq!(results.data[0].file_id.as_str(), "file-1");
+        assert_eq!(results.data[0].content[0].text, "Returns within 30 days.");
+    }
+
+    #[test]
+    fn per_file_batches_send_only_the_files() {
+        let file = CreateVectorStoreFileRequest::new(FileId::new("file-1").unwrap())
+            .attribute("lang", "en");
+        let request = CreateFileBatchRequest::from_files(vec![file]).unwrap();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({"files":[{"file_id":"file-1","attributes":{"lang":"en"}}]})
+        );
+    }
+
+    #[test]
+    fn local_validation_rejects_out_of_range_settings() {
+        assert_eq!(
+            VectorStoreExpiration::after_last_active(0),
+            Err(VectorStoreError::InvalidExpiration(0))
+        );
+        assert!(matches!(
+            ChunkingStrategy::fixed(50, 0),
+            Err(VectorStoreError::InvalidChunkSize(50))
+        ));
+        assert!(matches!(
+            ChunkingStrategy::fixed(800, 401),
+            Err(VectorStoreError::InvalidChunkOverlap { .. })
+        ));
+        assert!(matches!(
+            CreateFileBatchRequest::new(Vec::new()),
+            Err(VectorStoreError::InvalidFileBatchSize(0))
+        ));
+        assert!(matches!(
+            VectorStoreSearchRequest::new("q").max_num_results(51),
+            Err(VectorStoreError::InvalidMaxResults(51))
+        ));
+    }
+}
@@ -1,4 +1,10 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
 use base64::prelude::*;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 pub trait IntoQuery {
     fn into_query(self) -> Vec<(String, String)>;
@@ -12,3 +18,24 @@ pub fn base64_encode(data: &[u8]) -> String {
 pub fn base64_decode(data: &str) -> Result<Vec<u8>, base64::DecodeError> {
     BASE64_STANDARD.decode(data.as_bytes())
 }
+
+/// Write `data` to `path` with its extension replaced by `extension`,
+/// returning the path that was written.
+pub(crate) async fn save_with_extension(
+    path: &Path,
+    extension: &str,
+    data: &[u8],
+) -> io::Result<PathBuf> {
+    let path = path.with_extension(extension);
+    tokio::fs::write(&path, data).await?;
+    Ok(path)
+}
+
+/// Write all of `data` to `writer` and flush it.
+pub(crate) async fn write_and_flush<W>(writer: &mut W, data: &[u8]) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    writer.write_all(data).await?;
+    writer.flush().await
+}
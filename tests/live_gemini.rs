@@ -27,8 +27,8 @@ fn text_request(generation_config: Option<GenerationConfig>) -> GenerateContentR
             parts: vec![Part::text("Reply with only OK.")],
             role: Some(Role::User),
         }],
-        safety_settings: None,
         generation_config,
+        ..Default::default()
     }
 }
 
@@ -124,7 +124,11 @@ async fn live_gemini_core_generation_options_are_accepted_together() {
             })),
             response_modalities: Some(vec!["TEXT".into()]),
             image_config: None,
+            response_json_schema: None,
+            thinking_config: None,
+            speech_config: None,
         }),
+        ..Default::default()
     };
 
     let response = client()
@@ -225,7 +229,6 @@ async fn live_gemini_expensive_image_model_matrix() {
                         parts: vec![Part::text("A single small blue circle on white.")],
                         role: Some(Role::User),
                     }],
-                    safety_settings: None,
                     generation_config: Some(GenerationConfig {
                         response_modalities: Some(vec!["IMAGE".into()]),
                         image_config: Some(GeminiImageConfig {
@@ -235,6 +238,7 @@ async fn live_gemini_expensive_image_model_matrix() {
                         }),
                         ..Default::default()
                     }),
+                    ..Default::default()
                 },
             )
             .await
@@ -298,7 +302,6 @@ async fn generate_image(
                     parts: vec![Part::text("A single small blue circle on white.")],
                     role: Some(Role::User),
                 }],
-                safety_settings: None,
                 generation_config: Some(GenerationConfig {
                     response_modalities: Some(vec!["IMAGE".into()]),
                     image_config: Some(GeminiImageConfig {
@@ -307,6 +310,7 @@ async fn generate_image(
                     }),
                     ..Default::default()
                 }),
+                ..Default::default()
             },
         )
         .await
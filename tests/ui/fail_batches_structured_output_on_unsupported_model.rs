@@ -0,0 +1,19 @@
+use ai_client::openai::{
+    batches::ResponsesBatchWriter,
+    responses::{CreateResponseRequest, Gpt5_4Pro},
+    OpenAIJsonSchema,
+};
+
+fn main() {
+    let request = CreateResponseRequest::builder()
+        .input_text("hello")
+        .json_schema(OpenAIJsonSchema {
+            name: "result".into(),
+            description: "result".into(),
+            schema: serde_json::json!({"type":"object"}),
+            strict: Some(true),
+        })
+        .build();
+    let mut writer = ResponsesBatchWriter::new(Vec::new());
+    let _ = writer.push("row-1", Gpt5_4Pro::config(), request);
+}
@@ -0,0 +1,5 @@
+use ai_client::openai::embeddings::TextEmbeddingAda002;
+
+fn main() {
+    let _ = TextEmbeddingAda002::config().dimensions(256);
+}
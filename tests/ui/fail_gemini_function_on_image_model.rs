@@ -0,0 +1,21 @@
+use ai_client::gemini::{
+    FunctionDeclaration, Gemini2_5FlashImage, GeminiClient, GeminiGenerateContentRequest,
+};
+
+async fn generate(client: &GeminiClient) {
+    let request = GeminiGenerateContentRequest::builder()
+        .user_text("draw the weather")
+        .function(FunctionDeclaration {
+            name: "weather".into(),
+            description: String::new(),
+            parameters: None,
+            parameters_json_schema: None,
+        })
+        .build();
+    let _ = client
+        .models()
+        .generate_content(Gemini2_5FlashImage::config(), request)
+        .await;
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use ai_client::gemini::Gemini2_5Flash;
+
+fn main() {
+    let _ = Gemini2_5Flash::config().image_output();
+}
@@ -0,0 +1,13 @@
+use ai_client::gemini::{Gemini2_5FlashImage, GeminiClient, GeminiGenerateContentRequest, GoogleSearch};
+
+async fn generate(client: &GeminiClient) {
+    let request = GeminiGenerateContentRequest::builder()
+        .user_text("draw today's weather")
+        .google_search(GoogleSearch::default());
+    let _ = client
+        .models()
+        .generate_content(Gemini2_5FlashImage::config().image_output(), request)
+        .await;
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use ai_client::gemini::{Gemini2_5Flash, ThinkingLevel};
+
+fn main() {
+    let _ = Gemini2_5Flash::config().thinking(ThinkingLevel::High);
+}
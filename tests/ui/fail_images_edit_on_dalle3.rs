@@ -0,0 +1,13 @@
+use ai_client::openai::{
+    images::{CreateImageEditRequest, DallE3, ImageInput},
+    OpenAIClient,
+};
+
+async fn edit(client: &OpenAIClient, image: ImageInput) {
+    let _ = client
+        .images()
+        .edit(DallE3::config(), CreateImageEditRequest::new(image, "add a hat"))
+        .await;
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use ai_client::openai::{images::GptImage1Mini, responses::OpenAIImageInputFidelity};
+
+fn main() {
+    let _ = GptImage1Mini::config().input_fidelity(OpenAIImageInputFidelity::High);
+}
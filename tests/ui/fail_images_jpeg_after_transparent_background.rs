@@ -0,0 +1,8 @@
+use ai_client::openai::images::{GptImage1_5, TransparentImageFormat};
+use ai_client::openai::responses::OpenAIImageFormat;
+
+fn main() {
+    let _ = GptImage1_5::config()
+        .transparent_background(TransparentImageFormat::Webp)
+        .output_format(OpenAIImageFormat::Jpeg);
+}
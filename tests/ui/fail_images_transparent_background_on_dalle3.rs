@@ -0,0 +1,5 @@
+use ai_client::openai::images::{DallE3, TransparentImageFormat};
+
+fn main() {
+    let _ = DallE3::config().transparent_background(TransparentImageFormat::Png);
+}
@@ -0,0 +1,14 @@
+use ai_client::openai::{
+    moderations::{CreateModerationRequest, TextModerationLatest},
+    OpenAIClient,
+};
+
+async fn moderate(client: &OpenAIClient) {
+    let request = CreateModerationRequest::text("caption").image_url("https://example.com/a.png");
+    let _ = client
+        .moderations()
+        .create(TextModerationLatest, request)
+        .await;
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use ai_client::openai::audio::Tts1;
+
+fn main() {
+    let _ = Tts1::config().instructions("Speak slowly.");
+}
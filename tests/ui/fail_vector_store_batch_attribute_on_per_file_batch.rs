@@ -0,0 +1,11 @@
+use ai_client::openai::{
+    files::FileId,
+    vector_stores::{CreateFileBatchRequest, CreateVectorStoreFileRequest},
+};
+
+fn main() {
+    let file = CreateVectorStoreFileRequest::new(FileId::new("file-1").unwrap());
+    let _ = CreateFileBatchRequest::from_files(vec![file])
+        .unwrap()
+        .attribute("lang", "en");
+}
@@ -0,0 +1,62 @@
+use ai_client::gemini::{
+    CachedContentName, FunctionDeclaration, Gemini2_5Flash, Gemini2_5FlashImage,
+    Gemini2_5FlashPreviewTts, Gemini3FlashPreview, Gemini3ProImage, GeminiClient,
+    GeminiGenerateContentRequest, GoogleSearch, ThinkingBudget, ThinkingLevel, Tool,
+};
+
+async fn generate(client: &GeminiClient) {
+    let request = GeminiGenerateContentRequest::builder()
+        .user_text("hello")
+        .function(FunctionDeclaration {
+            name: "lookup".into(),
+            description: String::new(),
+            parameters: None,
+            parameters_json_schema: None,
+        })
+        .tool(Tool::code_execution())
+        .google_search(GoogleSearch::default())
+        .response_json_schema(serde_json::json!({"type":"object"}))
+        .cached_content(CachedContentName::new("abc").unwrap())
+        .build();
+    let _ = client
+        .models()
+        .generate_content(
+            Gemini2_5Flash::config().thinking(ThinkingBudget::Dynamic),
+            request.clone(),
+        )
+        .await;
+    let _ = client
+        .models()
+        .generate_content(
+            Gemini3FlashPreview::config().thinking(ThinkingLevel::Minimal),
+            request,
+        )
+        .await;
+    let _ = client
+        .models()
+        .generate_content(
+            Gemini2_5FlashImage::config().image_output(),
+            GeminiGenerateContentRequest::builder().user_text("draw"),
+        )
+        .await;
+    let _ = client
+        .models()
+        .generate_content(
+            Gemini3ProImage::config().image_output(),
+            GeminiGenerateContentRequest::builder()
+                .user_text("draw today's weather")
+                .google_search(GoogleSearch::default()),
+        )
+        .await;
+    let _ = client
+        .models()
+        .generate_content(
+            Gemini2_5FlashPreviewTts::config().audio_output(),
+            GeminiGenerateContentRequest::builder().user_text("say hi"),
+        )
+        .await;
+}
+
+fn main() {
+    let _ = generate;
+}
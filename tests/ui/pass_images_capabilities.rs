@@ -0,0 +1,37 @@
+use ai_client::openai::images::{
+    CreateImageEditRequest, CreateImageRequest, CreateImageVariationRequest, DallE2, DallE2Size,
+    GptImage1, GptImageSize, ImageCount, ImageInput, ImageResponseFormat, TransparentImageFormat,
+};
+use ai_client::openai::responses::{OpenAIImageInputFidelity, OpenAIImageQuality};
+use ai_client::openai::OpenAIClient;
+
+async fn generate(client: &OpenAIClient) {
+    let request = CreateImageRequest::new("a lighthouse");
+    let gpt = GptImage1::config()
+        .size(GptImageSize::Portrait1024x1536)
+        .quality(OpenAIImageQuality::Medium)
+        .transparent_background(TransparentImageFormat::Png)
+        .count(ImageCount::new(2).unwrap());
+    let _ = client.images().generate(gpt, request.clone()).await;
+
+    let dalle = DallE2::config()
+        .size(DallE2Size::Square512)
+        .response_format(ImageResponseFormat::B64Json);
+    let _ = client.images().generate(dalle, request).await;
+}
+
+async fn edit(client: &OpenAIClient, base: ImageInput, reference: ImageInput, mask: ImageInput) {
+    let gpt = GptImage1::config().input_fidelity(OpenAIImageInputFidelity::High);
+    let request = CreateImageEditRequest::new(base.clone(), "combine these")
+        .image(reference)
+        .mask(mask);
+    let _ = client.images().edit(gpt, request).await;
+
+    let dalle = DallE2::config().count(ImageCount::new(2).unwrap());
+    let _ = client
+        .images()
+        .create_variation(dalle, CreateImageVariationRequest::new(base))
+        .await;
+}
+
+fn main() {}